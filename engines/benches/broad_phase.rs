@@ -0,0 +1,92 @@
+//! Compares the broad-phase structures on boxes scattered at a constant density, timing the
+//! first pass, then moving every box a little and finding the pairs again.
+//!
+//! Run with `cargo bench -p engines`.
+use std::time::{Duration, Instant};
+use engines::broad_phase::{Bounds, BroadPhase, DynamicTree, Quadtree, SpatialHash, SweepAndPrune};
+use physics::units::space::Meters;
+
+const STEPS: usize = 10;
+
+/// A repeatable stream of numbers between zero and one.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.0 >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+fn scatter(count: usize) -> (f32, Vec<Bounds<2, f32>>) {
+    let side = (count as f32 * 4.0).sqrt();
+    let mut random = Random(count as u64);
+
+    let boxes = (0..count).map(|_| {
+        let (x, y) = (random.next() * side, random.next() * side);
+        let (w, h) = (0.2 + random.next() * 0.8, 0.2 + random.next() * 0.8);
+        Bounds::new(Meters::from_components(&[x, y]), Meters::from_components(&[x + w, y + h]))
+    }).collect();
+
+    (side, boxes)
+}
+
+fn drift(boxes: &mut [Bounds<2, f32>], random: &mut Random) {
+    for bounds in boxes.iter_mut() {
+        let by = Meters::from_components(&[(random.next() - 0.5) * 0.1, (random.next() - 0.5) * 0.1]);
+        *bounds = bounds.translated(by);
+    }
+}
+
+fn run(name: &str, mut broad_phase: impl BroadPhase<2, f32>, boxes: &[Bounds<2, f32>]) {
+    let mut boxes = boxes.to_vec();
+    let mut random = Random(7);
+    let mut pairs = Vec::new();
+
+    let start = Instant::now();
+    for (key, bounds) in boxes.iter().enumerate() {
+        broad_phase.insert(key, *bounds);
+    }
+    broad_phase.pairs(&mut pairs);
+    let first = start.elapsed();
+
+    let mut stepping = Duration::ZERO;
+    for _ in 0..STEPS {
+        drift(&mut boxes, &mut random);
+
+        let start = Instant::now();
+        for (key, bounds) in boxes.iter().enumerate() {
+            broad_phase.update(key, *bounds);
+        }
+        broad_phase.pairs(&mut pairs);
+        stepping += start.elapsed();
+    }
+
+    println!("{name:>16} {:>12.3?} {:>12.3?} {:>10}", first, stepping / STEPS as u32, pairs.len());
+}
+
+fn brute_force(boxes: &[Bounds<2, f32>]) {
+    let start = Instant::now();
+    let mut count = 0;
+    for (i, a) in boxes.iter().enumerate() {
+        count += boxes[i + 1..].iter().filter(|b| a.overlaps(b)).count();
+    }
+    println!("{:>16} {:>12.3?} {:>12} {:>10}", "brute force", start.elapsed(), "", count);
+}
+
+fn main() {
+    for count in [1_000, 10_000, 100_000] {
+        let (side, boxes) = scatter(count);
+        println!("{count} bodies\n{:>16} {:>12} {:>12} {:>10}", "", "first", "per step", "pairs");
+
+        if count <= 10_000 {
+            brute_force(&boxes);
+        }
+        run("spatial hash", SpatialHash::new(Meters::new(1.0)), &boxes);
+        let region = Bounds::new(Meters::from_components(&[0.0, 0.0]), Meters::from_components(&[side + 1.0, side + 1.0]));
+        run("quadtree", Quadtree::new(region), &boxes);
+        run("sweep and prune", SweepAndPrune::new(), &boxes);
+        run("dynamic tree", DynamicTree::new(Meters::new(0.1)), &boxes);
+        println!();
+    }
+}
@@ -1,6 +1,9 @@
-use core::cmp::{min, max};
-use physics::{Zero, Space, space};
-use physics::space::ObservableSpace;
+use physics::{Real, Space, space};
+use physics::collision::{Convex, Direction, Polygon};
+use physics::space::{BaseUnit, ObservableSpace};
+use physics::units::force::MomentOfInertia;
+use physics::units::mass::Kilograms;
+use physics::units::space::{Meters, SquareMeters};
 
 #[derive(Copy, Clone)]
 pub struct NonRotatingBox<S: Space> {
@@ -8,6 +11,22 @@ pub struct NonRotatingBox<S: Space> {
     rbottom: S
 }
 
+impl<S: Space> NonRotatingBox<S> {
+    pub fn new(ltop: S, rbottom: S) -> Self {
+        Self {
+            ltop,
+            rbottom
+        }
+    }
+
+    pub fn translated(&self, by: S) -> Self {
+        Self {
+            ltop: self.ltop + by,
+            rbottom: self.rbottom + by
+        }
+    }
+}
+
 impl<S: ObservableSpace<2>> NonRotatingBox<S> {
     pub fn width(&self) -> S {
         let [width, _] = (self.rbottom - self.ltop).components();
@@ -20,36 +39,142 @@ impl<S: ObservableSpace<2>> NonRotatingBox<S> {
     }
 }
 
-impl<S: ObservableSpace<2>> space::Area<S> for NonRotatingBox<S> {
-    fn amount_of_space(&self) -> S {
-        let dist_comps = (self.rbottom - self.ltop).components();
-        let mut sum = S::new(&[S::Base::zero(); 2]);
+impl<B: BaseUnit> space::Area<Meters<2, B>> for NonRotatingBox<Meters<2, B>> {
+    type Amount = SquareMeters<B>;
 
-        for i in 0..dist_comps.len() {
-            let j = (i + 1) % dist_comps.len();
-            sum = sum + (dist_comps[i] * dist_comps[j]);
-        }
+    fn amount_of_space(&self) -> Self::Amount {
+        let diagonal = self.rbottom - self.ltop;
+        let width = Meters::<1, B>::new(diagonal.value_at(0));
+        let height = Meters::<1, B>::new(diagonal.value_at(1));
+
+        width * height
+    }
+}
+
+/// A solid box spinning about its centre: `m (w² + h²) / 12`.
+impl<B: Real> space::Inertia<Meters<2, B>, Kilograms<B>> for NonRotatingBox<Meters<2, B>> {
+    type Moment = MomentOfInertia<B>;
+
+    fn moment_of_inertia(&self, mass: Kilograms<B>) -> Self::Moment {
+        let diagonal = self.rbottom - self.ltop;
+        mass.scale(B::one() / B::from_f32(12.0)) * diagonal.magnitude_squared()
+    }
+}
+
+impl<const D: usize, B: Real> NonRotatingBox<Meters<D, B>> {
+    /// The corner with the smallest coordinates, whichever way round the box was given.
+    pub fn min(&self) -> Meters<D, B> {
+        let (a, b) = (self.ltop.values(), self.rbottom.values());
+        Meters::from_components(&core::array::from_fn(|i| a[i].min(b[i])))
+    }
+
+    /// The corner with the largest coordinates.
+    pub fn max(&self) -> Meters<D, B> {
+        let (a, b) = (self.ltop.values(), self.rbottom.values());
+        Meters::from_components(&core::array::from_fn(|i| a[i].max(b[i])))
+    }
+
+    /// Whether the two boxes share any point, edges included.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        let (low_a, high_a) = (self.min().values(), self.max().values());
+        let (low_b, high_b) = (other.min().values(), other.max().values());
+
+        (0..D).all(|i| low_a[i] <= high_b[i] && low_b[i] <= high_a[i])
+    }
+
+    /// Whether `other` lies wholly inside this box.
+    pub fn contains(&self, other: &Self) -> bool {
+        let (low_a, high_a) = (self.min().values(), self.max().values());
+        let (low_b, high_b) = (other.min().values(), other.max().values());
+
+        (0..D).all(|i| low_a[i] <= low_b[i] && high_b[i] <= high_a[i])
+    }
+
+    /// The smallest box around both.
+    pub fn merged(&self, other: &Self) -> Self {
+        let (low_a, high_a) = (self.min().values(), self.max().values());
+        let (low_b, high_b) = (other.min().values(), other.max().values());
+
+        Self::new(
+            Meters::from_components(&core::array::from_fn(|i| low_a[i].min(low_b[i]))),
+            Meters::from_components(&core::array::from_fn(|i| high_a[i].max(high_b[i])))
+        )
+    }
+
+    /// The box pushed out by `margin` on every side.
+    pub fn grown(&self, margin: Meters<1, B>) -> Self {
+        let by = Meters::from_components(&[margin.value(); D]);
+        Self::new(self.min() - by, self.max() + by)
+    }
+
+    /// The sum of its side lengths, a cheap stand-in for its size when deciding how to group
+    /// boxes.
+    pub fn extent(&self) -> Meters<1, B> {
+        let diagonal = (self.max() - self.min()).values();
+        Meters::new(diagonal.iter().fold(B::zero(), |sum, &side| sum + side))
+    }
+}
+
+impl<B: Real> Convex<B> for NonRotatingBox<Meters<2, B>> {
+    fn support(&self, direction: &Direction<B>) -> Meters<2, B> {
+        let [low_x, low_y] = self.min().values();
+        let [high_x, high_y] = self.max().values();
+        let [x, y] = direction.values();
 
-        sum
+        Meters::from_components(&[
+            if x > B::zero() { high_x } else { low_x },
+            if y > B::zero() { high_y } else { low_y }
+        ])
+    }
+
+    fn polygon(&self) -> Option<&dyn Polygon<B>> {
+        Some(self)
     }
 }
 
-impl<S: ObservableSpace<2>> space::AreaIntersection<S, Self> for NonRotatingBox<S> {
+impl<B: Real> Polygon<B> for NonRotatingBox<Meters<2, B>> {
+    fn vertex_count(&self) -> usize {
+        4
+    }
+
+    fn vertex(&self, i: usize) -> Meters<2, B> {
+        let [low_x, low_y] = self.min().values();
+        let [high_x, high_y] = self.max().values();
+
+        Meters::from_components(&match i {
+            0 => [low_x, low_y],
+            1 => [high_x, low_y],
+            2 => [high_x, high_y],
+            _ => [low_x, high_y]
+        })
+    }
+}
+
+/// Whichever of two coordinates is further along.
+fn later<B: BaseUnit>(a: B, b: B) -> B {
+    if b > a { b } else { a }
+}
+
+fn earlier<B: BaseUnit>(a: B, b: B) -> B {
+    if b < a { b } else { a }
+}
+
+impl<B: BaseUnit> space::AreaIntersection<Meters<2, B>, Self> for NonRotatingBox<Meters<2, B>> {
     fn area_intersection(&self, other: &Self) -> Self {
-        let ltop_a = self.ltop.components();
-        let rbottom_a = self.rbottom.components();
-        let ltop_b = other.ltop.components();
-        let rbottom_b = other.rbottom.components();
+        let [left_a, top_a] = self.ltop.values();
+        let [right_a, bottom_a] = self.rbottom.values();
+        let [left_b, top_b] = other.ltop.values();
+        let [right_b, bottom_b] = other.rbottom.values();
 
-        let left_x = max(ltop_a[0], ltop_b[0]);
-        let right_x = min(rbottom_a[0], rbottom_b[0]);
-        let top_y = max(ltop_a[1], ltop_b[1]);
-        let bottom_y = min(rbottom_a[1], rbottom_b[1]);
+        let left = later(left_a, left_b);
+        let right = earlier(right_a, right_b);
+        let top = later(top_a, top_b);
+        let bottom = earlier(bottom_a, bottom_b);
 
-        if right_x > left_x && bottom_y > top_y {
+        if right > left && bottom > top {
             Self {
-                ltop: left_x + top_y,
-                rbottom: right_x + bottom_y
+                ltop: Meters::from_components(&[left, top]),
+                rbottom: Meters::from_components(&[right, bottom])
             }
         } else {
             Self {
@@ -58,4 +183,24 @@ impl<S: ObservableSpace<2>> space::AreaIntersection<S, Self> for NonRotatingBox<
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use physics::space::{Area, AreaIntersection};
+    use super::*;
+
+    fn square(x: f32, y: f32, size: f32) -> NonRotatingBox<Meters<2, f32>> {
+        NonRotatingBox::new(Meters::from_components(&[x, y]), Meters::from_components(&[x + size, y + size]))
+    }
+
+    #[test]
+    fn intersection_keeps_its_corners() {
+        let overlap = square(-1.0, -1.0, 2.0).area_intersection(&square(0.5, -0.5, 2.0));
+        assert_eq!(overlap.min().values(), [0.5, -0.5]);
+        assert_eq!(overlap.max().values(), [1.0, 1.0]);
+        assert_eq!(overlap.amount_of_space().value(), 0.75);
+
+        assert_eq!(square(0.0, 0.0, 1.0).area_intersection(&square(5.0, 5.0, 1.0)).amount_of_space().value(), 0.0);
+    }
+}
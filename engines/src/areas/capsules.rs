@@ -0,0 +1,73 @@
+use physics::{Real, space};
+use physics::collision::{Convex, Direction};
+use physics::units::force::MomentOfInertia;
+use physics::units::mass::Kilograms;
+use physics::units::space::{Meters, SquareMeters};
+
+/// Every point within `radius` of the segment from `start` to `end`.
+#[derive(Copy, Clone)]
+pub struct Capsule<B: Real> {
+    pub start: Meters<2, B>,
+    pub end: Meters<2, B>,
+    pub radius: Meters<1, B>
+}
+
+impl<B: Real> Capsule<B> {
+    pub fn new(start: Meters<2, B>, end: Meters<2, B>, radius: Meters<1, B>) -> Self {
+        Self {
+            start,
+            end,
+            radius
+        }
+    }
+
+    pub fn center(&self) -> Meters<2, B> {
+        (self.start + self.end).scale(B::from_f32(0.5))
+    }
+}
+
+impl<B: Real> space::Area<Meters<2, B>> for Capsule<B> {
+    type Amount = SquareMeters<B>;
+
+    fn amount_of_space(&self) -> Self::Amount {
+        let length = (self.end - self.start).magnitude();
+        (length * self.radius).scale(B::from_f32(2.0)) + (self.radius * self.radius).scale(B::pi())
+    }
+}
+
+/// A solid rectangle between two half discs, each moved out from its own centroid by the
+/// parallel axis theorem.
+impl<B: Real> space::Inertia<Meters<2, B>, Kilograms<B>> for Capsule<B> {
+    type Moment = MomentOfInertia<B>;
+
+    fn moment_of_inertia(&self, mass: Kilograms<B>) -> Self::Moment {
+        let length = (self.end - self.start).magnitude().value();
+        let radius = self.radius.value();
+
+        let rectangle = B::from_f32(2.0) * radius * length;
+        let disc = B::pi() * radius * radius;
+        let total = rectangle + disc;
+        if total <= B::zero() {
+            return MomentOfInertia::zero();
+        }
+
+        let twelfth = B::one() / B::from_f32(12.0);
+        let rectangle_moment = rectangle / total * (length * length + B::from_f32(4.0) * radius * radius) * twelfth;
+
+        let centroid = B::from_f32(4.0) * radius / (B::from_f32(3.0) * B::pi());
+        let ends_moment = disc / total
+            * (radius * radius * B::from_f32(0.5) + length * length * B::from_f32(0.25) + length * centroid);
+
+        mass * SquareMeters::new(rectangle_moment + ends_moment)
+    }
+}
+
+impl<B: Real> Convex<B> for Capsule<B> {
+    fn support(&self, direction: &Direction<B>) -> Meters<2, B> {
+        if direction.dot(&(self.end - self.start)).value() > B::zero() { self.end } else { self.start }
+    }
+
+    fn radius(&self) -> Meters<1, B> {
+        self.radius
+    }
+}
@@ -0,0 +1,47 @@
+use physics::{Real, space};
+use physics::collision::{Convex, Direction};
+use physics::units::force::MomentOfInertia;
+use physics::units::mass::Kilograms;
+use physics::units::space::{Meters, SquareMeters};
+
+#[derive(Copy, Clone)]
+pub struct Circle<B: Real> {
+    pub center: Meters<2, B>,
+    pub radius: Meters<1, B>
+}
+
+impl<B: Real> Circle<B> {
+    pub fn new(center: Meters<2, B>, radius: Meters<1, B>) -> Self {
+        Self {
+            center,
+            radius
+        }
+    }
+}
+
+impl<B: Real> space::Area<Meters<2, B>> for Circle<B> {
+    type Amount = SquareMeters<B>;
+
+    fn amount_of_space(&self) -> Self::Amount {
+        (self.radius * self.radius).scale(B::pi())
+    }
+}
+
+/// A solid disc: `m r² / 2`.
+impl<B: Real> space::Inertia<Meters<2, B>, Kilograms<B>> for Circle<B> {
+    type Moment = MomentOfInertia<B>;
+
+    fn moment_of_inertia(&self, mass: Kilograms<B>) -> Self::Moment {
+        mass.scale(B::from_f32(0.5)) * (self.radius * self.radius)
+    }
+}
+
+impl<B: Real> Convex<B> for Circle<B> {
+    fn support(&self, _direction: &Direction<B>) -> Meters<2, B> {
+        self.center
+    }
+
+    fn radius(&self) -> Meters<1, B> {
+        self.radius
+    }
+}
@@ -0,0 +1,58 @@
+use physics::{Real, space};
+use physics::rotation::Rotation3;
+use physics::units::force::InertiaTensor;
+use physics::units::mass::Kilograms;
+use physics::units::space::{CubicMeters, Meters};
+
+/// A box in 3D, turned by `orientation` about its centre.
+#[derive(Copy, Clone)]
+pub struct Cuboid<B: Real> {
+    pub center: Meters<3, B>,
+    pub half_extents: Meters<3, B>,
+    pub orientation: Rotation3<B>
+}
+
+impl<B: Real> Cuboid<B> {
+    pub fn new(center: Meters<3, B>, size: Meters<3, B>, orientation: Rotation3<B>) -> Self {
+        Self {
+            center,
+            half_extents: size.scale(B::from_f32(0.5)),
+            orientation
+        }
+    }
+
+    pub fn size(&self) -> Meters<3, B> {
+        self.half_extents.scale(B::from_f32(2.0))
+    }
+
+    /// The eight corners in the world.
+    pub fn corners(&self) -> [Meters<3, B>; 8] {
+        let [x, y, z] = self.half_extents.values();
+        [0, 1, 2, 3, 4, 5, 6, 7].map(|i| {
+            let sign = |bit: usize, v: B| if i & bit == 0 { -v } else { v };
+            let local = Meters::from_components(&[sign(1, x), sign(2, y), sign(4, z)]);
+            self.center + self.orientation.rotate(&local)
+        })
+    }
+}
+
+impl<B: Real> space::Area<Meters<3, B>> for Cuboid<B> {
+    type Amount = CubicMeters<B>;
+
+    fn amount_of_space(&self) -> Self::Amount {
+        let [x, y, z] = self.size().values();
+        CubicMeters::new(x * y * z)
+    }
+}
+
+/// A solid box about its centre, in its own frame: `m (b² + c²) / 12` about each axis.
+impl<B: Real> space::Inertia<Meters<3, B>, Kilograms<B>> for Cuboid<B> {
+    type Moment = InertiaTensor<B>;
+
+    fn moment_of_inertia(&self, mass: Kilograms<B>) -> Self::Moment {
+        let [x, y, z] = self.size().values();
+        let m = mass.value() / B::from_f32(12.0);
+
+        InertiaTensor::diagonal([m * (y * y + z * z), m * (x * x + z * z), m * (x * x + y * y)])
+    }
+}
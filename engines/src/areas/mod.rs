@@ -1,2 +1,20 @@
 mod boxes;
-pub use boxes::NonRotatingBox;
\ No newline at end of file
+pub use boxes::NonRotatingBox;
+
+mod oriented;
+pub use oriented::OrientedBox;
+
+mod circles;
+pub use circles::Circle;
+
+mod capsules;
+pub use capsules::Capsule;
+
+mod polygons;
+pub use polygons::{ConvexPolygon, MAX_VERTICES};
+
+mod shapes;
+pub use shapes::Shape;
+
+mod cuboids;
+pub use cuboids::Cuboid;
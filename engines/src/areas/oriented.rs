@@ -0,0 +1,81 @@
+use physics::{Real, space};
+use physics::collision::{Convex, Direction, Polygon};
+use physics::rotation::Rotation2;
+use physics::units::force::MomentOfInertia;
+use physics::units::mass::Kilograms;
+use physics::units::space::{Meters, SquareMeters};
+
+/// A box turned by `rotation` about its centre.
+#[derive(Copy, Clone)]
+pub struct OrientedBox<B: Real> {
+    pub center: Meters<2, B>,
+    pub half_extents: Meters<2, B>,
+    pub rotation: Rotation2<B>
+}
+
+impl<B: Real> OrientedBox<B> {
+    pub fn new(center: Meters<2, B>, size: Meters<2, B>, rotation: Rotation2<B>) -> Self {
+        Self {
+            center,
+            half_extents: size.scale(B::from_f32(0.5)),
+            rotation
+        }
+    }
+
+    pub fn size(&self) -> Meters<2, B> {
+        self.half_extents.scale(B::from_f32(2.0))
+    }
+}
+
+impl<B: Real> space::Area<Meters<2, B>> for OrientedBox<B> {
+    type Amount = SquareMeters<B>;
+
+    fn amount_of_space(&self) -> Self::Amount {
+        let [width, height] = self.size().values();
+        SquareMeters::new(width * height)
+    }
+}
+
+/// The same as an unturned box: `m (w² + h²) / 12`.
+impl<B: Real> space::Inertia<Meters<2, B>, Kilograms<B>> for OrientedBox<B> {
+    type Moment = MomentOfInertia<B>;
+
+    fn moment_of_inertia(&self, mass: Kilograms<B>) -> Self::Moment {
+        mass.scale(B::one() / B::from_f32(12.0)) * self.size().magnitude_squared()
+    }
+}
+
+impl<B: Real> Convex<B> for OrientedBox<B> {
+    fn support(&self, direction: &Direction<B>) -> Meters<2, B> {
+        let [x, y] = self.rotation.inverse().rotate(direction).values();
+        let [half_x, half_y] = self.half_extents.values();
+
+        let corner = Meters::from_components(&[
+            if x > B::zero() { half_x } else { -half_x },
+            if y > B::zero() { half_y } else { -half_y }
+        ]);
+        self.center + self.rotation.rotate(&corner)
+    }
+
+    fn polygon(&self) -> Option<&dyn Polygon<B>> {
+        Some(self)
+    }
+}
+
+impl<B: Real> Polygon<B> for OrientedBox<B> {
+    fn vertex_count(&self) -> usize {
+        4
+    }
+
+    fn vertex(&self, i: usize) -> Meters<2, B> {
+        let [x, y] = self.half_extents.values();
+        let corner = Meters::from_components(&match i {
+            0 => [-x, -y],
+            1 => [x, -y],
+            2 => [x, y],
+            _ => [-x, y]
+        });
+
+        self.center + self.rotation.rotate(&corner)
+    }
+}
@@ -0,0 +1,155 @@
+use physics::{Real, space};
+use physics::collision::{Convex, Direction, Polygon};
+use physics::rotation::Rotation2;
+use physics::units::force::MomentOfInertia;
+use physics::units::mass::Kilograms;
+use physics::units::space::{Meters, SquareMeters};
+
+/// The most vertices a `ConvexPolygon` can have.
+pub const MAX_VERTICES: usize = 8;
+
+/// A convex polygon of up to `MAX_VERTICES` corners, kept counter-clockwise.
+#[derive(Copy, Clone)]
+pub struct ConvexPolygon<B: Real> {
+    vertices: [Meters<2, B>; MAX_VERTICES],
+    count: usize
+}
+
+impl<B: Real> ConvexPolygon<B> {
+    /// The convex hull of `points`, or `None` if there are more than `MAX_VERTICES` of them
+    /// or they enclose no area.
+    pub fn new(points: &[Meters<2, B>]) -> Option<Self> {
+        if points.len() > MAX_VERTICES {
+            return None;
+        }
+
+        let mut sorted = [Meters::zero(); MAX_VERTICES];
+        let sorted = &mut sorted[..points.len()];
+        sorted.copy_from_slice(points);
+        sorted.sort_unstable_by(|a, b| {
+            a.values().partial_cmp(&b.values()).unwrap_or(core::cmp::Ordering::Equal)
+        });
+
+        // Andrew's monotone chain: the lower hull left to right, then the upper hull back.
+        let mut hull = [Meters::zero(); 2 * MAX_VERTICES];
+        let mut count = 0;
+        for pass in 0..2 {
+            let floor = count;
+            for i in 0..sorted.len() {
+                let point = if pass == 0 { sorted[i] } else { sorted[sorted.len() - 1 - i] };
+                while count >= floor + 2 && turn(hull[count - 2], hull[count - 1], point) <= B::zero() {
+                    count -= 1;
+                }
+                hull[count] = point;
+                count += 1;
+            }
+            // The last point of each half starts the other.
+            count -= 1;
+        }
+
+        if count < 3 {
+            return None;
+        }
+
+        let mut vertices = [Meters::zero(); MAX_VERTICES];
+        vertices[..count].copy_from_slice(&hull[..count]);
+        Some(Self {
+            vertices,
+            count
+        })
+    }
+
+    pub fn vertices(&self) -> &[Meters<2, B>] {
+        &self.vertices[..self.count]
+    }
+
+    /// The same polygon moved by `by`.
+    pub fn translated(&self, by: Meters<2, B>) -> Self {
+        let mut moved = *self;
+        for vertex in moved.vertices[..self.count].iter_mut() {
+            *vertex += by;
+        }
+        moved
+    }
+
+    /// The same polygon turned by `rotation` about the origin, then moved by `by`.
+    pub fn transformed(&self, rotation: &Rotation2<B>, by: Meters<2, B>) -> Self {
+        let mut moved = *self;
+        for vertex in moved.vertices[..self.count].iter_mut() {
+            *vertex = rotation.rotate(vertex) + by;
+        }
+        moved
+    }
+
+    /// The centre of its area.
+    pub fn centroid(&self) -> Meters<2, B> {
+        let origin = self.vertices[0];
+        let (twice_area, weighted) = self.fan().fold((B::zero(), Meters::zero()), |(area, sum), (a, b)| {
+            let cross = a.cross(&b).value();
+            (area + cross, sum + (a + b).scale(cross))
+        });
+
+        origin + weighted.scale(B::one() / (B::from_f32(3.0) * twice_area))
+    }
+
+    /// Each edge relative to the first vertex, as the two sides of a triangle from it.
+    fn fan(&self) -> impl Iterator<Item = (Meters<2, B>, Meters<2, B>)> + '_ {
+        let origin = self.vertices[0];
+        (1..self.count - 1).map(move |i| (self.vertices[i] - origin, self.vertices[i + 1] - origin))
+    }
+}
+
+/// Positive when `a`, `b`, `c` turn counter-clockwise.
+fn turn<B: Real>(a: Meters<2, B>, b: Meters<2, B>, c: Meters<2, B>) -> B {
+    (b - a).cross(&(c - a)).value()
+}
+
+impl<B: Real> space::Area<Meters<2, B>> for ConvexPolygon<B> {
+    type Amount = SquareMeters<B>;
+
+    fn amount_of_space(&self) -> Self::Amount {
+        let twice_area = self.fan().fold(B::zero(), |area, (a, b)| area + a.cross(&b).value());
+        SquareMeters::new(twice_area * B::from_f32(0.5))
+    }
+}
+
+/// Summed over the triangles of a fan from the first vertex, then moved to the centroid by the
+/// parallel axis theorem.
+impl<B: Real> space::Inertia<Meters<2, B>, Kilograms<B>> for ConvexPolygon<B> {
+    type Moment = MomentOfInertia<B>;
+
+    fn moment_of_inertia(&self, mass: Kilograms<B>) -> Self::Moment {
+        let (twice_area, second_moment) = self.fan().fold((B::zero(), B::zero()), |(area, moment), (a, b)| {
+            let cross = a.cross(&b).value();
+            let spread = a.dot(&a).value() + a.dot(&b).value() + b.dot(&b).value();
+            (area + cross, moment + cross * spread)
+        });
+
+        let about_first = second_moment / (B::from_f32(6.0) * twice_area);
+        let offset = (self.centroid() - self.vertices[0]).magnitude_squared().value();
+
+        mass * SquareMeters::new(about_first - offset)
+    }
+}
+
+impl<B: Real> Convex<B> for ConvexPolygon<B> {
+    fn support(&self, direction: &Direction<B>) -> Meters<2, B> {
+        self.vertices().iter().copied().fold(self.vertices[0], |best, vertex| {
+            if direction.dot(&vertex).value() > direction.dot(&best).value() { vertex } else { best }
+        })
+    }
+
+    fn polygon(&self) -> Option<&dyn Polygon<B>> {
+        Some(self)
+    }
+}
+
+impl<B: Real> Polygon<B> for ConvexPolygon<B> {
+    fn vertex_count(&self) -> usize {
+        self.count
+    }
+
+    fn vertex(&self, i: usize) -> Meters<2, B> {
+        self.vertices[i]
+    }
+}
@@ -0,0 +1,322 @@
+use physics::{Real, space};
+use physics::collision::{Convex, Direction, Polygon};
+use physics::rotation::Rotation2;
+use physics::units::force::MomentOfInertia;
+use physics::units::mass::Kilograms;
+use physics::units::space::{Meters, SquareMeters};
+use crate::broad_phase;
+use super::{Capsule, Circle, ConvexPolygon, NonRotatingBox, OrientedBox};
+
+/// Any of the shapes a body in the plane can take.
+#[derive(Copy, Clone)]
+pub enum Shape<B: Real> {
+    Circle(Circle<B>),
+    Capsule(Capsule<B>),
+    Box(OrientedBox<B>),
+    Polygon(ConvexPolygon<B>)
+}
+
+impl<B: Real> Shape<B> {
+    /// The centre of its area.
+    pub fn centroid(&self) -> Meters<2, B> {
+        match self {
+            Shape::Circle(circle) => circle.center,
+            Shape::Capsule(capsule) => capsule.center(),
+            Shape::Box(boxed) => boxed.center,
+            Shape::Polygon(polygon) => polygon.centroid()
+        }
+    }
+
+    /// The shape turned by `rotation` about the origin, then moved by `by`.
+    pub fn transformed(&self, rotation: &Rotation2<B>, by: Meters<2, B>) -> Self {
+        match self {
+            Shape::Circle(circle) => Shape::Circle(Circle::new(rotation.rotate(&circle.center) + by, circle.radius)),
+            Shape::Capsule(capsule) => Shape::Capsule(Capsule::new(
+                rotation.rotate(&capsule.start) + by,
+                rotation.rotate(&capsule.end) + by,
+                capsule.radius
+            )),
+            Shape::Box(boxed) => Shape::Box(OrientedBox {
+                center: rotation.rotate(&boxed.center) + by,
+                half_extents: boxed.half_extents,
+                rotation: *rotation * boxed.rotation
+            }),
+            Shape::Polygon(polygon) => Shape::Polygon(polygon.transformed(rotation, by))
+        }
+    }
+
+    /// The smallest axis-aligned box around the shape.
+    pub fn bounds(&self) -> NonRotatingBox<Meters<2, B>> {
+        broad_phase::bounds_of(self)
+    }
+}
+
+impl<B: Real> space::Area<Meters<2, B>> for Shape<B> {
+    type Amount = SquareMeters<B>;
+
+    fn amount_of_space(&self) -> Self::Amount {
+        match self {
+            Shape::Circle(circle) => circle.amount_of_space(),
+            Shape::Capsule(capsule) => capsule.amount_of_space(),
+            Shape::Box(boxed) => boxed.amount_of_space(),
+            Shape::Polygon(polygon) => polygon.amount_of_space()
+        }
+    }
+}
+
+impl<B: Real> space::Inertia<Meters<2, B>, Kilograms<B>> for Shape<B> {
+    type Moment = MomentOfInertia<B>;
+
+    fn moment_of_inertia(&self, mass: Kilograms<B>) -> Self::Moment {
+        match self {
+            Shape::Circle(circle) => circle.moment_of_inertia(mass),
+            Shape::Capsule(capsule) => capsule.moment_of_inertia(mass),
+            Shape::Box(boxed) => boxed.moment_of_inertia(mass),
+            Shape::Polygon(polygon) => polygon.moment_of_inertia(mass)
+        }
+    }
+}
+
+impl<B: Real> Convex<B> for Shape<B> {
+    fn support(&self, direction: &Direction<B>) -> Meters<2, B> {
+        match self {
+            Shape::Circle(circle) => circle.support(direction),
+            Shape::Capsule(capsule) => capsule.support(direction),
+            Shape::Box(boxed) => boxed.support(direction),
+            Shape::Polygon(polygon) => polygon.support(direction)
+        }
+    }
+
+    fn radius(&self) -> Meters<1, B> {
+        match self {
+            Shape::Circle(circle) => circle.radius(),
+            Shape::Capsule(capsule) => capsule.radius(),
+            Shape::Box(boxed) => boxed.radius(),
+            Shape::Polygon(polygon) => polygon.radius()
+        }
+    }
+
+    fn polygon(&self) -> Option<&dyn Polygon<B>> {
+        match self {
+            Shape::Circle(circle) => circle.polygon(),
+            Shape::Capsule(capsule) => capsule.polygon(),
+            Shape::Box(boxed) => boxed.polygon(),
+            Shape::Polygon(polygon) => polygon.polygon()
+        }
+    }
+}
+
+impl<B: Real> From<Circle<B>> for Shape<B> {
+    fn from(circle: Circle<B>) -> Self {
+        Shape::Circle(circle)
+    }
+}
+
+impl<B: Real> From<Capsule<B>> for Shape<B> {
+    fn from(capsule: Capsule<B>) -> Self {
+        Shape::Capsule(capsule)
+    }
+}
+
+impl<B: Real> From<OrientedBox<B>> for Shape<B> {
+    fn from(boxed: OrientedBox<B>) -> Self {
+        Shape::Box(boxed)
+    }
+}
+
+/// As an oriented box that isn't turned.
+impl<B: Real> From<NonRotatingBox<Meters<2, B>>> for Shape<B> {
+    fn from(boxed: NonRotatingBox<Meters<2, B>>) -> Self {
+        let (min, max) = (boxed.min(), boxed.max());
+        Shape::Box(OrientedBox::new((min + max).scale(B::from_f32(0.5)), max - min, Rotation2::identity()))
+    }
+}
+
+impl<B: Real> From<ConvexPolygon<B>> for Shape<B> {
+    fn from(polygon: ConvexPolygon<B>) -> Self {
+        Shape::Polygon(polygon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use core::f32::consts::FRAC_PI_2;
+    use physics::collision::{collide, overlaps, Manifold};
+    use super::*;
+
+    #[derive(Copy, Clone, Debug)]
+    enum Kind {
+        Aabb,
+        Obb,
+        Circle,
+        Capsule,
+        Polygon
+    }
+
+    const KINDS: [Kind; 5] = [Kind::Aabb, Kind::Obb, Kind::Circle, Kind::Capsule, Kind::Polygon];
+
+    fn at(x: f32, y: f32) -> Meters<2, f32> {
+        Meters::from_components(&[x, y])
+    }
+
+    /// A shape of each kind centred at `x` on the x axis, all reaching exactly 1 m to either
+    /// side of it, with a flat face or a round side there.
+    fn shape(kind: Kind, x: f32) -> Box<dyn Convex<f32>> {
+        match kind {
+            Kind::Aabb => Box::new(NonRotatingBox::new(at(x - 1.0, -1.0), at(x + 1.0, 1.0))),
+            Kind::Obb => Box::new(OrientedBox::new(at(x, 0.0), at(3.0, 2.0), Rotation2::from_angle(FRAC_PI_2))),
+            Kind::Circle => Box::new(Circle::new(at(x, 0.0), Meters::new(1.0))),
+            Kind::Capsule => Box::new(Capsule::new(at(x, -0.5), at(x, 0.5), Meters::new(1.0))),
+            Kind::Polygon => Box::new(ConvexPolygon::new(&[
+                at(1.0, -0.5), at(1.0, 0.5), at(0.5, 1.0), at(-0.5, 1.0),
+                at(-1.0, 0.5), at(-1.0, -0.5), at(-0.5, -1.0), at(0.5, -1.0)
+            ]).unwrap().translated(at(x, 0.0)))
+        }
+    }
+
+    /// Checks a manifold against one overlapping by `depth` on the `side` of the first shape,
+    /// which is 1 or -1, with its normal along `facing`.
+    fn check(manifold: &Manifold<f32>, facing: f32, side: f32, depth: f32, pair: &str) {
+        let [x, y] = manifold.normal.values();
+        assert!((x - facing).abs() < 1e-4 && y.abs() < 1e-4, "{pair}: normal {x}, {y}");
+        assert!((manifold.depth().value() - depth).abs() < 1e-4, "{pair}: depth {}", manifold.depth().value());
+
+        // Every contact lies halfway between the two surfaces
+        for contact in manifold.contacts() {
+            let middle = side * (1.0 - depth / 2.0);
+            assert!((contact.point.values()[0] - middle).abs() < 1e-4, "{pair}: contact at {:?}", contact.point.values());
+            assert!((contact.depth.value() - depth).abs() < 1e-4, "{pair}: contact depth {}", contact.depth.value());
+        }
+    }
+
+    #[test]
+    fn every_pair_of_shapes() {
+        for first in KINDS {
+            for second in KINDS {
+                let a = shape(first, 0.0);
+                for side in [1.0, -1.0] {
+                    for overlap in [-0.1, 0.0, 0.05, 0.6, 1.5] {
+                        let pair = alloc::format!("{first:?} against {second:?} overlapping by {overlap} on side {side}");
+                        let b = shape(second, side * (2.0 - overlap));
+
+                        if overlap < 0.0 {
+                            assert!(collide(a.as_ref(), b.as_ref()).is_none(), "{pair}");
+                            assert!(collide(b.as_ref(), a.as_ref()).is_none(), "{pair}");
+                            assert!(!overlaps(a.as_ref(), b.as_ref()), "{pair}");
+                            continue;
+                        }
+
+                        // Touching counts, with no depth
+                        assert!(overlaps(a.as_ref(), b.as_ref()), "{pair}");
+                        let manifold = collide(a.as_ref(), b.as_ref()).unwrap_or_else(|| panic!("{pair}: no contact"));
+                        check(&manifold, side, side, overlap, &pair);
+
+                        // Seen from the other shape, only the normal turns round
+                        let flipped = collide(b.as_ref(), a.as_ref()).unwrap_or_else(|| panic!("{pair}: no contact flipped"));
+                        check(&flipped, -side, side, overlap, &pair);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn flat_faces_touch_at_two_points() {
+        for first in [Kind::Aabb, Kind::Obb, Kind::Polygon] {
+            for second in [Kind::Aabb, Kind::Obb, Kind::Polygon] {
+                let manifold = collide(shape(first, 0.0).as_ref(), shape(second, 1.9).as_ref()).unwrap();
+                assert_eq!(manifold.contacts().len(), 2, "{first:?} against {second:?}");
+            }
+        }
+
+        let manifold = collide(shape(Kind::Aabb, 0.0).as_ref(), shape(Kind::Circle, 1.9).as_ref()).unwrap();
+        assert_eq!(manifold.contacts().len(), 1);
+    }
+
+    #[test]
+    fn diagonal_gaps_separate_boxes() {
+        // The bounds overlap, but the turned box only reaches towards the corner
+        let upright = OrientedBox::new(at(0.0, 0.0), at(2.0, 2.0), Rotation2::identity());
+        let turned = OrientedBox::new(at(1.9, 1.9), at(2.0, 2.0), Rotation2::from_angle(FRAC_PI_2 / 2.0));
+        assert!(broad_phase::bounds_of(&upright).overlaps(&broad_phase::bounds_of(&turned)));
+        assert!(collide(&upright, &turned).is_none());
+    }
+
+    #[test]
+    fn distances_reach_the_faces_of_long_boxes() {
+        // A bullet-sized box beside a 100 m wall, where rounding in the nearest point once
+        // turned the search back along the wall and left it at a diagonal
+        let wall = NonRotatingBox::new(at(1.0, -50.0), at(1.02, 50.0));
+        for i in 0..64 {
+            let turn = Rotation2::from_angle(i as f32 * 0.1);
+            let bullet = OrientedBox::new(at(0.9945 - i as f32 * 1e-4, 0.0), at(0.01, 0.005), turn);
+
+            let gap = 1.0 - bullet.support(&Direction::from_components(&[1.0, 0.0])).values()[0];
+            let closest = physics::collision::gjk::closest(&bullet, &wall).unwrap();
+            assert!((closest.distance.value() - gap).abs() < 1e-5, "turned {i}: {} for a gap of {gap}", closest.distance.value());
+        }
+    }
+
+    /// Hides that a shape is a polygon, so it goes through GJK and EPA instead of SAT.
+    struct Rounded<'a>(&'a Shape<f32>);
+
+    impl Convex<f32> for Rounded<'_> {
+        fn support(&self, direction: &Direction<f32>) -> Meters<2, f32> {
+            self.0.support(direction)
+        }
+    }
+
+    /// How far `a` reaches past `b` along `normal`.
+    fn overlap_along(a: &Shape<f32>, b: &Shape<f32>, normal: &Direction<f32>) -> f32 {
+        normal.dot(&a.support(normal)).value() - normal.dot(&b.support(&-*normal)).value()
+    }
+
+    #[test]
+    fn separating_axes_agree_with_gjk() {
+        let mut seed = 12345u32;
+        let mut random = || {
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            (seed >> 8) as f32 / (1u32 << 24) as f32
+        };
+
+        let mut overlapping = 0;
+        for _ in 0..2000 {
+            let mut polygon = || -> Shape<f32> {
+                let (centre, turn) = (at(random() * 2.0, random() * 2.0), Rotation2::from_angle(random() * 6.0));
+                if random() < 0.5 {
+                    OrientedBox::new(centre, at(0.5 + random(), 0.5 + random()), turn).into()
+                } else {
+                    let points: [Meters<2, f32>; 6] = core::array::from_fn(|_| at(random() - 0.5, random() - 0.5));
+                    ConvexPolygon::new(&points).map_or_else(
+                        || OrientedBox::new(centre, at(1.0, 1.0), turn).into(),
+                        |polygon| polygon.transformed(&turn, centre).into()
+                    )
+                }
+            };
+            let (p, q) = (polygon(), polygon());
+
+            match (collide(&p, &q), collide(&Rounded(&p), &Rounded(&q))) {
+                (Some(sat), Some(epa)) => {
+                    overlapping += 1;
+                    let (depth, other) = (sat.depth().value(), epa.depth().value());
+                    assert!((depth - other).abs() < 2e-3, "depths {depth} and {other}");
+
+                    // Where two faces tie they may pick different normals, but each must be
+                    // one the shapes overlap that little along
+                    for normal in [sat.normal, epa.normal] {
+                        let along = overlap_along(&p, &q, &normal);
+                        assert!((along - depth).abs() < 2e-3, "{along} along {:?} for {depth}", normal.values());
+                    }
+                },
+                (None, None) => {},
+                (sat, epa) => {
+                    // They may only disagree about shapes that barely touch
+                    let depth = sat.or(epa).unwrap().depth().value();
+                    assert!(depth < 1e-3, "only one found a contact {depth} deep");
+                }
+            }
+        }
+        assert!(overlapping > 300, "only {overlapping} pairs overlapped");
+    }
+}
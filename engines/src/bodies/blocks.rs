@@ -0,0 +1,118 @@
+use linalg::Matrix;
+use physics::{Body, Dynamics, Real, Universe};
+use physics::rotation::Rotation3;
+use physics::space::Inertia;
+use physics::units::{space, mass, force};
+use crate::areas;
+
+/// A solid box in 3D, matching a `models::d3::Cube` scaled to its size.
+pub struct Block<U: Universe> {
+    position: U::Space,
+    size: U::Space,
+    mass: U::Mass,
+    dynamics: Dynamics<U>
+}
+
+impl<B: Real, U> Block<U>
+    where U: Universe<
+        Space = space::Meters<3, B>,
+        Mass = mass::Kilograms<B>,
+        InverseMass = mass::InverseKilograms<B>,
+        InverseInertia = force::InverseInertiaTensor<B>,
+        Orientation = Rotation3<B>
+    >
+{
+    pub fn new(position: U::Space, size: U::Space, mass: U::Mass) -> Self {
+        let inertia = areas::Cuboid::new(position, size, Rotation3::identity()).moment_of_inertia(mass);
+
+        Self {
+            position,
+            size,
+            mass,
+            dynamics: Dynamics::dynamic(mass.inverse(), inertia.inverse())
+        }
+    }
+
+    pub fn size(&self) -> U::Space {
+        self.size
+    }
+
+    /// The model matrix that places a unit cube where this block is, in the same layout as
+    /// `world::Transform::matrix`.
+    pub fn model_matrix(&self) -> Matrix<f32, 4, 4> {
+        let r = self.dynamics.orientation.matrix();
+        let [x, y, z] = self.position.values().map(B::to_f32);
+        let scale = self.size.values().map(B::to_f32);
+
+        let mut m = [[0.0; 4]; 4];
+        for (i, row) in r.iter().enumerate() {
+            for (j, v) in row.iter().enumerate() {
+                m[i][j] = v.to_f32() * scale[j];
+            }
+        }
+        m[0][3] = x;
+        m[1][3] = y;
+        m[2][3] = z;
+        m[3][3] = 1.0;
+
+        Matrix::new(m)
+    }
+}
+
+impl<B: Real, U: Universe<Space = space::Meters<3, B>, Orientation = Rotation3<B>>> Body<U> for Block<U> {
+    type Boundary = areas::Cuboid<B>;
+
+    fn boundary(&self) -> Self::Boundary {
+        areas::Cuboid::new(self.position, self.size, self.dynamics.orientation)
+    }
+
+    fn position(&self) -> U::Space {
+        self.position
+    }
+
+    fn set_position(&mut self, position: U::Space) {
+        self.position = position;
+    }
+
+    fn mass(&self) -> U::Mass {
+        self.mass
+    }
+
+    fn dynamics(&self) -> &Dynamics<U> {
+        &self.dynamics
+    }
+
+    fn dynamics_mut(&mut self) -> &mut Dynamics<U> {
+        &mut self.dynamics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use linalg::Vector;
+    use crate::universes::D3Basic;
+    use super::*;
+
+    #[test]
+    fn model_matrix_matches_a_transform() {
+        let mut block = Block::<D3Basic<1>>::new(
+            space::Meters::from_components(&[1.0, -2.0, 0.5]),
+            space::Meters::from_components(&[2.0, 0.5, 3.0]),
+            mass::Kilograms::new(4.0)
+        );
+        let rotation = Rotation3::from_axis_angle([1.0, 2.0, -1.0], 0.8);
+        block.dynamics_mut().orientation = rotation;
+
+        let transform = ::world::Transform::new(
+            Vector::vector([1.0, -2.0, 0.5]),
+            ::world::Quaternion::new(rotation.x, rotation.y, rotation.z, rotation.w),
+            Vector::vector([2.0, 0.5, 3.0])
+        );
+        let (ours, theirs) = (block.model_matrix(), transform.matrix());
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((ours.value_at(i, j) - theirs.value_at(i, j)).abs() < 1e-5, "differ at {i}, {j}");
+            }
+        }
+    }
+}
@@ -1,25 +1,122 @@
-use physics::{Body, Universe};
-use physics::space::BaseUnit;
-use physics::units::{space, mass};
+use physics::{Body, Dynamics, Real, Universe};
+use physics::rotation::Rotation2;
+use physics::space::Inertia;
+use physics::units::{space, mass, force};
 use crate::areas;
 
 pub struct BasicEnemy<U: Universe> {
     position: U::Space,
-    bounding_box: areas::NonRotatingBox<U::Space>
+    /// The enemy's box before it turns, relative to the world.
+    bounding_box: areas::NonRotatingBox<U::Space>,
+    mass: U::Mass,
+    dynamics: Dynamics<U>
 }
 
-impl<B: BaseUnit, U: Universe<Space = space::Meters<2, B>, Mass = mass::Kilograms>> Body<U> for BasicEnemy<U> {
-    type Boundary = areas::NonRotatingBox<U::Space>;
+impl<B: Real, U> BasicEnemy<U>
+    where U: Universe<Space = space::Meters<2, B>, Mass = mass::Kilograms<B>, InverseMass = mass::InverseKilograms<B>, InverseInertia = force::InverseMomentOfInertia<B>>
+{
+    /// A dynamic enemy at `position`, with `bounding_box` given relative to the world. It
+    /// spins about its position as a solid box of its mass.
+    pub fn new(position: U::Space, bounding_box: areas::NonRotatingBox<U::Space>, mass: U::Mass) -> Self {
+        let inertia = bounding_box.moment_of_inertia(mass);
 
+        Self {
+            position,
+            bounding_box,
+            mass,
+            dynamics: Dynamics::dynamic(mass.inverse(), inertia.inverse())
+        }
+    }
+}
+
+impl<B: Real, U: Universe<Space = space::Meters<2, B>, Orientation = Rotation2<B>>> Body<U> for BasicEnemy<U> {
+    type Boundary = areas::OrientedBox<B>;
+
+    /// The bounding box, turned about the enemy's position to face the way it does.
     fn boundary(&self) -> Self::Boundary {
-        self.bounding_box
+        let (min, max) = (self.bounding_box.min(), self.bounding_box.max());
+        let rotation = self.dynamics.orientation;
+        let offset = (min + max).scale(B::from_f32(0.5)) - self.position;
+
+        areas::OrientedBox::new(self.position + rotation.rotate(&offset), max - min, rotation)
     }
 
     fn position(&self) -> U::Space {
         self.position
     }
 
+    /// Moves the bounding box along with the enemy.
+    fn set_position(&mut self, position: U::Space) {
+        self.bounding_box = self.bounding_box.translated(position - self.position);
+        self.position = position;
+    }
+
     fn mass(&self) -> U::Mass {
-        mass::Kilograms::new(5)
+        self.mass
     }
-}
\ No newline at end of file
+
+    fn dynamics(&self) -> &Dynamics<U> {
+        &self.dynamics
+    }
+
+    fn dynamics_mut(&mut self) -> &mut Dynamics<U> {
+        &mut self.dynamics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::FRAC_PI_2;
+    use physics::units::motion::AngularVelocity;
+    use physics::units::time::Seconds;
+    use crate::broad_phase::bounds_of;
+    use crate::universes::D2Basic;
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-5
+    }
+
+    /// A 4 m by 2 m enemy at the origin, whose box sits 1 m to its right.
+    fn enemy() -> BasicEnemy<D2Basic> {
+        let bounding_box = areas::NonRotatingBox::new(
+            space::Meters::from_components(&[-1.0, -1.0]),
+            space::Meters::from_components(&[3.0, 1.0])
+        );
+        BasicEnemy::new(space::Meters::zero(), bounding_box, mass::Kilograms::new(2.0))
+    }
+
+    #[test]
+    fn boundary_turns_with_the_enemy() {
+        let mut enemy = enemy();
+        let bounds = bounds_of(&enemy.boundary());
+        assert_eq!(bounds.min().values(), [-1.0, -1.0]);
+        assert_eq!(bounds.max().values(), [3.0, 1.0]);
+
+        // A quarter turn about its position swaps width for height and swings the box upwards
+        let dynamics = enemy.dynamics_mut();
+        dynamics.angular_velocity = AngularVelocity::new(FRAC_PI_2);
+        dynamics.turn(Seconds::new(1.0));
+
+        let [low_x, low_y] = bounds_of(&enemy.boundary()).min().values();
+        let [high_x, high_y] = bounds_of(&enemy.boundary()).max().values();
+        assert!(close(low_x, -1.0) && close(low_y, -1.0), "{low_x}, {low_y}");
+        assert!(close(high_x, 1.0) && close(high_y, 3.0), "{high_x}, {high_y}");
+    }
+
+    #[test]
+    fn boundary_moves_with_the_enemy() {
+        let mut enemy = enemy();
+        enemy.set_position(space::Meters::from_components(&[5.0, 0.0]));
+
+        let bounds = bounds_of(&enemy.boundary());
+        assert!(close(bounds.min().values()[0], 4.0) && close(bounds.max().values()[0], 8.0));
+    }
+
+    #[test]
+    fn spins_as_a_solid_box() {
+        // I = m (w² + h²) / 12 = 2 (16 + 4) / 12
+        let inverse = enemy().dynamics().inverse_inertia().value();
+        assert!(close(inverse, 12.0 / 40.0));
+    }
+}
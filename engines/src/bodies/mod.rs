@@ -1 +1,3 @@
-pub mod enemies;
\ No newline at end of file
+pub mod enemies;
+pub mod blocks;
+pub mod rigid;
@@ -0,0 +1,159 @@
+use physics::{Body, Dynamics, Real, Universe};
+use physics::rotation::Rotation2;
+use physics::space::Inertia;
+use physics::units::{force, mass, motion, space};
+use crate::areas::Shape;
+use crate::queries::DEFAULT_LAYERS;
+use crate::solver::Material;
+
+/// A solid body of any `Shape`, free to move and turn in the plane.
+pub struct RigidBody<U: Universe> where U::Scalar: Real {
+    position: U::Space,
+    /// Centred on `position`, as it lies when the body is unturned.
+    shape: Shape<U::Scalar>,
+    mass: U::Mass,
+    pub material: Material<U::Scalar>,
+    /// A bit for each layer the body is on.
+    pub layers: u32,
+    /// A bit for each layer the body collides with. Two bodies collide only if each is on a
+    /// layer the other collides with.
+    pub mask: u32,
+    /// Whether the body only notes what it overlaps, without pushing or being pushed.
+    pub sensor: bool,
+    /// Whether the body is swept over each step, so that it can't pass through others however
+    /// fast it moves.
+    pub continuous: bool,
+    /// Whether the body may fall asleep once it comes to rest. Those that can't keep anything
+    /// they touch awake too.
+    pub can_sleep: bool,
+    dynamics: Dynamics<U>
+}
+
+impl<B: Real, U> RigidBody<U>
+    where U: Universe<
+        Scalar = B,
+        Space = space::Meters<2, B>,
+        Mass = mass::Kilograms<B>,
+        InverseMass = mass::InverseKilograms<B>,
+        InverseInertia = force::InverseMomentOfInertia<B>,
+        Velocity = motion::Velocity<2, B>,
+        AngularVelocity = motion::AngularVelocity<1, B>
+    >
+{
+    /// A body filling `shape`, given where it lies in the world, with its position at the
+    /// shape's centroid. It spins as a solid of its mass.
+    pub fn dynamic(shape: impl Into<Shape<B>>, mass: U::Mass) -> Self {
+        let shape = shape.into();
+        let inertia = shape.moment_of_inertia(mass);
+
+        Self::placed(shape, mass, Dynamics::dynamic(mass.inverse(), inertia.inverse()))
+    }
+
+    /// A body that never moves, such as the ground.
+    pub fn fixed(shape: impl Into<Shape<B>>) -> Self {
+        Self::placed(shape.into(), mass::Kilograms::zero(), Dynamics::fixed())
+    }
+
+    /// A body that moves as it is told, pushing dynamic bodies aside but never pushed back.
+    pub fn kinematic(shape: impl Into<Shape<B>>, velocity: U::Velocity, angular_velocity: U::AngularVelocity) -> Self {
+        Self::placed(shape.into(), mass::Kilograms::zero(), Dynamics::kinematic(velocity, angular_velocity))
+    }
+
+    fn placed(shape: Shape<B>, mass: U::Mass, dynamics: Dynamics<U>) -> Self {
+        let position = shape.centroid();
+
+        Self {
+            position,
+            shape: shape.transformed(&Rotation2::identity(), -position),
+            mass,
+            material: Material::default(),
+            layers: DEFAULT_LAYERS,
+            mask: u32::MAX,
+            sensor: false,
+            continuous: false,
+            can_sleep: true,
+            dynamics
+        }
+    }
+
+    pub fn material(self, material: Material<B>) -> Self {
+        Self {
+            material,
+            ..self
+        }
+    }
+
+    pub fn layers(self, layers: u32) -> Self {
+        Self {
+            layers,
+            ..self
+        }
+    }
+
+    pub fn mask(self, mask: u32) -> Self {
+        Self {
+            mask,
+            ..self
+        }
+    }
+
+    pub fn sensor(self, sensor: bool) -> Self {
+        Self {
+            sensor,
+            ..self
+        }
+    }
+
+    pub fn continuous(self, continuous: bool) -> Self {
+        Self {
+            continuous,
+            ..self
+        }
+    }
+
+    pub fn can_sleep(self, can_sleep: bool) -> Self {
+        Self {
+            can_sleep,
+            ..self
+        }
+    }
+
+    /// Whether the layers and masks of this body and `other` let them collide.
+    pub fn can_collide(&self, other: &Self) -> bool {
+        self.layers & other.mask != 0 && other.layers & self.mask != 0
+    }
+
+    /// The shape about the body's position, as it lies unturned.
+    pub fn shape(&self) -> &Shape<B> {
+        &self.shape
+    }
+}
+
+impl<B: Real, U: Universe<Scalar = B, Space = space::Meters<2, B>, Orientation = Rotation2<B>>> Body<U> for RigidBody<U> {
+    type Boundary = Shape<B>;
+
+    /// The shape where the body is now, turned as it faces.
+    fn boundary(&self) -> Self::Boundary {
+        self.shape.transformed(&self.dynamics.orientation, self.position)
+    }
+
+    fn position(&self) -> U::Space {
+        self.position
+    }
+
+    fn set_position(&mut self, position: U::Space) {
+        self.position = position;
+    }
+
+    fn mass(&self) -> U::Mass {
+        self.mass
+    }
+
+    fn dynamics(&self) -> &Dynamics<U> {
+        &self.dynamics
+    }
+
+    fn dynamics_mut(&mut self) -> &mut Dynamics<U> {
+        &mut self.dynamics
+    }
+}
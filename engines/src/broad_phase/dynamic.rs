@@ -0,0 +1,312 @@
+use alloc::vec::Vec;
+use physics::Real;
+use physics::units::space::Meters;
+use super::{BroadPhase, Bounds, Slots, ordered, settle};
+
+struct Node<const D: usize, B: Real> {
+    bounds: Bounds<D, B>,
+    /// For leaves, the box as it was given, without the margin.
+    held: Bounds<D, B>,
+    parent: Option<usize>,
+    children: [usize; 2],
+    /// Zero for leaves, which hold `key` and have no children.
+    height: usize,
+    key: usize
+}
+
+/// A balanced tree of boxes around boxes, built as they are inserted, in the manner of Box2D.
+/// Leaves are given a margin so that small movements need no change to the tree, which makes
+/// it the most general choice for bodies of mixed sizes and speeds. Pairs and queries still
+/// only report boxes that overlap as given.
+pub struct DynamicTree<const D: usize, B: Real> {
+    nodes: Vec<Node<D, B>>,
+    free: Vec<usize>,
+    root: Option<usize>,
+    leaves: Slots<usize>,
+    margin: Meters<1, B>
+}
+
+impl<const D: usize, B: Real> DynamicTree<D, B> {
+    /// A tree whose leaves reach `margin` beyond the boxes they hold.
+    pub fn new(margin: Meters<1, B>) -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            leaves: Slots::new(),
+            margin
+        }
+    }
+
+    /// The height of the tree, with a lone leaf at zero.
+    pub fn height(&self) -> usize {
+        self.root.map_or(0, |root| self.nodes[root].height)
+    }
+
+    fn allocate(&mut self, node: Node<D, B>) -> usize {
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            },
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn is_leaf(&self, node: usize) -> bool {
+        self.nodes[node].height == 0
+    }
+
+    /// Hangs `leaf` beside whichever node makes the tree grow least, then rebalances upwards.
+    fn insert_leaf(&mut self, leaf: usize) {
+        let Some(root) = self.root else {
+            self.root = Some(leaf);
+            self.nodes[leaf].parent = None;
+            return;
+        };
+
+        let bounds = self.nodes[leaf].bounds;
+        let mut sibling = root;
+        while !self.is_leaf(sibling) {
+            let node = &self.nodes[sibling];
+            let extent = node.bounds.extent();
+            let combined = node.bounds.merged(&bounds).extent();
+
+            // Pairing here adds a new parent; going deeper grows every ancestor instead.
+            let here = combined + combined;
+            let inherited = (combined - extent) + (combined - extent);
+
+            let descend = |child: usize| {
+                let child = &self.nodes[child];
+                let grown = child.bounds.merged(&bounds).extent();
+                if child.height == 0 { grown + inherited } else { grown - child.bounds.extent() + inherited }
+            };
+            let [first, second] = node.children;
+            let (cost_first, cost_second) = (descend(first), descend(second));
+
+            if here < cost_first && here < cost_second {
+                break;
+            }
+            sibling = if cost_first < cost_second { first } else { second };
+        }
+
+        let old_parent = self.nodes[sibling].parent;
+        let merged = self.nodes[sibling].bounds.merged(&bounds);
+        let parent = self.allocate(Node {
+            bounds: merged,
+            held: merged,
+            parent: old_parent,
+            children: [sibling, leaf],
+            height: self.nodes[sibling].height + 1,
+            key: 0
+        });
+
+        match old_parent {
+            Some(old) => self.replace_child(old, sibling, parent),
+            None => self.root = Some(parent)
+        }
+        self.nodes[sibling].parent = Some(parent);
+        self.nodes[leaf].parent = Some(parent);
+
+        self.refit(Some(parent));
+    }
+
+    fn remove_leaf(&mut self, leaf: usize) {
+        if self.root == Some(leaf) {
+            self.root = None;
+            return;
+        }
+
+        let parent = self.nodes[leaf].parent.expect("only the root has no parent");
+        let [first, second] = self.nodes[parent].children;
+        let sibling = if first == leaf { second } else { first };
+        let grandparent = self.nodes[parent].parent;
+
+        self.free.push(parent);
+        self.nodes[sibling].parent = grandparent;
+        match grandparent {
+            Some(grandparent) => {
+                self.replace_child(grandparent, parent, sibling);
+                self.refit(Some(grandparent));
+            },
+            None => self.root = Some(sibling)
+        }
+    }
+
+    fn replace_child(&mut self, parent: usize, old: usize, new: usize) {
+        let children = &mut self.nodes[parent].children;
+        if children[0] == old {
+            children[0] = new;
+        } else {
+            children[1] = new;
+        }
+    }
+
+    /// Rebalances and recomputes the bounds and height of `node` and every ancestor.
+    fn refit(&mut self, mut node: Option<usize>) {
+        while let Some(index) = node {
+            let index = self.balance(index);
+            self.fit(index);
+            node = self.nodes[index].parent;
+        }
+    }
+
+    fn fit(&mut self, node: usize) {
+        let [first, second] = self.nodes[node].children;
+        self.nodes[node].bounds = self.nodes[first].bounds.merged(&self.nodes[second].bounds);
+        self.nodes[node].height = 1 + self.nodes[first].height.max(self.nodes[second].height);
+    }
+
+    /// Rotates the taller grandchild up if one child of `top` is more than one level taller
+    /// than the other, returning the node now in its place.
+    fn balance(&mut self, top: usize) -> usize {
+        if self.nodes[top].height < 2 {
+            return top;
+        }
+
+        let [first, second] = self.nodes[top].children;
+        let (first_height, second_height) = (self.nodes[first].height, self.nodes[second].height);
+
+        if second_height > first_height + 1 {
+            self.rotate(top, 1)
+        } else if first_height > second_height + 1 {
+            self.rotate(top, 0)
+        } else {
+            top
+        }
+    }
+
+    /// Lifts child `side` of `top` into its place. `top` takes the lifted node's shorter
+    /// child in exchange, and the lifted node keeps its taller one.
+    fn rotate(&mut self, top: usize, side: usize) -> usize {
+        let lifted = self.nodes[top].children[side];
+        let [first, second] = self.nodes[lifted].children;
+        let (taller, shorter) = if self.nodes[first].height > self.nodes[second].height {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        let above = self.nodes[top].parent;
+        self.nodes[lifted].parent = above;
+        match above {
+            Some(above) => self.replace_child(above, top, lifted),
+            None => self.root = Some(lifted)
+        }
+
+        self.nodes[lifted].children = [top, taller];
+        self.nodes[top].parent = Some(lifted);
+        self.nodes[top].children[side] = shorter;
+        self.nodes[shorter].parent = Some(top);
+
+        self.fit(top);
+        self.fit(lifted);
+        lifted
+    }
+
+    fn visit(&self, bounds: &Bounds<D, B>, mut found: impl FnMut(usize)) {
+        let mut pending: Vec<usize> = self.root.into_iter().collect();
+
+        while let Some(node) = pending.pop() {
+            let node = &self.nodes[node];
+            if !node.bounds.overlaps(bounds) {
+                continue;
+            }
+
+            if node.height == 0 {
+                if node.held.overlaps(bounds) {
+                    found(node.key);
+                }
+            } else {
+                pending.extend_from_slice(&node.children);
+            }
+        }
+    }
+}
+
+impl<const D: usize, B: Real> BroadPhase<D, B> for DynamicTree<D, B> {
+    fn insert(&mut self, key: usize, bounds: Bounds<D, B>) {
+        if self.leaves.get(key).is_some() {
+            return self.update(key, bounds);
+        }
+
+        let leaf = self.allocate(Node {
+            bounds: bounds.grown(self.margin),
+            held: bounds,
+            parent: None,
+            children: [0, 0],
+            height: 0,
+            key
+        });
+        self.leaves.set(key, leaf);
+        self.insert_leaf(leaf);
+    }
+
+    fn update(&mut self, key: usize, bounds: Bounds<D, B>) {
+        let Some(&leaf) = self.leaves.get(key) else {
+            return self.insert(key, bounds);
+        };
+
+        self.nodes[leaf].held = bounds;
+        if self.nodes[leaf].bounds.contains(&bounds) {
+            return;
+        }
+
+        self.remove_leaf(leaf);
+        self.nodes[leaf].bounds = bounds.grown(self.margin);
+        self.insert_leaf(leaf);
+    }
+
+    fn remove(&mut self, key: usize) {
+        if let Some(leaf) = self.leaves.take(key) {
+            self.remove_leaf(leaf);
+            self.free.push(leaf);
+        }
+    }
+
+    fn pairs(&mut self, pairs: &mut Vec<(usize, usize)>) {
+        pairs.clear();
+
+        // Within each subtree, then across the two halves of every branch.
+        let mut within: Vec<usize> = self.root.into_iter().collect();
+        let mut across = Vec::new();
+        while let Some(node) = within.pop() {
+            if self.is_leaf(node) {
+                continue;
+            }
+            let [first, second] = self.nodes[node].children;
+            within.extend_from_slice(&[first, second]);
+            across.push((first, second));
+
+            while let Some((a, b)) = across.pop() {
+                let (node_a, node_b) = (&self.nodes[a], &self.nodes[b]);
+                if !node_a.bounds.overlaps(&node_b.bounds) {
+                    continue;
+                }
+
+                match (node_a.height, node_b.height) {
+                    (0, 0) => if node_a.held.overlaps(&node_b.held) {
+                        pairs.push(ordered(node_a.key, node_b.key));
+                    },
+                    (height_a, height_b) if height_b == 0 || (height_a != 0 && height_a >= height_b) => {
+                        across.extend(node_a.children.map(|child| (child, b)));
+                    },
+                    _ => across.extend(node_b.children.map(|child| (a, child)))
+                }
+            }
+        }
+
+        settle(pairs);
+    }
+
+    fn query(&self, bounds: &Bounds<D, B>, found: &mut dyn FnMut(usize)) {
+        let mut keys = Vec::new();
+        self.visit(bounds, |key| keys.push(key));
+
+        keys.sort_unstable();
+        keys.into_iter().for_each(found);
+    }
+}
@@ -0,0 +1,188 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use physics::Real;
+use physics::units::space::Meters;
+use super::{BroadPhase, Bounds, Slots, ordered, settle};
+
+type Cell<const D: usize> = [i64; D];
+
+/// The most cells a box is listed in. Larger boxes, and those reaching beyond where cells can
+/// be numbered, are kept to one side and tested against everything.
+const MAX_CELLS: u64 = 256;
+
+struct Entry<const D: usize, B: Real> {
+    bounds: Bounds<D, B>,
+    /// The first and last cells it is listed in, or `None` if it is oversized.
+    span: Option<(Cell<D>, Cell<D>)>
+}
+
+/// A uniform grid of cubic cells, each listing the boxes that touch it. Best when bodies are
+/// of similar size and the cells a little larger than them.
+pub struct SpatialHash<const D: usize, B: Real> {
+    cell_size: Meters<1, B>,
+    cells: BTreeMap<Cell<D>, Vec<usize>>,
+    oversized: Vec<usize>,
+    entries: Slots<Entry<D, B>>
+}
+
+impl<const D: usize, B: Real> SpatialHash<D, B> {
+    pub fn new(cell_size: Meters<1, B>) -> Self {
+        assert!(cell_size.value() > B::zero(), "cells must have a size");
+
+        Self {
+            cell_size,
+            cells: BTreeMap::new(),
+            oversized: Vec::new(),
+            entries: Slots::new()
+        }
+    }
+
+    /// The cell holding `point`, if its number fits comfortably in an `i64`.
+    fn cell_of(&self, point: Meters<D, B>) -> Option<Cell<D>> {
+        let size = self.cell_size.value();
+        let limit = B::from_f32(i64::MAX as f32 / 4.0);
+        let cells = point.values().map(|v| (v / size).floor());
+        if cells.iter().any(|cell| !cell.is_finite() || cell.abs() >= limit) {
+            return None;
+        }
+
+        Some(cells.map(|cell| cell.to_f32() as i64))
+    }
+
+    /// The first and last cells `bounds` covers, or `None` if that is more than `MAX_CELLS`.
+    fn span(&self, bounds: &Bounds<D, B>) -> Option<(Cell<D>, Cell<D>)> {
+        let (low, high) = (self.cell_of(bounds.min())?, self.cell_of(bounds.max())?);
+        let count = (0..D).try_fold(1u64, |count, i| {
+            count.checked_mul(high[i].abs_diff(low[i]) + 1).filter(|&count| count <= MAX_CELLS)
+        });
+
+        count.map(|_| (low, high))
+    }
+
+    fn add(&mut self, key: usize, span: Option<(Cell<D>, Cell<D>)>) {
+        match span {
+            Some((low, high)) => each_cell(low, high, |cell| self.cells.entry(cell).or_default().push(key)),
+            None => self.oversized.push(key)
+        }
+    }
+
+    fn subtract(&mut self, key: usize, span: Option<(Cell<D>, Cell<D>)>) {
+        let Some((low, high)) = span else {
+            return self.oversized.retain(|&k| k != key);
+        };
+
+        each_cell(low, high, |cell| {
+            if let Some(keys) = self.cells.get_mut(&cell) {
+                keys.retain(|&k| k != key);
+                if keys.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        });
+    }
+}
+
+/// Calls `visit` with every cell from `low` to `high` inclusive.
+fn each_cell<const D: usize>(low: Cell<D>, high: Cell<D>, mut visit: impl FnMut(Cell<D>)) {
+    if (0..D).any(|i| low[i] > high[i]) {
+        return;
+    }
+
+    let mut cell = low;
+    loop {
+        visit(cell);
+
+        let mut axis = 0;
+        loop {
+            if axis == D {
+                return;
+            }
+            if cell[axis] < high[axis] {
+                cell[axis] += 1;
+                break;
+            }
+            cell[axis] = low[axis];
+            axis += 1;
+        }
+    }
+}
+
+impl<const D: usize, B: Real> BroadPhase<D, B> for SpatialHash<D, B> {
+    fn insert(&mut self, key: usize, bounds: Bounds<D, B>) {
+        if self.entries.get(key).is_some() {
+            return self.update(key, bounds);
+        }
+
+        let span = self.span(&bounds);
+        self.add(key, span);
+        self.entries.set(key, Entry { bounds, span });
+    }
+
+    fn update(&mut self, key: usize, bounds: Bounds<D, B>) {
+        let span = self.span(&bounds);
+        let Some(entry) = self.entries.get_mut(key) else {
+            return self.insert(key, bounds);
+        };
+
+        entry.bounds = bounds;
+        if entry.span == span {
+            return;
+        }
+
+        let old = core::mem::replace(&mut entry.span, span);
+        self.subtract(key, old);
+        self.add(key, span);
+    }
+
+    fn remove(&mut self, key: usize) {
+        if let Some(entry) = self.entries.take(key) {
+            self.subtract(key, entry.span);
+        }
+    }
+
+    fn pairs(&mut self, pairs: &mut Vec<(usize, usize)>) {
+        pairs.clear();
+
+        for keys in self.cells.values() {
+            for (i, &a) in keys.iter().enumerate() {
+                let bounds = &self.entries.get(a).expect("cells list tracked keys").bounds;
+                for &b in &keys[i + 1..] {
+                    if bounds.overlaps(&self.entries.get(b).expect("cells list tracked keys").bounds) {
+                        pairs.push(ordered(a, b));
+                    }
+                }
+            }
+        }
+
+        for &a in &self.oversized {
+            let bounds = &self.entries.get(a).expect("oversized boxes are tracked").bounds;
+            pairs.extend(self.entries.iter()
+                .filter(|&(b, entry)| b != a && bounds.overlaps(&entry.bounds))
+                .map(|(b, _)| ordered(a, b)));
+        }
+
+        settle(pairs);
+    }
+
+    fn query(&self, bounds: &Bounds<D, B>, found: &mut dyn FnMut(usize)) {
+        let overlapping = |&key: &usize| self.entries.get(key).is_some_and(|entry| entry.bounds.overlaps(bounds));
+
+        // A query too large for the grid looks at everything instead.
+        let Some((low, high)) = self.span(bounds) else {
+            return self.entries.iter()
+                .filter(|(_, entry)| entry.bounds.overlaps(bounds))
+                .for_each(|(key, _)| found(key));
+        };
+
+        let mut keys: Vec<usize> = self.oversized.iter().copied().filter(overlapping).collect();
+        each_cell(low, high, |cell| {
+            if let Some(listed) = self.cells.get(&cell) {
+                keys.extend(listed.iter().copied().filter(overlapping));
+            }
+        });
+
+        keys.sort_unstable();
+        keys.dedup();
+        keys.into_iter().for_each(found);
+    }
+}
@@ -0,0 +1,247 @@
+//! Broad-phase structures, which find the pairs of bounding boxes that might overlap without
+//! testing every box against every other. Boxes are tracked under keys the caller chooses,
+//! such as the index of a body.
+use alloc::vec::Vec;
+use physics::Real;
+use physics::collision::{Convex, Direction};
+use physics::units::space::Meters;
+use crate::areas::NonRotatingBox;
+
+mod grid;
+pub use grid::SpatialHash;
+
+mod tree;
+pub use tree::{Octree, Orthtree, Quadtree};
+
+mod sweep;
+pub use sweep::SweepAndPrune;
+
+mod dynamic;
+pub use dynamic::DynamicTree;
+
+/// An axis-aligned bounding box in `D` dimensions.
+pub type Bounds<const D: usize, B> = NonRotatingBox<Meters<D, B>>;
+
+/// The bounding box of a shape in the plane, found through its furthest points along each axis.
+pub fn bounds_of<B: Real, C: Convex<B> + ?Sized>(shape: &C) -> Bounds<2, B> {
+    let along = |x: f32, y: f32| shape.support(&Direction::from_components(&[B::from_f32(x), B::from_f32(y)]));
+    let radius = shape.radius().value();
+
+    NonRotatingBox::new(
+        Meters::from_components(&[along(-1.0, 0.0).value_at(0) - radius, along(0.0, -1.0).value_at(1) - radius]),
+        Meters::from_components(&[along(1.0, 0.0).value_at(0) + radius, along(0.0, 1.0).value_at(1) + radius])
+    )
+}
+
+pub trait BroadPhase<const D: usize, B: Real> {
+    /// Starts tracking `bounds` under `key`, or moves them if `key` is already tracked.
+    fn insert(&mut self, key: usize, bounds: Bounds<D, B>);
+
+    /// Moves the bounds tracked under `key`.
+    fn update(&mut self, key: usize, bounds: Bounds<D, B>);
+
+    fn remove(&mut self, key: usize);
+
+    /// Replaces the contents of `pairs` with every pair of keys whose bounds overlap, each once
+    /// as `(lower, higher)`, in order.
+    fn pairs(&mut self, pairs: &mut Vec<(usize, usize)>);
+
+    /// Calls `found` once for every key whose bounds overlap `bounds`, in order.
+    fn query(&self, bounds: &Bounds<D, B>, found: &mut dyn FnMut(usize));
+}
+
+/// Values stored by key, for keys that are small indices.
+pub(crate) struct Slots<T> {
+    slots: Vec<Option<T>>
+}
+
+impl<T> Slots<T> {
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new()
+        }
+    }
+
+    pub fn get(&self, key: usize) -> Option<&T> {
+        self.slots.get(key).and_then(Option::as_ref)
+    }
+
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        self.slots.get_mut(key).and_then(Option::as_mut)
+    }
+
+    pub fn set(&mut self, key: usize, value: T) {
+        if key >= self.slots.len() {
+            self.slots.resize_with(key + 1, || None);
+        }
+        self.slots[key] = Some(value);
+    }
+
+    pub fn take(&mut self, key: usize) -> Option<T> {
+        self.slots.get_mut(key).and_then(Option::take)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.slots.iter().enumerate().filter_map(|(key, slot)| slot.as_ref().map(|value| (key, value)))
+    }
+}
+
+/// The pair of two keys in the order `BroadPhase::pairs` reports it.
+pub(crate) fn ordered(a: usize, b: usize) -> (usize, usize) {
+    if a < b { (a, b) } else { (b, a) }
+}
+
+/// Puts found pairs in order and drops repeats.
+pub(crate) fn settle(pairs: &mut Vec<(usize, usize)>) {
+    pairs.sort_unstable();
+    pairs.dedup();
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use super::*;
+
+    const COUNT: usize = 200;
+
+    fn random(seed: &mut u32) -> f32 {
+        *seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+        (*seed >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// A box somewhere in a 60 m world, now and then a large one.
+    fn random_bounds<const D: usize>(seed: &mut u32) -> Bounds<D, f32> {
+        let centre: [f32; D] = core::array::from_fn(|_| random(seed) * 60.0 - 5.0);
+        let half: [f32; D] = core::array::from_fn(|_| 0.2 + random(seed) * if random(seed) < 0.05 { 10.0 } else { 1.5 });
+
+        Bounds::new(
+            Meters::from_components(&core::array::from_fn(|i| centre[i] - half[i])),
+            Meters::from_components(&core::array::from_fn(|i| centre[i] + half[i]))
+        )
+    }
+
+    fn brute_force<const D: usize>(all: &[Option<Bounds<D, f32>>]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (a, first) in all.iter().enumerate() {
+            for (b, second) in all.iter().enumerate().skip(a + 1) {
+                if let (Some(first), Some(second)) = (first, second) {
+                    if first.overlaps(second) {
+                        pairs.push((a, b));
+                    }
+                }
+            }
+        }
+
+        pairs
+    }
+
+    /// Inserts, moves, removes and re-inserts boxes, checking pairs and queries against brute
+    /// force after every round.
+    fn matches_brute_force<const D: usize>(mut broad_phase: impl BroadPhase<D, f32>, mut seed: u32) {
+        let mut all: Vec<Option<Bounds<D, f32>>> = (0..COUNT).map(|_| Some(random_bounds(&mut seed))).collect();
+        for (key, bounds) in all.iter().enumerate() {
+            broad_phase.insert(key, bounds.unwrap());
+        }
+
+        let mut pairs = Vec::new();
+        for round in 0..12 {
+            broad_phase.pairs(&mut pairs);
+            assert_eq!(pairs, brute_force(&all), "pairs in round {round}");
+
+            let region = random_bounds::<D>(&mut seed);
+            let mut found = Vec::new();
+            broad_phase.query(&region, &mut |key| found.push(key));
+            let expected: Vec<usize> = (0..COUNT).filter(|&key| all[key].is_some_and(|bounds| bounds.overlaps(&region))).collect();
+            assert_eq!(found, expected, "query in round {round}");
+
+            // Every few rounds some boxes jump a long way
+            let reach = if round % 4 == 0 { 20.0 } else { 0.5 };
+            for (key, slot) in all.iter_mut().enumerate() {
+                let roll = random(&mut seed);
+                if roll < 0.05 {
+                    broad_phase.remove(key);
+                    *slot = None;
+                } else if roll < 0.08 {
+                    let bounds = random_bounds(&mut seed);
+                    broad_phase.insert(key, bounds);
+                    *slot = Some(bounds);
+                } else if let Some(bounds) = slot {
+                    let by: [f32; D] = core::array::from_fn(|_| (random(&mut seed) - 0.5) * reach);
+                    *bounds = bounds.translated(Meters::from_components(&by));
+                    broad_phase.update(key, *bounds);
+                }
+            }
+        }
+    }
+
+    fn region<const D: usize>() -> Bounds<D, f32> {
+        Bounds::new(Meters::from_components(&[-10.0; D]), Meters::from_components(&[60.0; D]))
+    }
+
+    #[test]
+    fn spatial_hash_matches_brute_force() {
+        matches_brute_force::<2>(SpatialHash::new(Meters::new(3.0)), 1);
+        matches_brute_force::<3>(SpatialHash::new(Meters::new(3.0)), 2);
+
+        // Cells small enough that the larger boxes are oversized
+        matches_brute_force::<2>(SpatialHash::new(Meters::new(0.25)), 3);
+    }
+
+    #[test]
+    fn orthtrees_match_brute_force() {
+        matches_brute_force::<2>(Quadtree::new(region()).capacity(4).max_depth(6), 4);
+        matches_brute_force::<3>(Octree::new(region()), 5);
+    }
+
+    #[test]
+    fn sweep_and_prune_matches_brute_force() {
+        matches_brute_force::<2>(SweepAndPrune::new(), 6);
+        matches_brute_force::<3>(SweepAndPrune::new().axis(2), 7);
+    }
+
+    #[test]
+    fn dynamic_tree_matches_brute_force() {
+        matches_brute_force::<2>(DynamicTree::new(Meters::new(0.2)), 8);
+        matches_brute_force::<3>(DynamicTree::new(Meters::new(0.0)), 9);
+    }
+
+    #[test]
+    fn dynamic_tree_stays_balanced() {
+        let mut tree = DynamicTree::<2, f32>::new(Meters::new(0.1));
+        for key in 0..4096 {
+            let x = key as f32;
+            tree.insert(key, Bounds::new(Meters::from_components(&[x, 0.0]), Meters::from_components(&[x + 0.5, 0.5])));
+        }
+        assert!(tree.height() <= 20, "height {}", tree.height());
+
+        let mut pairs = Vec::new();
+        tree.pairs(&mut pairs);
+        assert!(pairs.is_empty());
+    }
+
+    #[test]
+    fn spatial_hash_sets_huge_boxes_aside() {
+        let mut grid = SpatialHash::<2, f32>::new(Meters::new(1.0));
+        let small = |x: f32| Bounds::new(Meters::from_components(&[x, 0.0]), Meters::from_components(&[x + 0.5, 0.5]));
+        grid.insert(0, small(0.0));
+        grid.insert(1, small(1e6));
+
+        // Boxes that would cover billions of cells, or reach to infinity, are tested directly
+        grid.insert(2, Bounds::new(Meters::from_components(&[-1e9, -1e9]), Meters::from_components(&[1e9, 1e9])));
+        grid.insert(3, Bounds::new(Meters::from_components(&[0.25, f32::NEG_INFINITY]), Meters::from_components(&[f32::INFINITY, 0.25])));
+
+        let mut pairs = Vec::new();
+        grid.pairs(&mut pairs);
+        assert_eq!(pairs, vec![(0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+
+        let mut found = Vec::new();
+        grid.query(&Bounds::new(Meters::from_components(&[f32::MIN, f32::MIN]), Meters::from_components(&[f32::MAX, f32::MAX])), &mut |key| found.push(key));
+        assert_eq!(found, vec![0, 1, 2, 3]);
+
+        // Shrinking one brings it back into the grid
+        grid.update(2, small(1e6 + 0.25));
+        grid.remove(3);
+        grid.pairs(&mut pairs);
+        assert_eq!(pairs, vec![(1, 2)]);
+    }
+}
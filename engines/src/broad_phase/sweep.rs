@@ -0,0 +1,95 @@
+use alloc::vec::Vec;
+use physics::Real;
+use super::{BroadPhase, Bounds, Slots, ordered, settle};
+
+/// Boxes kept sorted by where they start along one axis, so that each need only be tested
+/// against those that start before it ends. The order barely changes between steps, so it is
+/// cheap to restore.
+pub struct SweepAndPrune<const D: usize, B: Real> {
+    axis: usize,
+    entries: Slots<Bounds<D, B>>,
+    order: Vec<usize>
+}
+
+impl<const D: usize, B: Real> SweepAndPrune<D, B> {
+    /// Sweeps along the first axis.
+    pub fn new() -> Self {
+        Self {
+            axis: 0,
+            entries: Slots::new(),
+            order: Vec::new()
+        }
+    }
+
+    /// Sweeps along `axis` instead, best chosen as the one bodies are most spread out along.
+    pub fn axis(self, axis: usize) -> Self {
+        assert!(axis < D, "there is no axis {axis} in {D} dimensions");
+
+        Self {
+            axis,
+            ..self
+        }
+    }
+
+    /// Restores the order by start. The sort runs in close to linear time on the nearly
+    /// sorted order left by the last step.
+    fn sort(&mut self) {
+        let (entries, axis) = (&self.entries, self.axis);
+        let start = |key: &usize| entries.get(*key).expect("the order lists tracked keys").min().value_at(axis);
+
+        self.order.sort_by(|a, b| start(a).partial_cmp(&start(b)).unwrap_or(core::cmp::Ordering::Equal));
+    }
+}
+
+impl<const D: usize, B: Real> Default for SweepAndPrune<D, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const D: usize, B: Real> BroadPhase<D, B> for SweepAndPrune<D, B> {
+    fn insert(&mut self, key: usize, bounds: Bounds<D, B>) {
+        if self.entries.get(key).is_none() {
+            self.order.push(key);
+        }
+        self.entries.set(key, bounds);
+    }
+
+    fn update(&mut self, key: usize, bounds: Bounds<D, B>) {
+        self.insert(key, bounds);
+    }
+
+    fn remove(&mut self, key: usize) {
+        if self.entries.take(key).is_some() {
+            self.order.retain(|&k| k != key);
+        }
+    }
+
+    fn pairs(&mut self, pairs: &mut Vec<(usize, usize)>) {
+        pairs.clear();
+        self.sort();
+
+        for (i, &a) in self.order.iter().enumerate() {
+            let bounds = self.entries.get(a).expect("the order lists tracked keys");
+            let end = bounds.max().value_at(self.axis);
+
+            for &b in &self.order[i + 1..] {
+                let other = self.entries.get(b).expect("the order lists tracked keys");
+                if other.min().value_at(self.axis) > end {
+                    break;
+                }
+                if bounds.overlaps(other) {
+                    pairs.push(ordered(a, b));
+                }
+            }
+        }
+
+        settle(pairs);
+    }
+
+    fn query(&self, bounds: &Bounds<D, B>, found: &mut dyn FnMut(usize)) {
+        self.entries.iter()
+            .filter(|(_, entry)| entry.overlaps(bounds))
+            .for_each(|(key, _)| found(key));
+    }
+}
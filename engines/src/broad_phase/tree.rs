@@ -0,0 +1,205 @@
+use alloc::vec::Vec;
+use physics::Real;
+use physics::units::space::Meters;
+use super::{BroadPhase, Bounds, Slots, ordered, settle};
+
+/// A quadtree over the plane.
+pub type Quadtree<B> = Orthtree<2, B>;
+
+/// An octree over space.
+pub type Octree<B> = Orthtree<3, B>;
+
+struct Node<const D: usize, B: Real> {
+    bounds: Bounds<D, B>,
+    depth: usize,
+    /// The first of `2^D` children, which are stored together.
+    children: Option<usize>,
+    keys: Vec<usize>
+}
+
+struct Entry<const D: usize, B: Real> {
+    bounds: Bounds<D, B>,
+    node: usize
+}
+
+/// A tree that halves a fixed region along every axis at each level: a quadtree in 2D and an
+/// octree in 3D. Each box sits in the deepest node that holds it whole; boxes outside the
+/// region sit at the root.
+pub struct Orthtree<const D: usize, B: Real> {
+    nodes: Vec<Node<D, B>>,
+    entries: Slots<Entry<D, B>>,
+    capacity: usize,
+    max_depth: usize
+}
+
+impl<const D: usize, B: Real> Orthtree<D, B> {
+    /// A tree over `region`, splitting nodes of more than 8 boxes up to 8 levels deep.
+    pub fn new(region: Bounds<D, B>) -> Self {
+        Self {
+            nodes: alloc::vec![Node {
+                bounds: region,
+                depth: 0,
+                children: None,
+                keys: Vec::new()
+            }],
+            entries: Slots::new(),
+            capacity: 8,
+            max_depth: 8
+        }
+    }
+
+    /// How many boxes a node holds before it splits.
+    pub fn capacity(self, capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..self
+        }
+    }
+
+    pub fn max_depth(self, max_depth: usize) -> Self {
+        Self {
+            max_depth,
+            ..self
+        }
+    }
+
+    /// The deepest node below `node` that holds `bounds` whole.
+    fn settle_from(&self, mut node: usize, bounds: &Bounds<D, B>) -> usize {
+        while let Some(first) = self.nodes[node].children {
+            match (first..first + (1 << D)).find(|&child| self.nodes[child].bounds.contains(bounds)) {
+                Some(child) => node = child,
+                None => break
+            }
+        }
+        node
+    }
+
+    fn place(&mut self, key: usize, bounds: Bounds<D, B>) {
+        let node = self.settle_from(0, &bounds);
+        self.nodes[node].keys.push(key);
+        self.entries.set(key, Entry { bounds, node });
+
+        let crowded = self.nodes[node].keys.len() > self.capacity;
+        if crowded && self.nodes[node].children.is_none() && self.nodes[node].depth < self.max_depth {
+            self.split(node);
+        }
+    }
+
+    /// Gives `node` its children and moves down every box that fits in one of them.
+    fn split(&mut self, node: usize) {
+        let bounds = self.nodes[node].bounds;
+        let (low, high) = (bounds.min().values(), bounds.max().values());
+        let half = B::from_f32(0.5);
+        let middle: [B; D] = core::array::from_fn(|i| (low[i] + high[i]) * half);
+
+        let first = self.nodes.len();
+        for child in 0..1usize << D {
+            let upper = |i: usize| child & (1 << i) != 0;
+            let child_low = core::array::from_fn(|i| if upper(i) { middle[i] } else { low[i] });
+            let child_high = core::array::from_fn(|i| if upper(i) { high[i] } else { middle[i] });
+
+            self.nodes.push(Node {
+                bounds: Bounds::new(Meters::from_components(&child_low), Meters::from_components(&child_high)),
+                depth: self.nodes[node].depth + 1,
+                children: None,
+                keys: Vec::new()
+            });
+        }
+        self.nodes[node].children = Some(first);
+
+        let keys = core::mem::take(&mut self.nodes[node].keys);
+        for key in keys {
+            let entry = self.entries.get(key).expect("nodes list tracked keys");
+            let target = self.settle_from(node, &entry.bounds);
+            self.nodes[target].keys.push(key);
+            self.entries.get_mut(key).expect("nodes list tracked keys").node = target;
+        }
+    }
+
+    fn unplace(&mut self, key: usize, node: usize) {
+        let keys = &mut self.nodes[node].keys;
+        if let Some(at) = keys.iter().position(|&k| k == key) {
+            keys.swap_remove(at);
+        }
+    }
+
+    /// Pairs the boxes of `node` with each other, with `above`, and then the same for its
+    /// children with this node's boxes added to `above`.
+    fn collect(&self, node: usize, above: &mut Vec<usize>, pairs: &mut Vec<(usize, usize)>) {
+        let keys = &self.nodes[node].keys;
+        let bounds = |key: usize| &self.entries.get(key).expect("nodes list tracked keys").bounds;
+
+        for (i, &a) in keys.iter().enumerate() {
+            for &b in keys[i + 1..].iter().chain(above.iter()) {
+                if bounds(a).overlaps(bounds(b)) {
+                    pairs.push(ordered(a, b));
+                }
+            }
+        }
+
+        if let Some(first) = self.nodes[node].children {
+            let depth = above.len();
+            above.extend_from_slice(keys);
+            for child in first..first + (1 << D) {
+                self.collect(child, above, pairs);
+            }
+            above.truncate(depth);
+        }
+    }
+}
+
+impl<const D: usize, B: Real> BroadPhase<D, B> for Orthtree<D, B> {
+    fn insert(&mut self, key: usize, bounds: Bounds<D, B>) {
+        if self.entries.get(key).is_some() {
+            return self.update(key, bounds);
+        }
+        self.place(key, bounds);
+    }
+
+    fn update(&mut self, key: usize, bounds: Bounds<D, B>) {
+        let Some(entry) = self.entries.get_mut(key) else {
+            return self.place(key, bounds);
+        };
+
+        // Stay put while still inside a node with nowhere deeper to go.
+        let node = entry.node;
+        if self.nodes[node].children.is_none() && self.nodes[node].bounds.contains(&bounds) {
+            entry.bounds = bounds;
+            return;
+        }
+
+        self.unplace(key, node);
+        self.place(key, bounds);
+    }
+
+    fn remove(&mut self, key: usize) {
+        if let Some(entry) = self.entries.take(key) {
+            self.unplace(key, entry.node);
+        }
+    }
+
+    fn pairs(&mut self, pairs: &mut Vec<(usize, usize)>) {
+        pairs.clear();
+        self.collect(0, &mut Vec::new(), pairs);
+        settle(pairs);
+    }
+
+    fn query(&self, bounds: &Bounds<D, B>, found: &mut dyn FnMut(usize)) {
+        let mut keys = Vec::new();
+        let mut pending = alloc::vec![0];
+
+        while let Some(node) = pending.pop() {
+            let node = &self.nodes[node];
+            keys.extend(node.keys.iter().filter(|&&key| {
+                self.entries.get(key).is_some_and(|entry| entry.bounds.overlaps(bounds))
+            }));
+
+            if let Some(first) = node.children {
+                pending.extend((first..first + (1 << D)).filter(|&child| self.nodes[child].bounds.overlaps(bounds)));
+            }
+        }
+
+        keys.sort_unstable();
+        keys.into_iter().for_each(found);
+    }
+}
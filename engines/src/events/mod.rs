@@ -0,0 +1,97 @@
+//! What happened over a step that gameplay may want to hear about: bodies starting to touch,
+//! staying in touch and coming apart, and bodies coming into and leaving sensors.
+//!
+//! Universes work these out by comparing what touches at the end of a step with what touched
+//! at the end of the one before, so each pair is reported at most once a step.
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use physics::Real;
+use physics::collision::Direction;
+use physics::units::force::Impulse;
+use physics::units::space::Meters;
+
+/// Two bodies touching over a step.
+#[derive(Copy, Clone)]
+pub struct Touch<B: Real> {
+    /// The lower of the two keys.
+    pub a: usize,
+    pub b: usize,
+    /// Unit length, pointing from `a` into `b`.
+    pub normal: Direction<B>,
+    /// One of the points where they touch.
+    pub point: Meters<2, B>,
+    /// The total impulse along the normal that kept them apart over the step.
+    pub impulse: Impulse<1, B>
+}
+
+impl<B: Real> Touch<B> {
+    fn pair(&self) -> (usize, usize) {
+        (self.a, self.b)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum Event<B: Real> {
+    /// The bodies touch, and didn't the step before.
+    BeginContact(Touch<B>),
+    /// The bodies still touch.
+    PersistContact(Touch<B>),
+    /// The bodies under these keys, lower first, touched the step before but no longer do, or
+    /// one of them was removed.
+    EndContact(usize, usize),
+    /// The body under `visitor` came to overlap the sensor under `sensor`.
+    TriggerEnter { sensor: usize, visitor: usize },
+    /// The body under `visitor` no longer overlaps the sensor under `sensor`, or one of them
+    /// was removed.
+    TriggerExit { sensor: usize, visitor: usize }
+}
+
+/// Adds the contact events of going from touching as in `previous` to touching as in
+/// `current`, both in order of their pairs, to `events` in the same order.
+pub(crate) fn compare_touches<B: Real>(previous: &[Touch<B>], current: &[Touch<B>], events: &mut Vec<Event<B>>) {
+    merge(previous, current, Touch::pair, |before, now| events.push(match (before, now) {
+        (Some(_), Some(now)) => Event::PersistContact(*now),
+        (None, Some(now)) => Event::BeginContact(*now),
+        (Some(before), None) => Event::EndContact(before.a, before.b),
+        (None, None) => unreachable!("a pair is in one list or the other")
+    }));
+}
+
+/// Adds the trigger events of going from sensors overlapping as in `previous` to overlapping
+/// as in `current`, both in order of their sensor and visitor, to `events` in the same order.
+pub(crate) fn compare_overlaps<B: Real>(previous: &[(usize, usize)], current: &[(usize, usize)], events: &mut Vec<Event<B>>) {
+    merge(previous, current, |&pair| pair, |before, now| match (before, now) {
+        (None, Some(&(sensor, visitor))) => events.push(Event::TriggerEnter { sensor, visitor }),
+        (Some(&(sensor, visitor)), None) => events.push(Event::TriggerExit { sensor, visitor }),
+        _ => {}
+    });
+}
+
+/// Walks two lists in order of their keys together, calling `visit` once for each key with
+/// what either list has under it.
+fn merge<T, K: Ord>(previous: &[T], current: &[T], key: impl Fn(&T) -> K, mut visit: impl FnMut(Option<&T>, Option<&T>)) {
+    let (mut i, mut j) = (0, 0);
+    while i < previous.len() || j < current.len() {
+        let order = match (previous.get(i), current.get(j)) {
+            (Some(before), Some(now)) => key(before).cmp(&key(now)),
+            (Some(_), None) => Ordering::Less,
+            _ => Ordering::Greater
+        };
+
+        match order {
+            Ordering::Less => {
+                visit(Some(&previous[i]), None);
+                i += 1;
+            },
+            Ordering::Greater => {
+                visit(None, Some(&current[j]));
+                j += 1;
+            },
+            Ordering::Equal => {
+                visit(Some(&previous[i]), Some(&current[j]));
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+}
@@ -1,5 +1,11 @@
 #![no_std]
 
+extern crate alloc;
+
 pub mod areas;
 pub mod bodies;
-pub mod universes;
\ No newline at end of file
+pub mod universes;
+pub mod broad_phase;
+pub mod solver;
+pub mod queries;
+pub mod events;
@@ -0,0 +1,340 @@
+//! Questions about what is where in a universe: what a ray hits first, where a moving shape
+//! would stop, and which bodies lie at a point or over a shape.
+//!
+//! Universes answer them through `Query`, by offering up the bodies near a region, from their
+//! broad phase if they have one; the rest is the same for all of them.
+use alloc::vec::Vec;
+use physics::Real;
+use physics::collision::{self, Convex, Direction};
+use physics::units::space::Meters;
+use crate::broad_phase::{self, Bounds};
+
+/// The layers a body is on unless told otherwise.
+pub const DEFAULT_LAYERS: u32 = 1;
+
+/// Which bodies a query sees.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Filter {
+    /// A bit for each layer, where bodies on none of them are passed over.
+    pub layers: u32,
+    /// A body to pass over, such as the one asking.
+    pub excluded: Option<usize>
+}
+
+impl Filter {
+    /// Sees every body on every layer.
+    pub fn new() -> Self {
+        Self {
+            layers: u32::MAX,
+            excluded: None
+        }
+    }
+
+    /// Sees only bodies on at least one of `layers`.
+    pub fn layers(self, layers: u32) -> Self {
+        Self {
+            layers,
+            ..self
+        }
+    }
+
+    /// Passes over the body under `key`, as well as any the filter already passes over.
+    pub fn excluding(self, key: usize) -> Self {
+        Self {
+            excluded: Some(key),
+            ..self
+        }
+    }
+
+    /// Whether the body under `key`, on `layers`, is seen.
+    pub fn admits(&self, key: usize, layers: u32) -> bool {
+        self.layers & layers != 0 && self.excluded != Some(key)
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A segment from `origin`, `length` along `direction`.
+#[derive(Copy, Clone)]
+pub struct Ray<B: Real> {
+    pub origin: Meters<2, B>,
+    /// Unit length.
+    pub direction: Direction<B>,
+    pub length: Meters<1, B>
+}
+
+impl<B: Real> Ray<B> {
+    /// The ray from `origin` along `direction`, which need not be unit length.
+    pub fn new(origin: Meters<2, B>, direction: Direction<B>, length: Meters<1, B>) -> Self {
+        Self {
+            origin,
+            direction: direction.direction(),
+            length
+        }
+    }
+
+    /// The ray from `start` that ends at `end`.
+    pub fn between(start: Meters<2, B>, end: Meters<2, B>) -> Self {
+        let along = end - start;
+
+        Self {
+            origin: start,
+            direction: along.direction(),
+            length: along.magnitude()
+        }
+    }
+
+    /// The point `distance` along the ray.
+    pub fn at(&self, distance: Meters<1, B>) -> Meters<2, B> {
+        self.origin + self.direction * distance
+    }
+
+    pub fn end(&self) -> Meters<2, B> {
+        self.at(self.length)
+    }
+}
+
+/// Where a ray meets a body.
+#[derive(Copy, Clone)]
+pub struct RayHit<B: Real> {
+    pub key: usize,
+    pub point: Meters<2, B>,
+    /// Unit length, out of the body's surface.
+    pub normal: Direction<B>,
+    /// From the origin of the ray.
+    pub distance: Meters<1, B>
+}
+
+/// Where a moving shape stops against a body.
+#[derive(Copy, Clone)]
+pub struct ShapeHit<B: Real> {
+    pub key: usize,
+    /// Where the two touch, on the body's surface.
+    pub point: Meters<2, B>,
+    /// Unit length, out of the body's surface.
+    pub normal: Direction<B>,
+    /// How far along its translation the shape got, from zero to one.
+    pub fraction: B
+}
+
+pub trait Query<B: Real> {
+    /// Calls `found` with the key and shape of every body `filter` admits that might overlap
+    /// `bounds`. Every body that does overlap them is included.
+    fn candidates(&self, bounds: &Bounds<2, B>, filter: &Filter, found: &mut dyn FnMut(usize, &dyn Convex<B>));
+
+    /// The first body `ray` hits, leaving out any it starts inside. Ties go to the lower key.
+    fn raycast(&self, ray: &Ray<B>, filter: &Filter) -> Option<RayHit<B>> {
+        let mut nearest: Option<RayHit<B>> = None;
+
+        self.candidates(&segment_bounds(ray), filter, &mut |key, shape| {
+            if let Some(hit) = ray_hit(ray, key, shape) {
+                match nearest {
+                    Some(best) if (best.distance, best.key) <= (hit.distance, hit.key) => {},
+                    _ => nearest = Some(hit)
+                }
+            }
+        });
+        nearest
+    }
+
+    /// Replaces the contents of `hits` with every body `ray` hits, nearest first, leaving out
+    /// any it starts inside.
+    fn raycast_all(&self, ray: &Ray<B>, filter: &Filter, hits: &mut Vec<RayHit<B>>) {
+        hits.clear();
+        self.candidates(&segment_bounds(ray), filter, &mut |key, shape| {
+            hits.extend(ray_hit(ray, key, shape));
+        });
+        hits.sort_by(|a, b| (a.distance, a.key).partial_cmp(&(b.distance, b.key)).unwrap_or(core::cmp::Ordering::Equal));
+    }
+
+    /// The first body `shape` touches as it moves by `translation`. Bodies it already touches
+    /// stop it at once.
+    fn shape_cast(&self, shape: &dyn Convex<B>, translation: Meters<2, B>, filter: &Filter) -> Option<ShapeHit<B>> {
+        let start = broad_phase::bounds_of(shape);
+        let bounds = start.merged(&start.translated(translation));
+        let mut nearest: Option<ShapeHit<B>> = None;
+
+        self.candidates(&bounds, filter, &mut |key, body| {
+            if let Some(impact) = collision::cast(shape, translation, body) {
+                match nearest {
+                    Some(best) if (best.fraction, best.key) <= (impact.fraction, key) => {},
+                    _ => nearest = Some(ShapeHit {
+                        key,
+                        point: impact.point,
+                        normal: impact.normal,
+                        fraction: impact.fraction
+                    })
+                }
+            }
+        });
+        nearest
+    }
+
+    /// Replaces the contents of `found` with the key of every body containing `point`, in
+    /// order.
+    fn containing(&self, point: Meters<2, B>, filter: &Filter, found: &mut Vec<usize>) {
+        self.overlapping(&point, filter, found);
+    }
+
+    /// Replaces the contents of `found` with the key of every body overlapping or touching
+    /// `shape`, in order.
+    fn overlapping(&self, shape: &dyn Convex<B>, filter: &Filter, found: &mut Vec<usize>) {
+        found.clear();
+        self.candidates(&broad_phase::bounds_of(shape), filter, &mut |key, body| {
+            if collision::overlaps(shape, body) {
+                found.push(key);
+            }
+        });
+        found.sort_unstable();
+    }
+}
+
+fn segment_bounds<B: Real>(ray: &Ray<B>) -> Bounds<2, B> {
+    let (start, end) = (ray.origin, ray.end());
+    Bounds::new(start, start).merged(&Bounds::new(end, end))
+}
+
+fn ray_hit<B: Real>(ray: &Ray<B>, key: usize, shape: &dyn Convex<B>) -> Option<RayHit<B>> {
+    if collision::overlaps(&ray.origin, shape) {
+        return None;
+    }
+
+    let impact = collision::cast(&ray.origin, ray.direction * ray.length, shape)?;
+    Some(RayHit {
+        key,
+        point: impact.point,
+        normal: impact.normal,
+        distance: ray.length.scale(impact.fraction)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use physics::rotation::Rotation2;
+    use physics::units::mass::Kilograms;
+    use crate::areas::{Circle, NonRotatingBox, OrientedBox};
+    use crate::bodies::enemies::BasicEnemy;
+    use crate::bodies::rigid::RigidBody;
+    use crate::universes::{D2Basic, D2World};
+    use super::*;
+
+    fn at(x: f32, y: f32) -> Meters<2, f32> {
+        Meters::from_components(&[x, y])
+    }
+
+    /// The centres of five 2 m squares: two stacked so they share an edge along y = 1, one
+    /// behind them, one around the origin and one off to the left.
+    const SQUARES: [(f32, f32); 5] = [(4.0, 0.0), (4.0, 2.0), (8.0, 0.0), (0.0, 0.0), (-4.0, 0.0)];
+
+    /// The squares as fixed bodies, keyed in order, with the one behind on layer 2 alone.
+    fn world() -> D2World {
+        let mut world = D2World::new();
+        for (i, (x, y)) in SQUARES.into_iter().enumerate() {
+            let body = RigidBody::fixed(OrientedBox::new(at(x, y), at(2.0, 2.0), Rotation2::identity()));
+            let key = world.add(if i == 2 { body.layers(2) } else { body });
+            assert_eq!(key, i);
+        }
+        world
+    }
+
+    /// The squares as enemies, which are all on the default layers.
+    fn basic() -> D2Basic {
+        D2Basic::new(SQUARES.map(|(x, y)| {
+            let bounding_box = NonRotatingBox::new(at(x - 1.0, y - 1.0), at(x + 1.0, y + 1.0));
+            BasicEnemy::new(at(x, y), bounding_box, Kilograms::new(1.0))
+        }))
+    }
+
+    fn close(a: Meters<2, f32>, b: Meters<2, f32>) -> bool {
+        (a - b).magnitude().value() < 1e-4
+    }
+
+    /// Along the x axis from the middle of the square around the origin.
+    fn outwards() -> Ray<f32> {
+        Ray::new(at(0.0, 0.0), Direction::from_components(&[2.0, 0.0]), Meters::new(10.0))
+    }
+
+    /// What both universes answer alike, whatever the layers.
+    fn answers(universe: &impl Query<f32>) {
+        // The square the ray starts in is passed over
+        let hit = universe.raycast(&outwards(), &Filter::new()).unwrap();
+        assert_eq!(hit.key, 0);
+        assert!((hit.distance.value() - 3.0).abs() < 1e-4 && close(hit.point, at(3.0, 0.0)));
+        assert!(close(Meters::from_components(&hit.normal.values()), at(-1.0, 0.0)));
+
+        // Reaching both stacked squares at once, the lower key wins
+        let along_edge = Ray::between(at(1.5, 1.0), at(10.0, 1.0));
+        assert_eq!(universe.raycast(&along_edge, &Filter::new()).map(|hit| hit.key), Some(0));
+        assert_eq!(universe.raycast(&along_edge, &Filter::new().excluding(0)).map(|hit| hit.key), Some(1));
+
+        // Too short to reach anything
+        assert!(universe.raycast(&Ray::between(at(1.5, 0.0), at(2.5, 0.0)), &Filter::new()).is_none());
+
+        // A ball dropped onto the left square stops with its centre half a metre above it
+        let ball = Circle::new(at(-4.0, 5.0), Meters::new(0.5));
+        let hit = universe.shape_cast(&ball, at(0.0, -8.0), &Filter::new()).unwrap();
+        assert_eq!(hit.key, 4);
+        assert!((hit.fraction - 3.5 / 8.0).abs() < 1e-3, "stopped at {}", hit.fraction);
+        assert!(close(Meters::from_components(&hit.normal.values()), at(0.0, 1.0)));
+        assert!(universe.shape_cast(&ball, at(0.0, -3.0), &Filter::new()).is_none());
+
+        // Points on an edge are in the squares either side
+        let mut found = Vec::new();
+        universe.containing(at(4.5, 1.0), &Filter::new(), &mut found);
+        assert_eq!(found, [0, 1]);
+        universe.containing(at(5.0, 0.0), &Filter::new(), &mut found);
+        assert_eq!(found, [0]);
+        universe.containing(at(2.0, 0.0), &Filter::new(), &mut found);
+        assert!(found.is_empty());
+        universe.containing(at(4.5, 1.0), &Filter::new().excluding(1), &mut found);
+        assert_eq!(found, [0]);
+    }
+
+    #[test]
+    fn worlds_answer_queries() {
+        answers(&world());
+    }
+
+    #[test]
+    fn basic_universes_answer_queries() {
+        answers(&basic());
+    }
+
+    #[test]
+    fn hits_come_nearest_first() {
+        let mut hits = Vec::new();
+        basic().raycast_all(&outwards(), &Filter::new(), &mut hits);
+        assert_eq!(hits.iter().map(|hit| hit.key).collect::<Vec<_>>(), [0, 2]);
+        assert!((hits[1].distance.value() - 7.0).abs() < 1e-4);
+
+        world().raycast_all(&outwards(), &Filter::new(), &mut hits);
+        assert_eq!(hits.iter().map(|hit| hit.key).collect::<Vec<_>>(), [0, 2]);
+
+        // Backwards, the far squares come first
+        let backwards = Ray::between(at(10.0, 0.5), at(-10.0, 0.5));
+        world().raycast_all(&backwards, &Filter::new(), &mut hits);
+        assert_eq!(hits.iter().map(|hit| hit.key).collect::<Vec<_>>(), [2, 0, 3, 4]);
+        assert!(hits.windows(2).all(|pair| pair[0].distance < pair[1].distance));
+    }
+
+    #[test]
+    fn filters_hide_bodies() {
+        let (world, basic) = (world(), basic());
+        let mut hits = Vec::new();
+
+        // Only the square behind is on layer 2
+        world.raycast_all(&outwards(), &Filter::new().layers(1), &mut hits);
+        assert_eq!(hits.iter().map(|hit| hit.key).collect::<Vec<_>>(), [0]);
+        assert_eq!(world.raycast(&outwards(), &Filter::new().layers(2)).map(|hit| hit.key), Some(2));
+        assert_eq!(world.raycast(&outwards(), &Filter::new().excluding(0)).map(|hit| hit.key), Some(2));
+        assert!(world.raycast(&outwards(), &Filter::new().layers(1).excluding(0)).is_none());
+
+        // Enemies are on the default layers alone
+        assert!(basic.raycast(&outwards(), &Filter::new().layers(2)).is_none());
+        assert_eq!(basic.raycast(&outwards(), &Filter::new().layers(DEFAULT_LAYERS).excluding(0)).map(|hit| hit.key), Some(2));
+    }
+}
@@ -0,0 +1,552 @@
+use alloc::vec::Vec;
+use physics::Real;
+use physics::collision::{Direction, Manifold};
+use physics::units::force::Impulse;
+use physics::units::mass::Kilograms;
+use physics::units::motion::Velocity;
+use physics::units::space::Meters;
+use physics::units::time::Seconds;
+use super::{Correction, Joint, Material, SolverBody, perpendicular};
+
+/// Two bodies, by their index among the solver's bodies, touching as `manifold` says.
+#[derive(Copy, Clone)]
+pub struct ContactPair<B: Real> {
+    pub a: usize,
+    pub b: usize,
+    /// With the normal pointing from `a` into `b`.
+    pub manifold: Manifold<B>,
+    /// Already combined from both bodies.
+    pub material: Material<B>
+}
+
+#[derive(Copy, Clone)]
+struct Point<B: Real> {
+    point: Meters<2, B>,
+    arm_a: Meters<2, B>,
+    arm_b: Meters<2, B>,
+    depth: Meters<1, B>,
+    normal_mass: Kilograms<B>,
+    tangent_mass: Kilograms<B>,
+    /// The speed of separation asked for by restitution.
+    bounce: Velocity<1, B>,
+    normal_impulse: Impulse<1, B>,
+    tangent_impulse: Impulse<1, B>,
+    push_impulse: Impulse<1, B>
+}
+
+struct Constraint<B: Real> {
+    a: usize,
+    b: usize,
+    normal: Direction<B>,
+    friction: B,
+    points: [Point<B>; 2],
+    count: usize,
+    /// For two points, how an impulse at each changes the normal velocity at both, so that
+    /// they can be solved together. `None` when the points are too nearly the same one.
+    coupling: Option<[[B; 2]; 2]>
+}
+
+impl<B: Real> Constraint<B> {
+    fn tangent(&self) -> Direction<B> {
+        perpendicular(self.normal)
+    }
+}
+
+/// The impulses a contact point ended the last step with, to start the next from.
+#[derive(Copy, Clone)]
+struct Remembered<B: Real> {
+    a: usize,
+    b: usize,
+    point: Meters<2, B>,
+    normal_impulse: Impulse<1, B>,
+    tangent_impulse: Impulse<1, B>
+}
+
+/// A sequential impulse solver for contacts and joints, which remembers the impulses of the
+/// last step to warm start the next.
+pub struct ContactSolver<B: Real> {
+    /// Passes over every contact to settle velocities.
+    pub velocity_iterations: usize,
+    /// Passes over every contact to settle split impulses.
+    pub position_iterations: usize,
+    pub correction: Correction<B>,
+    pub warm_starting: bool,
+    /// Overlap left alone, so that resting contacts stay touching from one step to the next.
+    pub slop: Meters<1, B>,
+    /// Contacts approaching slower than this don't bounce.
+    pub restitution_threshold: Velocity<1, B>,
+    /// How far a contact point can move between steps and still be taken for the same one.
+    pub persistence: Meters<1, B>,
+    constraints: Vec<Constraint<B>>,
+    remembered: Vec<Remembered<B>>
+}
+
+impl<B: Real> ContactSolver<B> {
+    pub fn new() -> Self {
+        Self {
+            velocity_iterations: 8,
+            position_iterations: 3,
+            correction: Correction::SplitImpulse { factor: B::from_f32(0.2) },
+            warm_starting: true,
+            slop: Meters::new(B::from_f32(0.005)),
+            restitution_threshold: Velocity::new(B::one()),
+            persistence: Meters::new(B::from_f32(0.05)),
+            constraints: Vec::new(),
+            remembered: Vec::new()
+        }
+    }
+
+    pub fn iterations(self, velocity_iterations: usize, position_iterations: usize) -> Self {
+        Self {
+            velocity_iterations,
+            position_iterations,
+            ..self
+        }
+    }
+
+    pub fn correction(self, correction: Correction<B>) -> Self {
+        Self {
+            correction,
+            ..self
+        }
+    }
+
+    pub fn warm_starting(self, warm_starting: bool) -> Self {
+        Self {
+            warm_starting,
+            ..self
+        }
+    }
+
+    /// Resolves `contacts` and `joints` between `bodies` over a step of `dt`, leaving velocities
+    /// that keep them apart and joined, and pushes for `SolverBody::integrate` to undo their
+    /// overlap. Joints that break along the way are marked so, and skipped from then on.
+    pub fn solve(&mut self, bodies: &mut [SolverBody<B>], contacts: &[ContactPair<B>], joints: &mut [Joint<B>], dt: Seconds<B>) {
+        self.prepare(bodies, contacts, dt);
+
+        let factor = match self.correction {
+            Correction::Baumgarte { factor } | Correction::SplitImpulse { factor } => factor
+        };
+        for joint in intact(joints) {
+            joint.prepare(&bodies[joint.a], &bodies[joint.b], factor, dt, self.warm_starting);
+        }
+
+        if self.warm_starting {
+            for joint in intact(joints) {
+                joint.warm_start(bodies);
+            }
+            self.warm_start(bodies);
+        }
+        for _ in 0..self.velocity_iterations {
+            for joint in intact(joints) {
+                joint.solve_velocities(bodies);
+            }
+            self.solve_velocities(bodies);
+        }
+        for joint in intact(joints) {
+            joint.finish(dt);
+        }
+        if let Correction::SplitImpulse { factor } = self.correction {
+            for _ in 0..self.position_iterations {
+                self.solve_pushes(bodies, factor, dt);
+            }
+        }
+
+        self.remember();
+    }
+
+    /// The impulse along the normal each contact point ended the last solve with, with its
+    /// pair and point.
+    pub fn impulses(&self) -> impl Iterator<Item = (usize, usize, Meters<2, B>, Impulse<1, B>)> + '_ {
+        self.constraints.iter().flat_map(|constraint| {
+            constraint.points[..constraint.count].iter()
+                .map(move |point| (constraint.a, constraint.b, point.point, point.normal_impulse))
+        })
+    }
+
+    /// Drops every contact of the body at `index`, and the impulses remembered for them, so
+    /// that a body given its index later doesn't start from them.
+    pub fn forget(&mut self, index: usize) {
+        self.constraints.retain(|constraint| constraint.a != index && constraint.b != index);
+        self.remembered.retain(|r| r.a != index && r.b != index);
+    }
+
+    fn prepare(&mut self, bodies: &[SolverBody<B>], contacts: &[ContactPair<B>], dt: Seconds<B>) {
+        self.constraints.clear();
+
+        for contact in contacts {
+            let (body_a, body_b) = (&bodies[contact.a], &bodies[contact.b]);
+            let normal = contact.manifold.normal;
+            let tangent = perpendicular(normal);
+
+            let mut constraint = Constraint {
+                a: contact.a,
+                b: contact.b,
+                normal,
+                friction: contact.material.friction,
+                points: [self.point_zero(); 2],
+                count: contact.manifold.contacts().len(),
+                coupling: None
+            };
+
+            for (slot, touch) in constraint.points.iter_mut().zip(contact.manifold.contacts()) {
+                let arm_a = touch.point - body_a.position;
+                let arm_b = touch.point - body_b.position;
+
+                let mass_along = |direction: Direction<B>| {
+                    let (turn_a, turn_b) = (arm_a.cross(&direction), arm_b.cross(&direction));
+                    let inverse = body_a.inverse_mass + body_b.inverse_mass
+                        + body_a.inverse_inertia * (turn_a * turn_a)
+                        + body_b.inverse_inertia * (turn_b * turn_b);
+                    inverse.inverse()
+                };
+
+                let approach = (body_b.velocity_at(arm_b) - body_a.velocity_at(arm_a)).dot(&normal);
+                let bounce = if approach < -self.restitution_threshold {
+                    -approach.scale(contact.material.restitution)
+                } else {
+                    Velocity::zero()
+                };
+
+                let (normal_impulse, tangent_impulse) = self.recall(contact.a, contact.b, touch.point);
+
+                *slot = Point {
+                    point: touch.point,
+                    arm_a,
+                    arm_b,
+                    depth: touch.depth,
+                    normal_mass: mass_along(normal),
+                    tangent_mass: mass_along(tangent),
+                    bounce: match self.correction {
+                        Correction::Baumgarte { factor } => {
+                            let overlap = (touch.depth - self.slop).max(Meters::zero());
+                            bounce.max((overlap / dt).scale(factor))
+                        },
+                        Correction::SplitImpulse { .. } => bounce
+                    },
+                    normal_impulse,
+                    tangent_impulse,
+                    push_impulse: Impulse::zero()
+                };
+            }
+
+            if constraint.count == 2 {
+                constraint.coupling = coupling(body_a, body_b, &constraint.points, normal);
+            }
+            self.constraints.push(constraint);
+        }
+    }
+
+    fn point_zero(&self) -> Point<B> {
+        Point {
+            point: Meters::zero(),
+            arm_a: Meters::zero(),
+            arm_b: Meters::zero(),
+            depth: Meters::zero(),
+            normal_mass: Kilograms::zero(),
+            tangent_mass: Kilograms::zero(),
+            bounce: Velocity::zero(),
+            normal_impulse: Impulse::zero(),
+            tangent_impulse: Impulse::zero(),
+            push_impulse: Impulse::zero()
+        }
+    }
+
+    /// The impulses of the nearest remembered point of the same pair, if near enough.
+    fn recall(&self, a: usize, b: usize, point: Meters<2, B>) -> (Impulse<1, B>, Impulse<1, B>) {
+        let start = self.remembered.partition_point(|r| (r.a, r.b) < (a, b));
+        let reach = self.persistence * self.persistence;
+
+        self.remembered[start..].iter()
+            .take_while(|r| (r.a, r.b) == (a, b))
+            .map(|r| ((r.point - point).magnitude_squared(), r))
+            .filter(|(distance, _)| *distance <= reach)
+            .fold(None, |nearest: Option<(_, &Remembered<B>)>, (distance, r)| match nearest {
+                Some((closest, _)) if closest <= distance => nearest,
+                _ => Some((distance, r))
+            })
+            .map_or((Impulse::zero(), Impulse::zero()), |(_, r)| (r.normal_impulse, r.tangent_impulse))
+    }
+
+    fn remember(&mut self) {
+        self.remembered.clear();
+        for constraint in &self.constraints {
+            for point in &constraint.points[..constraint.count] {
+                self.remembered.push(Remembered {
+                    a: constraint.a,
+                    b: constraint.b,
+                    point: point.point,
+                    normal_impulse: point.normal_impulse,
+                    tangent_impulse: point.tangent_impulse
+                });
+            }
+        }
+        self.remembered.sort_by_key(|r| (r.a, r.b));
+    }
+
+    fn warm_start(&self, bodies: &mut [SolverBody<B>]) {
+        for constraint in &self.constraints {
+            let tangent = constraint.tangent();
+            for point in &constraint.points[..constraint.count] {
+                let impulse = constraint.normal * point.normal_impulse + tangent * point.tangent_impulse;
+                bodies[constraint.a].apply_impulse(-impulse, point.arm_a);
+                bodies[constraint.b].apply_impulse(impulse, point.arm_b);
+            }
+        }
+    }
+
+    fn solve_velocities(&mut self, bodies: &mut [SolverBody<B>]) {
+        for constraint in self.constraints.iter_mut() {
+            let (normal, tangent) = (constraint.normal, constraint.tangent());
+
+            // Friction first, bounded by the pushing impulse of the last pass, so that the
+            // push has the final say on penetration.
+            for point in constraint.points[..constraint.count].iter_mut() {
+                let relative = bodies[constraint.b].velocity_at(point.arm_b) - bodies[constraint.a].velocity_at(point.arm_a);
+                let change = point.tangent_mass * -relative.dot(&tangent);
+
+                let limit = point.normal_impulse.scale(constraint.friction);
+                let total = (point.tangent_impulse + change).max(-limit).min(limit);
+                let change = total - point.tangent_impulse;
+                point.tangent_impulse = total;
+
+                bodies[constraint.a].apply_impulse(-(tangent * change), point.arm_a);
+                bodies[constraint.b].apply_impulse(tangent * change, point.arm_b);
+            }
+
+            if let Some(coupling) = constraint.coupling {
+                solve_together(constraint, coupling, bodies);
+                continue;
+            }
+
+            for point in constraint.points[..constraint.count].iter_mut() {
+                let relative = bodies[constraint.b].velocity_at(point.arm_b) - bodies[constraint.a].velocity_at(point.arm_a);
+                let change = point.normal_mass * (point.bounce - relative.dot(&normal));
+
+                let total = (point.normal_impulse + change).max(Impulse::zero());
+                let change = total - point.normal_impulse;
+                point.normal_impulse = total;
+
+                bodies[constraint.a].apply_impulse(-(normal * change), point.arm_a);
+                bodies[constraint.b].apply_impulse(normal * change, point.arm_b);
+            }
+        }
+    }
+
+    fn solve_pushes(&mut self, bodies: &mut [SolverBody<B>], factor: B, dt: Seconds<B>) {
+        for constraint in self.constraints.iter_mut() {
+            let normal = constraint.normal;
+
+            for point in constraint.points[..constraint.count].iter_mut() {
+                let overlap = (point.depth - self.slop).max(Meters::zero());
+                let wanted = (overlap / dt).scale(factor);
+
+                let relative = bodies[constraint.b].push_velocity_at(point.arm_b) - bodies[constraint.a].push_velocity_at(point.arm_a);
+                let change = point.normal_mass * (wanted - relative.dot(&normal));
+
+                let total = (point.push_impulse + change).max(Impulse::zero());
+                let change = total - point.push_impulse;
+                point.push_impulse = total;
+
+                bodies[constraint.a].apply_push(-(normal * change), point.arm_a);
+                bodies[constraint.b].apply_push(normal * change, point.arm_b);
+            }
+        }
+    }
+}
+
+/// The matrix taking the normal impulses at two points to the change in normal velocity at
+/// each, if it is far enough from singular to invert.
+fn coupling<B: Real>(a: &SolverBody<B>, b: &SolverBody<B>, points: &[Point<B>; 2], normal: Direction<B>) -> Option<[[B; 2]; 2]> {
+    let inverse_mass = a.inverse_mass.value() + b.inverse_mass.value();
+    let (inertia_a, inertia_b) = (a.inverse_inertia.value(), b.inverse_inertia.value());
+    let turns = points.map(|point| (point.arm_a.cross(&normal).value(), point.arm_b.cross(&normal).value()));
+
+    let entry = |i: usize, j: usize| {
+        inverse_mass + inertia_a * turns[i].0 * turns[j].0 + inertia_b * turns[i].1 * turns[j].1
+    };
+    let k = [[entry(0, 0), entry(0, 1)], [entry(1, 0), entry(1, 1)]];
+
+    let determinant = k[0][0] * k[1][1] - k[0][1] * k[1][0];
+    (k[0][0] * k[0][0] < B::from_f32(1000.0) * determinant).then_some(k)
+}
+
+/// Solves the normal impulses of a two point contact together, as Box2D's block solver does,
+/// trying each way of them pushing or not for the one that leaves neither approaching.
+/// Solving them one at a time instead lets stacks rock.
+fn solve_together<B: Real>(constraint: &mut Constraint<B>, k: [[B; 2]; 2], bodies: &mut [SolverBody<B>]) {
+    let (normal, zero) = (constraint.normal, B::zero());
+    let [first, second] = &mut constraint.points;
+    let approach = |bodies: &[SolverBody<B>], point: &Point<B>| {
+        (bodies[constraint.b].velocity_at(point.arm_b) - bodies[constraint.a].velocity_at(point.arm_a)).dot(&normal).value()
+    };
+
+    let old = [first.normal_impulse.value(), second.normal_impulse.value()];
+    let b = [
+        approach(bodies, first) - first.bounce.value() - (k[0][0] * old[0] + k[0][1] * old[1]),
+        approach(bodies, second) - second.bounce.value() - (k[1][0] * old[0] + k[1][1] * old[1])
+    ];
+
+    let determinant = k[0][0] * k[1][1] - k[0][1] * k[1][0];
+    let both = [
+        (k[0][1] * b[1] - k[1][1] * b[0]) / determinant,
+        (k[1][0] * b[0] - k[0][0] * b[1]) / determinant
+    ];
+    let only_first = -b[0] / k[0][0];
+    let only_second = -b[1] / k[1][1];
+
+    let total = if both[0] >= zero && both[1] >= zero {
+        both
+    } else if only_first >= zero && k[1][0] * only_first + b[1] >= zero {
+        [only_first, zero]
+    } else if only_second >= zero && k[0][1] * only_second + b[0] >= zero {
+        [zero, only_second]
+    } else if b[0] >= zero && b[1] >= zero {
+        [zero, zero]
+    } else {
+        return;
+    };
+
+    for (point, total) in [first, second].into_iter().zip(total) {
+        let change = Impulse::new(total) - point.normal_impulse;
+        point.normal_impulse = Impulse::new(total);
+
+        bodies[constraint.a].apply_impulse(-(normal * change), point.arm_a);
+        bodies[constraint.b].apply_impulse(normal * change, point.arm_b);
+    }
+}
+
+fn intact<B: Real>(joints: &mut [Joint<B>]) -> impl Iterator<Item = &mut Joint<B>> {
+    joints.iter_mut().filter(|joint| !joint.is_broken())
+}
+
+impl<B: Real> Default for ContactSolver<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use physics::Body;
+    use physics::rotation::Rotation2;
+    use physics::stepping::Simulation;
+    use crate::areas::{Circle, OrientedBox};
+    use crate::bodies::rigid::RigidBody;
+    use crate::universes::D2World;
+    use super::*;
+
+    fn at(x: f32, y: f32) -> Meters<2, f32> {
+        Meters::from_components(&[x, y])
+    }
+
+    /// A wide floor with its top at zero.
+    fn ground() -> RigidBody<D2World> {
+        RigidBody::fixed(OrientedBox::new(at(0.0, -0.5), at(40.0, 1.0), Rotation2::identity()))
+    }
+
+    fn unit_box(x: f32, y: f32) -> RigidBody<D2World> {
+        RigidBody::dynamic(OrientedBox::new(at(x, y), at(1.0, 1.0), Rotation2::identity()), Kilograms::new(1.0))
+    }
+
+    /// Stacks ten boxes, each `stagger` to the side of the one below and dropped from `gap`
+    /// above it, and lets them fall for `seconds`. Returns how far the worst box has slid
+    /// from where it began, turned and sunk, and the fastest any is still moving.
+    fn stack(solver: ContactSolver<f32>, stagger: f32, gap: f32, seconds: f32) -> (f32, f32, f32, f32) {
+        let mut world = D2World::new().solver(solver);
+        world.add(ground());
+        let offset = |i: usize| if i.is_multiple_of(2) { 0.0 } else { stagger };
+        let keys: Vec<usize> = (0..10).map(|i| world.add(unit_box(offset(i), 0.5 + i as f32 * (1.0 + gap)))).collect();
+        for _ in 0..(seconds * 60.0) as usize {
+            world.step(Seconds::new(1.0 / 60.0));
+        }
+
+        keys.iter().enumerate().fold((0.0, 0.0, 0.0, 0.0), |(slid, turned, sunk, speed), (i, &key)| {
+            let body = world.body(key).unwrap();
+            let [x, y] = body.position().values();
+            (
+                slid.max((x - offset(i)).abs()),
+                turned.max(body.orientation().angle().abs()),
+                sunk.max(0.5 + i as f32 - y),
+                speed.max(body.velocity().magnitude().value())
+            )
+        })
+    }
+
+    #[test]
+    fn ten_boxes_stack() {
+        let (slid, turned, sunk, speed) = stack(ContactSolver::new(), 0.0, 0.0, 5.0);
+        assert!(slid < 1e-3 && turned < 1e-3, "slid {slid}, turned {turned}");
+        assert!(sunk < 0.02 && speed < 1e-3, "sunk {sunk}, moving at {speed}");
+
+        let (slid, turned, sunk, speed) = stack(ContactSolver::new().correction(Correction::Baumgarte { factor: 0.2 }), 0.0, 0.0, 5.0);
+        assert!(slid < 1e-3 && turned < 1e-3, "slid {slid}, turned {turned}");
+        assert!(sunk < 0.02 && speed < 1e-3, "sunk {sunk}, moving at {speed}");
+    }
+
+    #[test]
+    fn staggered_stacks_settle() {
+        let (slid, turned, sunk, speed) = stack(ContactSolver::new(), 0.1, 0.05, 5.0);
+        assert!(slid < 0.05 && turned < 0.02, "slid {slid}, turned {turned}");
+
+        // Landing presses each of the ten contacts in a little past the slop
+        assert!(sunk < 0.1 && speed < 1e-3, "sunk {sunk}, moving at {speed}");
+    }
+
+    /// The highest the ball's bottom reaches after its first bounce off the floor, dropped
+    /// from `height` with `restitution`.
+    fn bounce(height: f32, restitution: f32) -> f32 {
+        let material = Material::new(0.5, restitution);
+        let mut world = D2World::new();
+        world.add(ground().material(material));
+        let ball = world.add(RigidBody::dynamic(Circle::new(at(0.0, height + 0.5), Meters::new(0.5)), Kilograms::new(1.0)).material(material));
+
+        let (mut bounced, mut peak, mut falling) = (false, 0.0f32, false);
+        for _ in 0..2400 {
+            world.step(Seconds::new(1.0 / 240.0));
+            let body = world.body(ball).unwrap();
+            let rising = body.velocity().values()[1] > 0.0;
+            if bounced && !rising {
+                break;
+            }
+            bounced |= falling && rising;
+            if bounced {
+                peak = peak.max(body.position().values()[1] - 0.5);
+            }
+            falling = !rising;
+        }
+
+        assert!(bounced, "never bounced");
+        peak
+    }
+
+    #[test]
+    fn bounces_to_the_square_of_restitution() {
+        for (height, restitution) in [(1.0, 0.5), (2.0, 0.8), (2.0, 0.3)] {
+            let peak = bounce(height, restitution);
+            let expected = height * restitution * restitution;
+            assert!((peak - expected).abs() < expected * 0.05, "peaked at {peak} for {expected}");
+        }
+    }
+
+    #[test]
+    fn removed_bodies_are_forgotten() {
+        let mut world = D2World::new();
+        world.add(ground());
+        let key = world.add(unit_box(0.0, 0.5));
+        for _ in 0..30 {
+            world.step(Seconds::new(1.0 / 60.0));
+        }
+        assert!(world.solver.remembered.iter().any(|r| r.a == key || r.b == key));
+
+        world.remove(key);
+        assert!(world.solver.remembered.iter().all(|r| r.a != key && r.b != key));
+        assert!(world.solver.impulses().all(|(a, b, ..)| a != key && b != key));
+
+        // A light body later given the same key starts without the heavy one's impulses
+        let light = world.add(RigidBody::dynamic(OrientedBox::new(at(0.0, 0.5), at(1.0, 1.0), Rotation2::identity()), Kilograms::new(0.01)));
+        assert_eq!(light, key);
+        world.step(Seconds::new(1.0 / 60.0));
+        assert!(world.body(light).unwrap().velocity().magnitude().value() < 0.2);
+    }
+}
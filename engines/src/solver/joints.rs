@@ -0,0 +1,738 @@
+//! Joints between pairs of bodies, each broken down into rows: single conditions on how the
+//! bodies may move relative to each other, such as "these two points stay together along x".
+//! Rows are solved one at a time alongside contacts, each correcting the velocities it sees.
+use physics::Real;
+use physics::collision::Direction;
+use physics::rotation::Rotation2;
+use physics::units::force::{Impulse, Newtons, Torque};
+use physics::units::motion::AngularVelocity;
+use physics::units::space::Meters;
+use physics::units::time::Seconds;
+use super::{SolverBody, perpendicular};
+
+const MAX_ROWS: usize = 3;
+
+/// What a joint holds two bodies to, in terms of its anchors: a point fixed on each body.
+#[derive(Copy, Clone)]
+pub enum JointKind<B: Real> {
+    /// The anchors stay `length` apart, as if joined by a rod.
+    Distance { length: Meters<1, B> },
+    /// The anchors are at most `length` apart, as if joined by a rope.
+    Rope { length: Meters<1, B> },
+    /// The anchors meet, and the bodies turn freely about them, as on a hinge.
+    Revolute,
+    /// The bodies keep their relative turn, and the anchor of `b` slides along `axis`, which
+    /// runs through the anchor of `a` and turns with `a`.
+    Prismatic { axis: Direction<B> },
+    /// The anchors meet and the bodies keep their relative turn, as if glued.
+    Weld,
+    /// `b` turns at `speed` relative to `a`, driven by no more than `max_torque`. The anchors
+    /// are left alone, so pair it with a revolute joint for a powered hinge.
+    Motor { speed: AngularVelocity<1, B>, max_torque: Torque<1, B> }
+}
+
+/// How a joint gives, as a spring with damping that pulls it back into place.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Softness<B: Real> {
+    /// How many times a second the joint would swing back and forth undamped, in hertz.
+    pub frequency: B,
+    /// One for just enough damping to stop without swinging, less for some swing.
+    pub damping_ratio: B
+}
+
+impl<B: Real> Softness<B> {
+    pub fn new(frequency: B, damping_ratio: B) -> Self {
+        Self {
+            frequency,
+            damping_ratio
+        }
+    }
+}
+
+/// How far a joint is from holding exactly.
+#[derive(Copy, Clone)]
+pub struct JointError<B: Real> {
+    /// How far the anchors are from where the joint would have them.
+    pub linear: Meters<1, B>,
+    /// How far the bodies are turned from where the joint would have them, in radians.
+    pub angular: B
+}
+
+/// One condition on the relative motion of the bodies: that `b` moves relative to `a` at a
+/// given speed along `linear`, and turning by the given amounts. In raw numbers, since a row
+/// may be about distance or angle.
+#[derive(Copy, Clone)]
+struct Row<B: Real> {
+    /// Zero for rows that only turn.
+    linear: Direction<B>,
+    turn_a: B,
+    turn_b: B,
+    mass: B,
+    bias: B,
+    mass_scale: B,
+    impulse_scale: B,
+    lower: B,
+    upper: B,
+    impulse: B
+}
+
+impl<B: Real> Row<B> {
+    fn zero() -> Self {
+        Self {
+            linear: Direction::zero(),
+            turn_a: B::zero(),
+            turn_b: B::zero(),
+            mass: B::zero(),
+            bias: B::zero(),
+            mass_scale: B::one(),
+            impulse_scale: B::zero(),
+            lower: B::zero(),
+            upper: B::zero(),
+            impulse: B::zero()
+        }
+    }
+
+    fn speed(&self, a: &SolverBody<B>, b: &SolverBody<B>) -> B {
+        self.linear.dot(&(b.velocity - a.velocity)).value()
+            + self.turn_b * b.angular_velocity.value()
+            - self.turn_a * a.angular_velocity.value()
+    }
+
+    fn apply(&self, a: &mut SolverBody<B>, b: &mut SolverBody<B>, impulse: B) {
+        let linear = self.linear * Impulse::<1, B>::new(impulse);
+
+        a.velocity -= linear * a.inverse_mass;
+        a.angular_velocity -= AngularVelocity::new(a.inverse_inertia.value() * self.turn_a * impulse);
+        b.velocity += linear * b.inverse_mass;
+        b.angular_velocity += AngularVelocity::new(b.inverse_inertia.value() * self.turn_b * impulse);
+    }
+}
+
+/// Where a joint's anchors are, and how its bodies are turned, at the moment.
+struct Placement<B: Real> {
+    arm_a: Meters<2, B>,
+    arm_b: Meters<2, B>,
+    /// From the anchor of `a` to the anchor of `b`.
+    separation: Meters<2, B>,
+    rotation_a: Rotation2<B>,
+    /// How far `b` is turned from its reference relative to `a`.
+    angle: B
+}
+
+/// A joint between the bodies `a` and `b`, by their index among the solver's bodies.
+#[derive(Copy, Clone)]
+pub struct Joint<B: Real> {
+    pub a: usize,
+    pub b: usize,
+    pub kind: JointKind<B>,
+    /// Where the joint holds each body, from its centre of mass, as the body lies unturned.
+    pub anchor_a: Meters<2, B>,
+    pub anchor_b: Meters<2, B>,
+    /// How `b` is turned relative to `a` when the joint is at rest.
+    pub reference: Rotation2<B>,
+    /// `None` for a joint that holds rigidly.
+    pub softness: Option<Softness<B>>,
+    /// The joint breaks once it has to pull harder than this.
+    pub breaking_force: Option<Newtons<1, B>>,
+    /// The joint breaks once it has to twist harder than this.
+    pub breaking_torque: Option<Torque<1, B>>,
+    /// Whether the two bodies still collide with each other.
+    pub collide_connected: bool,
+    rows: [Row<B>; MAX_ROWS],
+    count: usize,
+    force: Newtons<1, B>,
+    torque: Torque<1, B>,
+    error: JointError<B>,
+    broken: bool
+}
+
+impl<B: Real> Joint<B> {
+    pub fn new(a: usize, b: usize, kind: JointKind<B>, anchor_a: Meters<2, B>, anchor_b: Meters<2, B>) -> Self {
+        Self {
+            a,
+            b,
+            kind,
+            anchor_a,
+            anchor_b,
+            reference: Rotation2::identity(),
+            softness: None,
+            breaking_force: None,
+            breaking_torque: None,
+            collide_connected: false,
+            rows: [Row::zero(); MAX_ROWS],
+            count: 0,
+            force: Newtons::zero(),
+            torque: Torque::zero(),
+            error: JointError {
+                linear: Meters::zero(),
+                angular: B::zero()
+            },
+            broken: false
+        }
+    }
+
+    pub fn reference(self, reference: Rotation2<B>) -> Self {
+        Self {
+            reference,
+            ..self
+        }
+    }
+
+    pub fn softness(self, softness: Softness<B>) -> Self {
+        Self {
+            softness: Some(softness),
+            ..self
+        }
+    }
+
+    pub fn breaking_force(self, breaking_force: Newtons<1, B>) -> Self {
+        Self {
+            breaking_force: Some(breaking_force),
+            ..self
+        }
+    }
+
+    pub fn breaking_torque(self, breaking_torque: Torque<1, B>) -> Self {
+        Self {
+            breaking_torque: Some(breaking_torque),
+            ..self
+        }
+    }
+
+    pub fn collide_connected(self, collide_connected: bool) -> Self {
+        Self {
+            collide_connected,
+            ..self
+        }
+    }
+
+    /// How far the joint was from holding when last measured.
+    pub fn error(&self) -> JointError<B> {
+        self.error
+    }
+
+    /// The force the joint pulled its bodies together with over the last step.
+    pub fn force(&self) -> Newtons<1, B> {
+        self.force
+    }
+
+    /// The torque the joint twisted its bodies with over the last step.
+    pub fn torque(&self) -> Torque<1, B> {
+        self.torque
+    }
+
+    /// Whether the joint has broken, after which it holds nothing.
+    pub fn is_broken(&self) -> bool {
+        self.broken
+    }
+
+    /// Measures how far the joint is from holding with its bodies as `a` and `b` are now.
+    pub fn measure(&mut self, a: &SolverBody<B>, b: &SolverBody<B>) {
+        let placement = self.placement(a, b);
+        let distance = placement.separation.magnitude();
+
+        self.error = match self.kind {
+            JointKind::Distance { length } => JointError {
+                linear: (distance - length).max(length - distance),
+                angular: B::zero()
+            },
+            JointKind::Rope { length } => JointError {
+                linear: (distance - length).max(Meters::zero()),
+                angular: B::zero()
+            },
+            JointKind::Revolute => JointError {
+                linear: distance,
+                angular: B::zero()
+            },
+            JointKind::Prismatic { axis } => {
+                let across = perpendicular(placement.rotation_a.rotate(&axis));
+                JointError {
+                    linear: Meters::new(across.dot(&placement.separation).value().abs()),
+                    angular: placement.angle.abs()
+                }
+            },
+            JointKind::Weld => JointError {
+                linear: distance,
+                angular: placement.angle.abs()
+            },
+            JointKind::Motor { .. } => JointError {
+                linear: Meters::zero(),
+                angular: B::zero()
+            }
+        };
+    }
+
+    fn placement(&self, a: &SolverBody<B>, b: &SolverBody<B>) -> Placement<B> {
+        let arm_a = a.rotation.rotate(&self.anchor_a);
+        let arm_b = b.rotation.rotate(&self.anchor_b);
+
+        Placement {
+            arm_a,
+            arm_b,
+            separation: (b.position + arm_b) - (a.position + arm_a),
+            rotation_a: a.rotation,
+            angle: (b.rotation * a.rotation.inverse() * self.reference.inverse()).angle()
+        }
+    }
+
+    /// Sets up the rows for a step of `dt`, correcting `factor` of any error per step unless
+    /// the joint is soft.
+    pub(super) fn prepare(&mut self, a: &SolverBody<B>, b: &SolverBody<B>, factor: B, dt: Seconds<B>, warm_starting: bool) {
+        self.measure(a, b);
+
+        let placement = self.placement(a, b);
+        let (arm_a, arm_b) = (placement.arm_a, placement.arm_b);
+        let unbounded = B::from_f32(f32::INFINITY);
+        let along = |x: B, y: B| Direction::from_components(&[x, y]);
+
+        // Each row as its direction, its turns, its error and the bounds of its impulse, and
+        // for rows that ignore softness, the speed they aim for.
+        let mut specs = [(Direction::zero(), B::zero(), B::zero(), B::zero(), -unbounded, unbounded); MAX_ROWS];
+        let mut aims = [None; MAX_ROWS];
+
+        let count = match self.kind {
+            JointKind::Distance { length } | JointKind::Rope { length } => {
+                let distance = placement.separation.magnitude();
+                let normal = if distance.value() > B::from_f32(1e-6) {
+                    placement.separation.direction()
+                } else {
+                    along(B::one(), B::zero())
+                };
+                let error = (distance - length).value();
+
+                let pulls_only = matches!(self.kind, JointKind::Rope { .. });
+                let upper = if pulls_only { B::zero() } else { unbounded };
+                specs[0] = (normal, arm_a.cross(&normal).value(), arm_b.cross(&normal).value(), error, -unbounded, upper);
+
+                // A slack rope only stops the anchors parting further than it allows.
+                if pulls_only && error < B::zero() {
+                    aims[0] = Some(-error / dt.value());
+                }
+                1
+            },
+            JointKind::Revolute | JointKind::Weld => {
+                let [x, y] = placement.separation.values();
+                let (one, zero) = (B::one(), B::zero());
+
+                specs[0] = (along(one, zero), arm_a.cross(&along(one, zero)).value(), arm_b.cross(&along(one, zero)).value(), x, -unbounded, unbounded);
+                specs[1] = (along(zero, one), arm_a.cross(&along(zero, one)).value(), arm_b.cross(&along(zero, one)).value(), y, -unbounded, unbounded);
+
+                if let JointKind::Weld = self.kind {
+                    specs[2] = (Direction::zero(), one, one, placement.angle, -unbounded, unbounded);
+                    3
+                } else {
+                    2
+                }
+            },
+            JointKind::Prismatic { axis } => {
+                let across = perpendicular(placement.rotation_a.rotate(&axis));
+                let error = across.dot(&placement.separation).value();
+                let reach_a = (placement.separation + arm_a).cross(&across).value();
+
+                specs[0] = (across, reach_a, arm_b.cross(&across).value(), error, -unbounded, unbounded);
+                specs[1] = (Direction::zero(), B::one(), B::one(), placement.angle, -unbounded, unbounded);
+                2
+            },
+            JointKind::Motor { speed, max_torque } => {
+                let limit = (max_torque * dt).value();
+                specs[0] = (Direction::zero(), B::one(), B::one(), B::zero(), -limit, limit);
+                aims[0] = Some(speed.value());
+                1
+            }
+        };
+
+        let (mass_scale, impulse_scale, bias_rate) = match self.softness {
+            Some(softness) => {
+                let omega = B::from_f32(2.0) * B::pi() * softness.frequency;
+                let h = dt.value();
+                let spread = B::from_f32(2.0) * softness.damping_ratio + h * omega;
+                let stiffness = h * omega * spread;
+                (stiffness / (B::one() + stiffness), B::one() / (B::one() + stiffness), omega / spread)
+            },
+            None => (B::one(), B::zero(), factor / dt.value())
+        };
+
+        for (i, (row, spec)) in self.rows.iter_mut().zip(specs).enumerate().take(count) {
+            let (linear, turn_a, turn_b, error, lower, upper) = spec;
+            let inverse = (a.inverse_mass + b.inverse_mass).value() * linear.magnitude_squared().value()
+                + a.inverse_inertia.value() * turn_a * turn_a
+                + b.inverse_inertia.value() * turn_b * turn_b;
+
+            let (bias, row_mass_scale, row_impulse_scale) = match aims[i] {
+                Some(aim) => (-aim, B::one(), B::zero()),
+                None => (error * bias_rate, mass_scale, impulse_scale)
+            };
+
+            *row = Row {
+                linear,
+                turn_a,
+                turn_b,
+                mass: if inverse > B::zero() { B::one() / inverse } else { B::zero() },
+                bias,
+                mass_scale: row_mass_scale,
+                impulse_scale: row_impulse_scale,
+                lower,
+                upper,
+                impulse: if warm_starting && i < self.count { row.impulse } else { B::zero() }
+            };
+        }
+        self.count = count;
+    }
+
+    pub(super) fn warm_start(&self, bodies: &mut [SolverBody<B>]) {
+        let (a, b) = pair(bodies, self.a, self.b);
+        for row in &self.rows[..self.count] {
+            row.apply(a, b, row.impulse);
+        }
+    }
+
+    pub(super) fn solve_velocities(&mut self, bodies: &mut [SolverBody<B>]) {
+        let (a, b) = pair(bodies, self.a, self.b);
+
+        for row in self.rows[..self.count].iter_mut() {
+            let change = -row.mass * row.mass_scale * (row.speed(a, b) + row.bias) - row.impulse_scale * row.impulse;
+            let total = (row.impulse + change).max(row.lower).min(row.upper);
+            let change = total - row.impulse;
+            row.impulse = total;
+
+            row.apply(a, b, change);
+        }
+    }
+
+    /// Records how hard the joint worked over the step of `dt`, and breaks it if too hard.
+    pub(super) fn finish(&mut self, dt: Seconds<B>) {
+        let (linear, angular) = self.rows[..self.count].iter().fold((Impulse::<2, B>::zero(), B::zero()), |(linear, angular), row| {
+            if row.linear.magnitude_squared().value() > B::zero() {
+                (linear + row.linear * Impulse::new(row.impulse), angular)
+            } else {
+                (linear, angular + row.impulse)
+            }
+        });
+
+        self.force = linear.magnitude() / dt;
+        self.torque = Torque::new(angular.abs() / dt.value());
+
+        let overpulled = self.breaking_force.is_some_and(|limit| self.force > limit);
+        let overtwisted = self.breaking_torque.is_some_and(|limit| self.torque > limit);
+        if overpulled || overtwisted {
+            self.broken = true;
+        }
+    }
+}
+
+/// Both bodies of a pair, which must differ.
+fn pair<B: Real>(bodies: &mut [SolverBody<B>], a: usize, b: usize) -> (&mut SolverBody<B>, &mut SolverBody<B>) {
+    assert_ne!(a, b, "a joint joins two different bodies");
+
+    if a < b {
+        let (low, high) = bodies.split_at_mut(b);
+        (&mut low[a], &mut high[0])
+    } else {
+        let (low, high) = bodies.split_at_mut(a);
+        (&mut high[0], &mut low[b])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::f32::consts::TAU;
+    use physics::Body;
+    use physics::stepping::Simulation;
+    use physics::units::mass::Kilograms;
+    use physics::units::motion::{Acceleration, Velocity};
+    use crate::areas::{Circle, OrientedBox};
+    use crate::bodies::rigid::RigidBody;
+    use crate::universes::D2World;
+    use super::*;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    fn at(x: f32, y: f32) -> Meters<2, f32> {
+        Meters::from_components(&[x, y])
+    }
+
+    /// A fixed peg at (0, 10) to hang things from.
+    fn peg(world: &mut D2World) -> usize {
+        world.add(RigidBody::fixed(Circle::new(at(0.0, 10.0), Meters::new(0.1))))
+    }
+
+    fn bob(world: &mut D2World, x: f32, y: f32) -> usize {
+        world.add(RigidBody::dynamic(Circle::new(at(x, y), Meters::new(0.05)), Kilograms::new(1.0)))
+    }
+
+    fn plank(x: f32, y: f32, width: f32, height: f32) -> RigidBody<D2World> {
+        RigidBody::dynamic(OrientedBox::new(at(x, y), at(width, height), Rotation2::identity()), Kilograms::new(1.0))
+    }
+
+    /// Steps `world` for `steps` steps, returning the largest linear and angular error
+    /// `joint` had after any of them, and the times `body` crossed under the peg going left.
+    fn swing(world: &mut D2World, joint: usize, body: usize, steps: usize) -> (f32, f32, [f32; 3]) {
+        let (mut linear, mut angular) = (0.0f32, 0.0f32);
+        let (mut crossings, mut count) = ([0.0; 3], 0);
+        let mut previous = world.body(body).unwrap().position().values()[0];
+        for step in 1..=steps {
+            world.step(Seconds::new(DT));
+
+            let error = world.joint(joint).unwrap().error();
+            linear = linear.max(error.linear.value());
+            angular = angular.max(error.angular.abs());
+
+            let x = world.body(body).unwrap().position().values()[0];
+            if previous > 0.0 && x <= 0.0 && count < 3 {
+                crossings[count] = step as f32 * DT - DT * x / (x - previous);
+                count += 1;
+            }
+            previous = x;
+        }
+
+        assert_eq!(count, 3, "never swung");
+        (linear, angular, crossings)
+    }
+
+    fn assert_period(crossings: [f32; 3], period: f32) {
+        for pair in crossings.windows(2) {
+            let measured = pair[1] - pair[0];
+            assert!((measured - period).abs() < period * 0.02, "swung in {measured} for {period}");
+        }
+    }
+
+    #[test]
+    fn distance_pendulum() {
+        // A 2 m rod let go 0.2 rad from hanging straight down
+        let mut world = D2World::new();
+        let top = peg(&mut world);
+        let (x, y) = (2.0 * 0.2f32.sin(), 10.0 - 2.0 * 0.2f32.cos());
+        let bob = bob(&mut world, x, y);
+        let rod = world.connect(top, bob, JointKind::Distance { length: Meters::new(2.0) }, at(0.0, 10.0), at(x, y));
+        let rod = world.add_joint(rod);
+
+        let (linear, _, crossings) = swing(&mut world, rod, bob, 600);
+        assert!(linear < 5e-3, "stretched by {linear}");
+        assert_period(crossings, TAU * (2.0f32 / 9.81).sqrt() * (1.0 + 0.2 * 0.2 / 16.0));
+    }
+
+    #[test]
+    fn hinged_plank_swings() {
+        // A 1 m plank hinged at one end, held 0.2 rad out: I = m (w² + h²) / 12 + m d²
+        let mut world = D2World::new();
+        let top = peg(&mut world);
+        let (sin, cos) = (0.2f32.sin(), 0.2f32.cos());
+        let mut body = plank(0.5 * sin, 10.0 - 0.5 * cos, 0.1, 1.0);
+        body.dynamics_mut().orientation = Rotation2::from_angle(0.2);
+        let plank = world.add(body);
+        let hinge = world.connect(top, plank, JointKind::Revolute, at(0.0, 10.0), at(0.0, 10.0));
+        let hinge = world.add_joint(hinge);
+
+        let (linear, _, crossings) = swing(&mut world, hinge, plank, 600);
+        assert!(linear < 5e-3, "pulled apart by {linear}");
+
+        let inertia = (0.1 * 0.1 + 1.0) / 12.0 + 0.25;
+        assert_period(crossings, TAU * (inertia / (9.81 * 0.5)).sqrt());
+    }
+
+    #[test]
+    fn chain_hangs_together() {
+        let mut world = D2World::new();
+        let mut previous = peg(&mut world);
+        let mut links = Vec::new();
+        for i in 0..10 {
+            let x = 0.5 + i as f32;
+            let link = world.add(plank(x, 10.0, 1.0, 0.2));
+            let joint = world.connect(previous, link, JointKind::Revolute, at(x - 0.5, 10.0), at(x - 0.5, 10.0));
+            links.push(world.add_joint(joint));
+            previous = link;
+        }
+        for _ in 0..600 {
+            world.step(Seconds::new(DT));
+        }
+
+        let worst = links.iter().map(|&joint| world.joint(joint).unwrap().error().linear.value()).fold(0.0, f32::max);
+        assert!(worst < 0.05, "links pulled apart by {worst}");
+
+        // Having swung down, the end hangs beneath the peg
+        let [x, y] = world.body(previous).unwrap().position().values();
+        assert!(x.abs() < 1.0 && y < 1.0, "end at {x}, {y}");
+    }
+
+    #[test]
+    fn prismatic_slider_keeps_to_its_axis() {
+        let mut world = D2World::new();
+        let rail = peg(&mut world);
+        let slider = world.add(plank(0.0, 10.0, 0.5, 0.5));
+        let axis = Direction::from_components(&[1.0, -1.0]).direction();
+        let joint = world.connect(rail, slider, JointKind::Prismatic { axis }, at(0.0, 10.0), at(0.0, 10.0));
+        let joint = world.add_joint(joint);
+
+        let mut worst = JointError { linear: Meters::zero(), angular: 0.0 };
+        for _ in 0..60 {
+            world.step(Seconds::new(DT));
+            let error = world.joint(joint).unwrap().error();
+            worst.linear = worst.linear.max(error.linear);
+            worst.angular = worst.angular.max(error.angular.abs());
+        }
+        assert!(worst.linear.value() < 0.01 && worst.angular < 0.01, "off by {} and {}", worst.linear.value(), worst.angular);
+
+        // It slides down the slope, as a bead on a frictionless wire at 45°: s = g t² / (2√2)
+        let [x, y] = world.body(slider).unwrap().position().values();
+        let along = 9.81 / (2.0 * 2.0f32.sqrt());
+        assert!((x - along / 2.0f32.sqrt()).abs() < 0.05 && (x + y - 10.0).abs() < 0.01, "at {x}, {y}");
+        assert!(world.body(slider).unwrap().orientation().angle().abs() < 0.01);
+    }
+
+    #[test]
+    fn weld_holds_a_beam_out() {
+        let mut world = D2World::new();
+        let wall = world.add(RigidBody::fixed(OrientedBox::new(at(0.0, 0.0), at(0.2, 2.0), Rotation2::identity())));
+        let beam = world.add(plank(1.1, 0.0, 2.0, 0.2));
+        let weld = world.connect(wall, beam, JointKind::Weld, at(0.1, 0.0), at(0.1, 0.0));
+        let weld = world.add_joint(weld);
+        for _ in 0..300 {
+            world.step(Seconds::new(DT));
+        }
+
+        let error = world.joint(weld).unwrap().error();
+        assert!(error.linear.value() < 0.01 && error.angular.abs() < 0.05, "off by {} and {}", error.linear.value(), error.angular);
+        assert!(world.body(beam).unwrap().orientation().angle().abs() < 0.05);
+
+        // Holding up 1 kg a metre out takes its weight and a torque of about 9.81 N m
+        let joint = world.joint(weld).unwrap();
+        assert!((joint.force().value() - 9.81).abs() < 0.5, "force {}", joint.force().value());
+        assert!((joint.torque().value().abs() - 9.81).abs() < 0.5, "torque {}", joint.torque().value());
+    }
+
+    #[test]
+    fn joints_break_past_their_limits() {
+        let mut world = D2World::new();
+        let top = peg(&mut world);
+        let bob = bob(&mut world, 0.0, 9.0);
+        let hinge = world.connect(top, bob, JointKind::Revolute, at(0.0, 10.0), at(0.0, 10.0)).breaking_force(Newtons::new(20.0));
+        let hinge = world.add_joint(hinge);
+        for _ in 0..30 {
+            world.step(Seconds::new(DT));
+        }
+
+        // Hanging still, it only carries the bob's weight
+        let joint = world.joint(hinge).unwrap();
+        assert!(!joint.is_broken() && (joint.force().value() - 9.81).abs() < 0.5, "force {}", joint.force().value());
+
+        // A sudden tug of 5 m/s needs 300 N over a step
+        world.body_mut(bob).unwrap().dynamics_mut().velocity = Velocity::from_components(&[0.0, -5.0]);
+        world.step(Seconds::new(DT));
+        assert!(world.joint(hinge).unwrap().is_broken());
+
+        // Once broken, it lets go
+        for _ in 0..30 {
+            world.step(Seconds::new(DT));
+        }
+        assert!(world.body(bob).unwrap().position().values()[1] < 8.0);
+    }
+
+    #[test]
+    fn welds_break_past_their_torque() {
+        let mut world = D2World::new();
+        let wall = world.add(RigidBody::fixed(OrientedBox::new(at(0.0, 0.0), at(0.2, 2.0), Rotation2::identity())));
+        let beam = world.add(plank(1.1, 0.0, 2.0, 0.2));
+
+        // The beam's weight a metre out turns the weld at about 9.81 N m
+        let weld = world.connect(wall, beam, JointKind::Weld, at(0.1, 0.0), at(0.1, 0.0)).breaking_torque(Torque::new(5.0));
+        let weld = world.add_joint(weld);
+        world.step(Seconds::new(DT));
+        assert!(world.joint(weld).unwrap().is_broken());
+
+        let holding = world.connect(wall, beam, JointKind::Weld, at(0.1, 0.0), at(0.1, 0.0)).breaking_torque(Torque::new(20.0));
+        world.remove_joint(weld);
+        let holding = world.add_joint(holding);
+        for _ in 0..60 {
+            world.step(Seconds::new(DT));
+        }
+        assert!(!world.joint(holding).unwrap().is_broken());
+    }
+
+    #[test]
+    fn ropes_go_slack_but_hold_at_their_length() {
+        // A bob on a 2 m rope, thrown up towards the peg
+        let mut world = D2World::new();
+        let top = peg(&mut world);
+        let bob = bob(&mut world, 0.0, 8.0);
+        let rope = world.connect(top, bob, JointKind::Rope { length: Meters::new(2.0) }, at(0.0, 10.0), at(0.0, 8.0));
+        let rope = world.add_joint(rope);
+        world.body_mut(bob).unwrap().dynamics_mut().velocity = Velocity::from_components(&[0.0, 4.0]);
+
+        // Until it comes back down, the rope carries nothing and the bob flies freely
+        for step in 1..=45 {
+            world.step(Seconds::new(DT));
+            assert_eq!(world.joint(rope).unwrap().force().value(), 0.0);
+            let speed = world.body(bob).unwrap().dynamics().velocity.values()[1];
+            assert!((speed - (4.0 - 9.81 * step as f32 * DT)).abs() < 1e-3, "falling at {speed} after {step} steps");
+        }
+
+        // Then it catches the bob at its full length and holds it there
+        let mut stretch = 0.0f32;
+        for _ in 0..120 {
+            world.step(Seconds::new(DT));
+            stretch = stretch.max(world.joint(rope).unwrap().error().linear.value());
+        }
+        assert!(stretch < 0.01, "stretched by {stretch}");
+        assert!((world.body(bob).unwrap().position().values()[1] - 8.0).abs() < 0.01);
+        assert!((world.joint(rope).unwrap().force().value() - 9.81).abs() < 0.5);
+    }
+
+    #[test]
+    fn motors_reach_their_speed_within_their_torque() {
+        // A 1 kg wheel of radius 0.5 m on a powered hinge: I = m r² / 2
+        let mut world = D2World::new().gravity(Acceleration::zero());
+        let top = peg(&mut world);
+        let wheel = world.add(RigidBody::dynamic(Circle::new(at(0.0, 10.0), Meters::new(0.5)), Kilograms::new(1.0)));
+        let hinge = world.connect(top, wheel, JointKind::Revolute, at(0.0, 10.0), at(0.0, 10.0));
+        world.add_joint(hinge);
+        let kind = JointKind::Motor { speed: AngularVelocity::new(3.0), max_torque: Torque::new(0.5) };
+        let motor = world.connect(top, wheel, kind, at(0.0, 10.0), at(0.0, 10.0));
+        let motor = world.add_joint(motor);
+
+        // Spinning up is limited to α = τ / I, so takes 0.75 s
+        let most = 0.5 / 0.125 * DT;
+        let mut previous = 0.0;
+        for step in 1..=90 {
+            world.step(Seconds::new(DT));
+            let spin = world.body(wheel).unwrap().dynamics().angular_velocity.value();
+            assert!(spin - previous <= most + 1e-4 && spin <= 3.0 + 1e-4, "spinning at {spin} after {step} steps");
+            assert!(world.joint(motor).unwrap().torque().value() <= 0.5 + 1e-4);
+            if step < 40 {
+                assert!((spin - previous - most).abs() < 1e-4, "held back at {spin} after {step} steps");
+            }
+            previous = spin;
+        }
+        assert!((previous - 3.0).abs() < 1e-4);
+
+        // Keeping it there takes nothing, as nothing slows it down
+        assert!(world.joint(motor).unwrap().torque().value() < 1e-3);
+    }
+
+    #[test]
+    fn soft_joints_spring_at_their_frequency() {
+        // Without gravity, a bob pulled 0.1 m past a soft 2 m joint bounces about that length
+        let mut world = D2World::new().gravity(Acceleration::zero());
+        let top = peg(&mut world);
+        let bob = bob(&mut world, 0.0, 7.9);
+        let spring = world.connect(top, bob, JointKind::Distance { length: Meters::new(2.0) }, at(0.0, 10.0), at(0.0, 7.9))
+            .softness(Softness::new(1.0, 0.0));
+        world.add_joint(spring);
+
+        let (mut crossings, mut count) = ([0.0; 3], 0);
+        let mut previous = 0.1;
+        for step in 1..=240 {
+            world.step(Seconds::new(DT));
+
+            let stretch = 8.0 - world.body(bob).unwrap().position().values()[1];
+            if previous > 0.0 && stretch <= 0.0 && count < 3 {
+                crossings[count] = step as f32 * DT - DT * stretch / (stretch - previous);
+                count += 1;
+            }
+            previous = stretch;
+        }
+
+        assert_eq!(count, 3, "never sprang back");
+        assert_period(crossings, 1.0);
+    }
+}
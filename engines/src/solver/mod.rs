@@ -0,0 +1,141 @@
+//! Impulse-based resolution of contacts between bodies in the plane.
+//!
+//! Each step, the solver takes a snapshot of every body's motion, nudges velocities with
+//! impulses at each contact until nothing is moving into anything else, then moves the bodies.
+//! Overlap left over from earlier steps is pushed out either by biasing those velocities
+//! (Baumgarte) or by a separate set of velocities used only for moving (split impulses).
+//! Joints are solved the same way, between the contacts, and always correct their own error by
+//! biasing velocities.
+use physics::Real;
+use physics::rotation::Rotation2;
+use physics::units::Quantity;
+use physics::units::force::{Impulse, InverseMomentOfInertia};
+use physics::units::mass::InverseKilograms;
+use physics::units::motion::{AngularVelocity, Velocity};
+use physics::units::space::Meters;
+use physics::units::time::Seconds;
+
+mod contacts;
+pub use contacts::{ContactPair, ContactSolver};
+
+mod joints;
+pub use joints::{Joint, JointError, JointKind, Softness};
+
+/// How the surfaces of two bodies behave where they touch.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Material<B: Real> {
+    /// The Coulomb coefficient: the most sideways impulse per unit of pushing impulse.
+    pub friction: B,
+    /// The share of the speed of approach kept as speed of separation.
+    pub restitution: B
+}
+
+impl<B: Real> Material<B> {
+    pub fn new(friction: B, restitution: B) -> Self {
+        Self {
+            friction,
+            restitution
+        }
+    }
+
+    /// The material of a contact between the two: friction is their geometric mean, so that
+    /// either surface being frictionless makes the contact so, and the bouncier one wins.
+    pub fn combine(&self, other: &Self) -> Self {
+        Self {
+            friction: (self.friction * other.friction).sqrt(),
+            restitution: self.restitution.max(other.restitution)
+        }
+    }
+}
+
+impl<B: Real> Default for Material<B> {
+    fn default() -> Self {
+        Self::new(B::from_f32(0.6), B::zero())
+    }
+}
+
+/// How overlap is undone.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Correction<B: Real> {
+    /// Ask contacts to separate at `factor` of their overlap per step. Simple, but the push
+    /// becomes real velocity, so resting bodies can jitter and deep ones pop out.
+    Baumgarte { factor: B },
+    /// Separate with velocities that move the bodies but are then forgotten, so overlap is
+    /// corrected without adding energy.
+    SplitImpulse { factor: B }
+}
+
+/// The part of a body the solver works on: where it is and how it moves.
+#[derive(Copy, Clone)]
+pub struct SolverBody<B: Real> {
+    pub position: Meters<2, B>,
+    pub rotation: Rotation2<B>,
+    pub velocity: Velocity<2, B>,
+    pub angular_velocity: AngularVelocity<1, B>,
+    /// Zero for bodies that impulses cannot move.
+    pub inverse_mass: InverseKilograms<B>,
+    pub inverse_inertia: InverseMomentOfInertia<B>,
+    push_velocity: Velocity<2, B>,
+    push_angular_velocity: AngularVelocity<1, B>
+}
+
+impl<B: Real> SolverBody<B> {
+    pub fn new(
+        position: Meters<2, B>,
+        rotation: Rotation2<B>,
+        velocity: Velocity<2, B>,
+        angular_velocity: AngularVelocity<1, B>,
+        inverse_mass: InverseKilograms<B>,
+        inverse_inertia: InverseMomentOfInertia<B>
+    ) -> Self {
+        Self {
+            position,
+            rotation,
+            velocity,
+            angular_velocity,
+            inverse_mass,
+            inverse_inertia,
+            push_velocity: Velocity::zero(),
+            push_angular_velocity: AngularVelocity::zero()
+        }
+    }
+
+    /// The velocity of the point `arm` away from the centre of mass.
+    pub fn velocity_at(&self, arm: Meters<2, B>) -> Velocity<2, B> {
+        self.velocity + perpendicular(arm) * self.angular_velocity
+    }
+
+    /// Changes the motion as `impulse` through the point `arm` away from the centre of mass
+    /// would.
+    pub fn apply_impulse(&mut self, impulse: Impulse<2, B>, arm: Meters<2, B>) {
+        self.velocity += impulse * self.inverse_mass;
+        self.angular_velocity += self.inverse_inertia * arm.cross(&impulse);
+    }
+
+    fn push_velocity_at(&self, arm: Meters<2, B>) -> Velocity<2, B> {
+        self.push_velocity + perpendicular(arm) * self.push_angular_velocity
+    }
+
+    fn apply_push(&mut self, impulse: Impulse<2, B>, arm: Meters<2, B>) {
+        self.push_velocity += impulse * self.inverse_mass;
+        self.push_angular_velocity += self.inverse_inertia * arm.cross(&impulse);
+    }
+
+    /// Moves and turns the body through `dt`, including any push left by split impulses, which
+    /// is then dropped.
+    pub fn integrate(&mut self, dt: Seconds<B>) {
+        self.position += (self.velocity + self.push_velocity) * dt;
+
+        let turn = (self.angular_velocity + self.push_angular_velocity) * dt;
+        self.rotation = (Rotation2::from_angle(turn.value()) * self.rotation).normalize();
+
+        self.push_velocity = Velocity::zero();
+        self.push_angular_velocity = AngularVelocity::zero();
+    }
+}
+
+/// `v` turned a quarter turn counter-clockwise.
+pub(crate) fn perpendicular<B: Real, U>(v: Quantity<2, B, U>) -> Quantity<2, B, U> {
+    let [x, y] = v.values();
+    Quantity::from_components(&[-y, x])
+}
@@ -0,0 +1,98 @@
+use physics::{Body, Universe};
+use physics::rotation::Rotation3;
+use physics::stepping::Simulation;
+use physics::units::{space, time, mass, motion, force, conversion};
+use crate::bodies::blocks;
+
+pub struct D3Basic<const N: usize> {
+    abs_time: time::Seconds<f32>,
+    pub blocks: [blocks::Block<Self>; N]
+}
+
+impl<const N: usize> D3Basic<N> {
+    pub fn new(blocks: [blocks::Block<Self>; N]) -> Self {
+        Self {
+            abs_time: time::Seconds::zero(),
+            blocks
+        }
+    }
+}
+
+impl<const N: usize> Universe for D3Basic<N> {
+    type Scalar = f32;
+
+    type Space = space::Meters<3, f32>;
+    type Time = time::Seconds<f32>;
+    type Mass = mass::Kilograms<f32>;
+
+    type Velocity = motion::Velocity<3, f32>;
+    type Acceleration = motion::Acceleration<3, f32>;
+    type Force = force::Newtons<3, f32>;
+
+    type InverseMass = mass::InverseKilograms<f32>;
+
+    type Orientation = Rotation3<f32>;
+    type AngularVelocity = motion::AngularVelocity<3, f32>;
+    type AngularAcceleration = motion::AngularAcceleration<3, f32>;
+    type Torque = force::Torque<3, f32>;
+    type InverseInertia = force::InverseInertiaTensor<f32>;
+
+    type Units = conversion::SI;
+
+    fn time(&self) -> &Self::Time {
+        &self.abs_time
+    }
+}
+
+impl<const N: usize> Simulation for D3Basic<N> {
+    fn step(&mut self, dt: Self::Time) {
+        for block in self.blocks.iter_mut() {
+            block.integrate(dt);
+        }
+
+        self.abs_time += dt;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use physics::units::motion::AngularVelocity;
+    use super::*;
+
+    /// The block's angular momentum in the world frame, and its rotational energy.
+    fn momentum_and_energy(block: &blocks::Block<D3Basic<1>>) -> ([f32; 3], f32) {
+        let dynamics = block.dynamics();
+        let momentum = (dynamics.world_inverse_inertia().inverse() * dynamics.angular_velocity).values();
+        let omega = dynamics.angular_velocity.values();
+        let energy = (0..3).map(|i| omega[i] * momentum[i]).sum::<f32>() / 2.0;
+
+        (momentum, energy)
+    }
+
+    #[test]
+    fn tumbling_boxes_keep_their_momentum_and_energy() {
+        let mut block = blocks::Block::new(
+            space::Meters::zero(),
+            space::Meters::from_components(&[1.0, 2.0, 3.0]),
+            mass::Kilograms::new(1.0)
+        );
+        // Mostly about the middle axis, which never spins steadily
+        block.dynamics_mut().angular_velocity = AngularVelocity::from_components(&[0.1, 2.0, 0.1]);
+        let mut universe = D3Basic::new([block]);
+
+        let (momentum, energy) = momentum_and_energy(&universe.blocks[0]);
+        let start = universe.blocks[0].dynamics().angular_velocity.values();
+        for _ in 0..4000 {
+            universe.step(time::Seconds::new(0.001));
+        }
+
+        let (after, spent) = momentum_and_energy(&universe.blocks[0]);
+        let size = momentum.iter().map(|l| l * l).sum::<f32>().sqrt();
+        assert!((0..3).all(|i| (after[i] - momentum[i]).abs() < size * 1e-2), "{momentum:?} became {after:?}");
+        assert!((spent - energy).abs() < energy * 1e-2, "{energy} became {spent}");
+
+        // The spin itself has wandered well away from where it started
+        let omega = universe.blocks[0].dynamics().angular_velocity.values();
+        assert!((0..3).map(|i| (omega[i] - start[i]).abs()).sum::<f32>() > 0.5, "still spinning at {omega:?}");
+    }
+}
@@ -0,0 +1,92 @@
+use alloc::vec::Vec;
+use physics::units::{motion, time};
+
+/// When bodies fall asleep: once every body of an island has moved slower than both speeds
+/// for `time`.
+#[derive(Copy, Clone)]
+pub struct Sleep {
+    pub linear_speed: motion::Velocity<1, f32>,
+    pub angular_speed: motion::AngularVelocity<1, f32>,
+    pub time: time::Seconds<f32>
+}
+
+impl Sleep {
+    /// Under five centimetres and two degrees a second, for half a second.
+    pub fn new() -> Self {
+        Self {
+            linear_speed: motion::Velocity::new(0.05),
+            angular_speed: motion::AngularVelocity::new(2.0f32.to_radians()),
+            time: time::Seconds::new(0.5)
+        }
+    }
+
+    pub fn speeds(self, linear_speed: motion::Velocity<1, f32>, angular_speed: motion::AngularVelocity<1, f32>) -> Self {
+        Self {
+            linear_speed,
+            angular_speed,
+            ..self
+        }
+    }
+
+    pub fn time(self, time: time::Seconds<f32>) -> Self {
+        Self {
+            time,
+            ..self
+        }
+    }
+}
+
+impl Default for Sleep {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How the dynamic bodies of a world stood at the end of the last step.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StepStats {
+    pub awake: usize,
+    pub sleeping: usize,
+    /// The groups the awake bodies formed, each touching or joined through one another.
+    pub islands: usize
+}
+
+/// How long a body has been still enough to sleep, and the island it sleeps with, if it does.
+#[derive(Copy, Clone, Default)]
+pub(crate) struct Rest {
+    pub idle: f32,
+    pub island: Option<usize>
+}
+
+/// Keys gathered into islands as they are joined, each known by its lowest key.
+pub(crate) struct Islands {
+    parents: Vec<usize>
+}
+
+impl Islands {
+    pub fn new() -> Self {
+        Self {
+            parents: Vec::new()
+        }
+    }
+
+    /// Puts each of the first `count` keys on an island of its own.
+    pub fn reset(&mut self, count: usize) {
+        self.parents.clear();
+        self.parents.extend(0..count);
+    }
+
+    pub fn island(&mut self, key: usize) -> usize {
+        let mut key = key;
+        while self.parents[key] != key {
+            self.parents[key] = self.parents[self.parents[key]];
+            key = self.parents[key];
+        }
+        key
+    }
+
+    pub fn join(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.island(a), self.island(b));
+        self.parents[a.max(b)] = a.min(b);
+    }
+}
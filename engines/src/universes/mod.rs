@@ -1,18 +1,79 @@
-use physics::Universe;
-use physics::units::{space, time, mass};
+use physics::{Body, Universe};
+use physics::rotation::Rotation2;
+use physics::stepping::Simulation;
+use physics::units::{space, time, mass, motion, force, conversion};
+use physics::collision::Convex;
 use crate::bodies::enemies;
+use crate::broad_phase::{bounds_of, Bounds};
+use crate::queries::{DEFAULT_LAYERS, Filter, Query};
+
+mod d3;
+pub use d3::D3Basic;
+
+mod world;
+pub use world::{D2World, PairFilter};
+
+mod islands;
+pub use islands::{Sleep, StepStats};
 
 pub struct D2Basic {
-    abs_time: time::Seconds<i64>,
+    abs_time: time::Seconds<f32>,
     pub enemies: [enemies::BasicEnemy<Self>; 5]
 }
 
+impl D2Basic {
+    pub fn new(enemies: [enemies::BasicEnemy<Self>; 5]) -> Self {
+        Self {
+            abs_time: time::Seconds::zero(),
+            enemies
+        }
+    }
+}
+
 impl Universe for D2Basic {
-    type Space = space::Meters<2, i64>;
-    type Time = time::Seconds<i64>;
-    type Mass = mass::Kilograms;
+    type Scalar = f32;
+
+    type Space = space::Meters<2, f32>;
+    type Time = time::Seconds<f32>;
+    type Mass = mass::Kilograms<f32>;
+
+    type Velocity = motion::Velocity<2, f32>;
+    type Acceleration = motion::Acceleration<2, f32>;
+    type Force = force::Newtons<2, f32>;
+
+    type InverseMass = mass::InverseKilograms<f32>;
+
+    type Orientation = Rotation2<f32>;
+    type AngularVelocity = motion::AngularVelocity<1, f32>;
+    type AngularAcceleration = motion::AngularAcceleration<1, f32>;
+    type Torque = force::Torque<1, f32>;
+    type InverseInertia = force::InverseMomentOfInertia<f32>;
+
+    type Units = conversion::SI;
 
     fn time(&self) -> &Self::Time {
         &self.abs_time
     }
-}
\ No newline at end of file
+}
+
+/// With no broad phase, every enemy is looked at. They are all on the default layers.
+impl Query<f32> for D2Basic {
+    fn candidates(&self, bounds: &Bounds<2, f32>, filter: &Filter, found: &mut dyn FnMut(usize, &dyn Convex<f32>)) {
+        for (key, enemy) in self.enemies.iter().enumerate() {
+            let boundary = enemy.boundary();
+            if filter.admits(key, DEFAULT_LAYERS) && bounds_of(&boundary).overlaps(bounds) {
+                found(key, &boundary);
+            }
+        }
+    }
+}
+
+impl Simulation for D2Basic {
+    fn step(&mut self, dt: Self::Time) {
+        for enemy in self.enemies.iter_mut() {
+            enemy.integrate(dt);
+        }
+
+        self.abs_time += dt;
+    }
+}
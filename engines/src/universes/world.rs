@@ -0,0 +1,1158 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use physics::{Body, BodyKind, Universe};
+use physics::collision::{self, Contact, Convex, Impact, Manifold, Sweep};
+use physics::forces::UniformGravity;
+use physics::rotation::Rotation2;
+use physics::stepping::Simulation;
+use physics::units::{space, time, mass, motion, force, conversion};
+use physics::units::force::Impulse;
+use crate::bodies::rigid::RigidBody;
+use crate::broad_phase::{BroadPhase, Bounds, DynamicTree};
+use crate::events::{self, Event, Touch};
+use crate::queries::{Filter, Query};
+use crate::solver::{ContactPair, ContactSolver, Joint, JointKind, SolverBody};
+use super::islands::{Islands, Rest, Sleep, StepStats};
+
+/// A plane of rigid bodies that collide with each other, found through the broad phase `P`,
+/// and may be joined together. Bodies and joints are known by the keys they are added under.
+///
+/// Which bodies collide is decided by their layers and masks, then by the pair filter if there
+/// is one. Sensors overlap whatever they would collide with, without either being pushed.
+///
+/// Each step adds events for the contacts that began, persisted and ended over it, in order of
+/// their pairs, then for bodies entering and leaving sensors, in order of sensor and visitor.
+/// They are kept until drained.
+///
+/// Bodies that touch or are joined, through any number of others, form an island. Once every
+/// body of an island has been still for long enough, they all fall asleep, and are neither
+/// moved nor solved until something wakes them: a force or velocity given to one of them, a
+/// moving body running into them, or a body or joint they rest on being removed.
+pub struct D2World<P: BroadPhase<2, f32> = DynamicTree<2, f32>> {
+    abs_time: time::Seconds<f32>,
+    pub gravity: motion::Acceleration<2, f32>,
+    pub solver: ContactSolver<f32>,
+    /// When bodies fall asleep, if they ever do.
+    pub sleep: Option<Sleep>,
+    bodies: Vec<Option<RigidBody<Self>>>,
+    joints: Vec<Option<Joint<f32>>>,
+    broad_phase: P,
+    pairs: Vec<(usize, usize)>,
+    /// Pairs of bodies held by joints that keep them from colliding, lower key first.
+    unpaired: Vec<(usize, usize)>,
+    pair_filter: Option<Box<PairFilter<P>>>,
+    contacts: Vec<ContactPair<f32>>,
+    overlaps: Vec<(usize, usize)>,
+    /// Where continuous bodies were stopped over the last step.
+    impacts: Vec<Touch<f32>>,
+    touches: Vec<Touch<f32>>,
+    /// What touched over the last step, and which sensors overlapped what, in order.
+    touching: Vec<Touch<f32>>,
+    sensed: Vec<(usize, usize)>,
+    /// Touches carried over between bodies too sound asleep to be paired up.
+    resting: Vec<Touch<f32>>,
+    events: Vec<Event<f32>>,
+    rests: Vec<Rest>,
+    islands: Islands,
+    /// The least time any body of each island has been still, under the island's lowest key.
+    least_idle: Vec<f32>,
+    /// Sleeping islands to wake, by their lowest key.
+    waking: Vec<usize>,
+    stats: StepStats,
+    solver_bodies: Vec<SolverBody<f32>>,
+    solver_joints: Vec<Joint<f32>>,
+    /// Where each body was, and how it was turned, before it last moved.
+    starts: Vec<(space::Meters<2, f32>, Rotation2<f32>)>,
+    impact_solver: ContactSolver<f32>
+}
+
+/// Whether the bodies under two keys, whose layers and masks let them, may collide.
+pub type PairFilter<P> = dyn Fn(usize, &RigidBody<D2World<P>>, usize, &RigidBody<D2World<P>>) -> bool;
+
+/// The most times a continuous body is stopped and sent on again in one step.
+const MAX_SUBSTEPS: usize = 8;
+
+impl D2World {
+    /// An empty world under standard gravity, along negative y.
+    pub fn new() -> Self {
+        Self::with_broad_phase(DynamicTree::new(space::Meters::new(0.1)))
+    }
+}
+
+impl Default for D2World {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: BroadPhase<2, f32>> D2World<P> {
+    pub fn with_broad_phase(broad_phase: P) -> Self {
+        Self {
+            abs_time: time::Seconds::zero(),
+            gravity: motion::Acceleration::from_components(&[0.0, -9.81]),
+            solver: ContactSolver::new(),
+            sleep: Some(Sleep::new()),
+            bodies: Vec::new(),
+            joints: Vec::new(),
+            broad_phase,
+            pairs: Vec::new(),
+            unpaired: Vec::new(),
+            pair_filter: None,
+            contacts: Vec::new(),
+            overlaps: Vec::new(),
+            impacts: Vec::new(),
+            touches: Vec::new(),
+            touching: Vec::new(),
+            sensed: Vec::new(),
+            resting: Vec::new(),
+            events: Vec::new(),
+            rests: Vec::new(),
+            islands: Islands::new(),
+            least_idle: Vec::new(),
+            waking: Vec::new(),
+            stats: StepStats::default(),
+            solver_bodies: Vec::new(),
+            solver_joints: Vec::new(),
+            starts: Vec::new(),
+            impact_solver: ContactSolver::new().warm_starting(false)
+        }
+    }
+
+    pub fn gravity(self, gravity: motion::Acceleration<2, f32>) -> Self {
+        Self {
+            gravity,
+            ..self
+        }
+    }
+
+    pub fn solver(self, solver: ContactSolver<f32>) -> Self {
+        Self {
+            solver,
+            ..self
+        }
+    }
+
+    pub fn sleep(self, sleep: Option<Sleep>) -> Self {
+        Self {
+            sleep,
+            ..self
+        }
+    }
+
+    /// Lets only the pairs of bodies `filter` agrees to collide or overlap.
+    pub fn pair_filter(self, filter: impl Fn(usize, &RigidBody<Self>, usize, &RigidBody<Self>) -> bool + 'static) -> Self {
+        Self {
+            pair_filter: Some(Box::new(filter)),
+            ..self
+        }
+    }
+
+    /// Adds `body`, returning its key, which may be one a removed body had.
+    pub fn add(&mut self, body: RigidBody<Self>) -> usize {
+        let key = self.bodies.iter().position(Option::is_none).unwrap_or(self.bodies.len());
+        self.broad_phase.insert(key, body.boundary().bounds());
+
+        if key == self.bodies.len() {
+            self.bodies.push(Some(body));
+            self.rests.push(Rest::default());
+        } else {
+            self.bodies[key] = Some(body);
+            self.rests[key] = Rest::default();
+        }
+        key
+    }
+
+    /// Removes the body under `key`, along with any joints to it, ending its contacts and its
+    /// overlaps with sensors, and waking whatever rested on it.
+    pub fn remove(&mut self, key: usize) -> Option<RigidBody<Self>> {
+        let body = self.bodies.get_mut(key)?.take()?;
+        self.broad_phase.remove(key);
+        self.solver.forget(key);
+
+        self.waking.extend(self.rests[key].island);
+        for touch in &self.touching {
+            if touch.a == key || touch.b == key {
+                self.waking.extend(self.rests[touch.a].island);
+                self.waking.extend(self.rests[touch.b].island);
+            }
+        }
+        for joint in self.joints.iter().flatten() {
+            if joint.a == key || joint.b == key {
+                self.waking.extend(self.rests[joint.a].island);
+                self.waking.extend(self.rests[joint.b].island);
+            }
+        }
+        self.wake_islands();
+
+        let events = &mut self.events;
+        self.touching.retain(|touch| {
+            let kept = touch.a != key && touch.b != key;
+            if !kept {
+                events.push(Event::EndContact(touch.a, touch.b));
+            }
+            kept
+        });
+        self.sensed.retain(|&(sensor, visitor)| {
+            let kept = sensor != key && visitor != key;
+            if !kept {
+                events.push(Event::TriggerExit { sensor, visitor });
+            }
+            kept
+        });
+
+        for joint in self.joints.iter_mut() {
+            if joint.as_ref().is_some_and(|joint| joint.a == key || joint.b == key) {
+                *joint = None;
+            }
+        }
+        Some(body)
+    }
+
+    pub fn body(&self, key: usize) -> Option<&RigidBody<Self>> {
+        self.bodies.get(key)?.as_ref()
+    }
+
+    pub fn body_mut(&mut self, key: usize) -> Option<&mut RigidBody<Self>> {
+        self.bodies.get_mut(key)?.as_mut()
+    }
+
+    pub fn bodies(&self) -> impl Iterator<Item = (usize, &RigidBody<Self>)> {
+        self.bodies.iter().enumerate().filter_map(|(key, body)| body.as_ref().map(|body| (key, body)))
+    }
+
+    /// A joint of `kind` between the bodies under `a` and `b`, holding them at the world points
+    /// `anchor_a` and `anchor_b` and turned as they are now, ready to be adjusted and added.
+    pub fn connect(&self, a: usize, b: usize, kind: JointKind<f32>, anchor_a: space::Meters<2, f32>, anchor_b: space::Meters<2, f32>) -> Joint<f32> {
+        let body_a = self.body(a).expect("the first body of a joint is in the world");
+        let body_b = self.body(b).expect("the second body of a joint is in the world");
+        let (rotation_a, rotation_b) = (body_a.orientation(), body_b.orientation());
+
+        Joint::new(
+            a,
+            b,
+            kind,
+            rotation_a.inverse().rotate(&(anchor_a - body_a.position())),
+            rotation_b.inverse().rotate(&(anchor_b - body_b.position()))
+        ).reference(rotation_b * rotation_a.inverse())
+    }
+
+    /// Adds `joint`, returning its key, which may be one a removed joint had, and wakes the
+    /// bodies it joins.
+    pub fn add_joint(&mut self, joint: Joint<f32>) -> usize {
+        self.wake(joint.a);
+        self.wake(joint.b);
+
+        let key = self.joints.iter().position(Option::is_none).unwrap_or(self.joints.len());
+
+        if key == self.joints.len() {
+            self.joints.push(Some(joint));
+        } else {
+            self.joints[key] = Some(joint);
+        }
+        key
+    }
+
+    /// Removes the joint under `key`, waking the bodies it joined.
+    pub fn remove_joint(&mut self, key: usize) -> Option<Joint<f32>> {
+        let joint = self.joints.get_mut(key)?.take()?;
+        self.wake(joint.a);
+        self.wake(joint.b);
+        Some(joint)
+    }
+
+    pub fn joint(&self, key: usize) -> Option<&Joint<f32>> {
+        self.joints.get(key)?.as_ref()
+    }
+
+    pub fn joint_mut(&mut self, key: usize) -> Option<&mut Joint<f32>> {
+        self.joints.get_mut(key)?.as_mut()
+    }
+
+    pub fn joints(&self) -> impl Iterator<Item = (usize, &Joint<f32>)> {
+        self.joints.iter().enumerate().filter_map(|(key, joint)| joint.as_ref().map(|joint| (key, joint)))
+    }
+
+    /// The contacts found at the start of the last step.
+    pub fn contacts(&self) -> &[ContactPair<f32>] {
+        &self.contacts
+    }
+
+    /// The sensors found at the start of the last step overlapping other bodies, as the key of
+    /// the sensor and of the other body, in order. Two sensors overlapping appear both ways.
+    pub fn overlaps(&self) -> &[(usize, usize)] {
+        &self.overlaps
+    }
+
+    pub fn is_asleep(&self, key: usize) -> bool {
+        self.rests.get(key).is_some_and(|rest| rest.island.is_some())
+    }
+
+    /// Wakes the body under `key`, and the rest of its island with it, as moving it or changing
+    /// it while it sleeps doesn't.
+    pub fn wake(&mut self, key: usize) {
+        if let Some(rest) = self.rests.get(key) {
+            self.waking.extend(rest.island);
+            self.wake_islands();
+        }
+    }
+
+    /// The awake and sleeping bodies at the end of the last step.
+    pub fn stats(&self) -> StepStats {
+        self.stats
+    }
+
+    /// Takes the events of the steps since they were last drained, oldest first.
+    pub fn drain_events(&mut self) -> impl Iterator<Item = Event<f32>> + '_ {
+        self.events.drain(..)
+    }
+
+    /// Moves every body's bounds in the broad phase to where the body is now.
+    fn track(&mut self) {
+        for (key, body) in self.bodies.iter().enumerate() {
+            if let Some(body) = body {
+                self.broad_phase.update(key, body.boundary().bounds());
+            }
+        }
+    }
+
+    /// Sweeps each continuous body over the step of `dt` it just took. Where it would have
+    /// passed into another body, it is moved back to the moment they meet, the contact is
+    /// resolved as if the other body couldn't be moved, and it carries on for the rest of the
+    /// step, up to `MAX_SUBSTEPS` times, after which it loses the rest of the step.
+    fn sweep_continuous(&mut self, dt: time::Seconds<f32>) {
+        self.impacts.clear();
+        for key in 0..self.bodies.len() {
+            let continuous = self.bodies[key].as_ref().is_some_and(|body| body.continuous && !body.sensor);
+            if !continuous || !awake(&self.bodies, &self.rests, key) {
+                continue;
+            }
+
+            let mut start = self.starts[key];
+            let mut span = dt;
+            for substep in 1..=MAX_SUBSTEPS {
+                let Some(body) = &self.bodies[key] else {
+                    break;
+                };
+                // Turning by its speed rather than the shorter way, in case it spun by more than
+                // half a turn.
+                let sweep = Sweep {
+                    position: start.0,
+                    rotation: start.1,
+                    translation: body.position() - start.0,
+                    turn: (body.dynamics().angular_velocity * span).value()
+                };
+
+                let Some((other, impact)) = self.earliest_impact(key, &sweep) else {
+                    break;
+                };
+                let (Some(body), Some(other_body)) = (&self.bodies[key], &self.bodies[other]) else {
+                    break;
+                };
+
+                let (position, rotation) = sweep.at(impact.fraction);
+                let remaining = span.scale(1.0 - impact.fraction);
+
+                let mut obstacle = solver_body(other_body);
+                obstacle.inverse_mass = mass::InverseKilograms::zero();
+                obstacle.inverse_inertia = force::InverseMomentOfInertia::zero();
+                let mut moving = solver_body(body);
+                moving.position = position;
+                moving.rotation = rotation;
+
+                let contact = ContactPair {
+                    a: 0,
+                    b: 1,
+                    manifold: Manifold::new(impact.normal, &[Contact {
+                        point: impact.point,
+                        depth: space::Meters::zero()
+                    }]),
+                    material: other_body.material.combine(&body.material)
+                };
+
+                let mut pair = [obstacle, moving];
+                self.impact_solver.solve(&mut pair, &[contact], &mut [], remaining);
+                self.waking.extend(self.rests[other].island);
+
+                let (a, b, normal) = if other < key { (other, key, impact.normal) } else { (key, other, -impact.normal) };
+                self.impacts.push(Touch {
+                    a,
+                    b,
+                    normal,
+                    point: impact.point,
+                    impulse: self.impact_solver.impulses().fold(Impulse::zero(), |total, (.., impulse)| total + impulse)
+                });
+
+                // Out of substeps, it stays where it met the other body rather than carry on
+                // unchecked.
+                if substep < MAX_SUBSTEPS {
+                    pair[1].integrate(remaining);
+                }
+
+                let body = self.bodies[key].as_mut().expect("the body was just swept");
+                body.set_position(pair[1].position);
+                let dynamics = body.dynamics_mut();
+                dynamics.velocity = pair[1].velocity;
+                dynamics.angular_velocity = pair[1].angular_velocity;
+                dynamics.orientation = pair[1].rotation;
+                self.broad_phase.update(key, body.boundary().bounds());
+
+                start = (position, rotation);
+                span = remaining;
+            }
+        }
+    }
+
+    /// The first body the one under `key` meets as it moves through `sweep`, taking the others
+    /// to have been where they are now all along.
+    fn earliest_impact(&self, key: usize, sweep: &Sweep<f32>) -> Option<(usize, Impact<f32>)> {
+        let body = self.bodies[key].as_ref()?;
+
+        // Wide enough for the shape turned any way along the way.
+        let local = body.shape().bounds();
+        let [x, y] = [0, 1].map(|i| local.max().value_at(i).max(-local.min().value_at(i)));
+        let around = |point: space::Meters<2, f32>| Bounds::new(point, point).grown(space::Meters::new(physics::Real::sqrt(x * x + y * y)));
+        let bounds = around(sweep.position).merged(&around(sweep.position + sweep.translation));
+
+        let mut earliest: Option<(usize, Impact<f32>)> = None;
+        self.broad_phase.query(&bounds, &mut |other| {
+            let Some(other_body) = self.bodies[other].as_ref().filter(|_| other != key) else {
+                return;
+            };
+            if other_body.sensor || !self.paired(key, body, other, other_body) {
+                return;
+            }
+
+            let still = Sweep::still(other_body.position(), other_body.orientation());
+            if let Some(impact) = collision::time_of_impact(body.shape(), sweep, other_body.shape(), &still, self.solver.slop) {
+                match earliest {
+                    Some((first, soonest)) if (soonest.fraction, first) <= (impact.fraction, other) => {},
+                    _ => earliest = Some((other, impact))
+                }
+            }
+        });
+        earliest
+    }
+
+    /// Whether the bodies under `a` and `b` may collide: their layers and masks let them, no
+    /// joint keeps them from it, and the pair filter agrees.
+    fn paired(&self, a: usize, body_a: &RigidBody<Self>, b: usize, body_b: &RigidBody<Self>) -> bool {
+        body_a.can_collide(body_b)
+            && self.unpaired.binary_search(&(a.min(b), a.max(b))).is_err()
+            && self.pair_filter.as_ref().is_none_or(|filter| filter(a, body_a, b, body_b))
+    }
+
+    /// Wakes the islands of sleeping bodies that have been given a force or a velocity since the
+    /// last step.
+    fn stir(&mut self) {
+        for (body, rest) in self.bodies.iter().zip(&self.rests) {
+            let (Some(body), Some(island)) = (body, rest.island) else {
+                continue;
+            };
+            let dynamics = body.dynamics();
+            if dynamics.force() != force::Newtons::zero() || dynamics.torque() != force::Torque::zero()
+                || dynamics.velocity != motion::Velocity::zero() || dynamics.angular_velocity != motion::AngularVelocity::zero()
+            {
+                self.waking.push(island);
+            }
+        }
+        self.wake_islands();
+    }
+
+    fn wake_islands(&mut self) {
+        if self.waking.is_empty() {
+            return;
+        }
+
+        for rest in self.rests.iter_mut() {
+            if rest.island.is_some_and(|island| self.waking.contains(&island)) {
+                *rest = Rest::default();
+            }
+        }
+        self.waking.clear();
+    }
+
+    /// Pairs up touching bodies that may collide, at least one of which is awake and can be
+    /// moved by them, and finds where sensors overlap bodies that aren't both fixed in place.
+    /// Sleeping bodies run into by moving ones are woken first.
+    fn find_contacts(&mut self) {
+        self.unpaired.clear();
+        self.unpaired.extend(self.joints.iter().flatten()
+            .filter(|joint| !joint.collide_connected && !joint.is_broken())
+            .map(|joint| (joint.a.min(joint.b), joint.a.max(joint.b))));
+        self.unpaired.sort_unstable();
+
+        self.track();
+        self.broad_phase.pairs(&mut self.pairs);
+
+        for &(a, b) in &self.pairs {
+            let (Some(body_a), Some(body_b)) = (&self.bodies[a], &self.bodies[b]) else {
+                continue;
+            };
+            let sleeper = match (self.rests[a].island, self.rests[b].island) {
+                (Some(island), None) if stirring(body_b) => island,
+                (None, Some(island)) if stirring(body_a) => island,
+                _ => continue
+            };
+            if body_a.sensor || body_b.sensor || !self.paired(a, body_a, b, body_b) {
+                continue;
+            }
+
+            if collision::overlaps(&body_a.boundary(), &body_b.boundary()) {
+                self.waking.push(sleeper);
+            }
+        }
+        self.wake_islands();
+
+        self.contacts.clear();
+        self.overlaps.clear();
+        for &(a, b) in &self.pairs {
+            let (Some(body_a), Some(body_b)) = (&self.bodies[a], &self.bodies[b]) else {
+                continue;
+            };
+            let sensing = body_a.sensor || body_b.sensor;
+            let (kind_a, kind_b) = (body_a.dynamics().kind, body_b.dynamics().kind);
+            let moving = if sensing {
+                kind_a != BodyKind::Static || kind_b != BodyKind::Static
+            } else {
+                awake(&self.bodies, &self.rests, a) || awake(&self.bodies, &self.rests, b)
+            };
+            if !moving || !self.paired(a, body_a, b, body_b) {
+                continue;
+            }
+
+            if sensing {
+                if collision::overlaps(&body_a.boundary(), &body_b.boundary()) {
+                    if body_a.sensor {
+                        self.overlaps.push((a, b));
+                    }
+                    if body_b.sensor {
+                        self.overlaps.push((b, a));
+                    }
+                }
+            } else if let Some(manifold) = collision::collide(&body_a.boundary(), &body_b.boundary()) {
+                self.contacts.push(ContactPair {
+                    a,
+                    b,
+                    manifold,
+                    material: body_a.material.combine(&body_b.material)
+                });
+            }
+        }
+
+        self.overlaps.sort_unstable();
+    }
+
+    /// Adds the events of the step just taken, from what touched over it and over the one
+    /// before.
+    fn report(&mut self) {
+        self.touches.clear();
+        let mut impulses = self.solver.impulses();
+        for contact in &self.contacts {
+            let points = contact.manifold.contacts();
+            self.touches.push(Touch {
+                a: contact.a,
+                b: contact.b,
+                normal: contact.manifold.normal,
+                point: points[0].point,
+                impulse: impulses.by_ref().take(points.len()).fold(Impulse::zero(), |total, (.., impulse)| total + impulse)
+            });
+        }
+
+        // Stable, so that where a pair both touched and was stopped short, the contact is kept
+        // and the impact adds its impulse.
+        self.touches.extend_from_slice(&self.impacts);
+        self.touches.sort_by_key(|touch| (touch.a, touch.b));
+        self.touches.dedup_by(|later, kept| {
+            let same = (later.a, later.b) == (kept.a, kept.b);
+            if same {
+                kept.impulse += later.impulse;
+            }
+            same
+        });
+
+        // Bodies asleep on each other, or on bodies that never move, weren't paired up, but
+        // still touch.
+        let (bodies, rests) = (&self.bodies, &self.rests);
+        let resting = |touch: &Touch<f32>| !awake(bodies, rests, touch.a) && !awake(bodies, rests, touch.b);
+        self.resting.clear();
+        self.resting.extend(self.touching.iter().filter(|touch| resting(touch)).copied());
+        self.touching.retain(|touch| !resting(touch));
+
+        events::compare_touches(&self.touching, &self.touches, &mut self.events);
+        self.touches.extend_from_slice(&self.resting);
+        self.touches.sort_by_key(|touch| (touch.a, touch.b));
+        core::mem::swap(&mut self.touching, &mut self.touches);
+
+        events::compare_overlaps(&self.sensed, &self.overlaps, &mut self.events);
+        self.sensed.clone_from(&self.overlaps);
+    }
+
+    /// Gathers the awake bodies into islands by what they touched and are joined to over the
+    /// step of `dt`, and puts to sleep those islands that have all been still for long enough.
+    fn settle(&mut self, dt: time::Seconds<f32>) {
+        self.wake_islands();
+
+        self.islands.reset(self.bodies.len());
+        let dynamic = |key: usize| self.bodies[key].as_ref().is_some_and(|body| body.dynamics().kind == BodyKind::Dynamic);
+        for contact in &self.contacts {
+            if dynamic(contact.a) && dynamic(contact.b) {
+                self.islands.join(contact.a, contact.b);
+            }
+        }
+        for joint in self.joints.iter().flatten() {
+            if !joint.is_broken() && dynamic(joint.a) && dynamic(joint.b) {
+                self.islands.join(joint.a, joint.b);
+            }
+        }
+
+        for (key, body) in self.bodies.iter().enumerate() {
+            let Some(body) = body else {
+                continue;
+            };
+            if !awake(&self.bodies, &self.rests, key) {
+                continue;
+            }
+
+            let dynamics = body.dynamics();
+            let still = self.sleep.is_some_and(|sleep| {
+                body.can_sleep
+                    && dynamics.velocity.magnitude() < sleep.linear_speed
+                    && dynamics.angular_velocity.value().abs() < sleep.angular_speed.value()
+            });
+            let rest = &mut self.rests[key];
+            rest.idle = if still { rest.idle + dt.value() } else { 0.0 };
+        }
+
+        // Bodies pushed by moving kinematic ones, or touching ones that can't sleep, are kept
+        // awake by them.
+        for contact in &self.contacts {
+            let (Some(body_a), Some(body_b)) = (&self.bodies[contact.a], &self.bodies[contact.b]) else {
+                continue;
+            };
+            if stirring(body_a) && (body_a.dynamics().kind == BodyKind::Kinematic || !body_a.can_sleep) {
+                self.rests[contact.b].idle = 0.0;
+            }
+            if stirring(body_b) && (body_b.dynamics().kind == BodyKind::Kinematic || !body_b.can_sleep) {
+                self.rests[contact.a].idle = 0.0;
+            }
+        }
+
+        self.least_idle.clear();
+        self.least_idle.resize(self.bodies.len(), f32::INFINITY);
+        for key in 0..self.bodies.len() {
+            if awake(&self.bodies, &self.rests, key) {
+                let island = self.islands.island(key);
+                self.least_idle[island] = self.least_idle[island].min(self.rests[key].idle);
+            }
+        }
+
+        let mut stats = StepStats::default();
+        for key in 0..self.bodies.len() {
+            if !awake(&self.bodies, &self.rests, key) {
+                stats.sleeping += usize::from(self.rests[key].island.is_some());
+                continue;
+            }
+
+            let island = self.islands.island(key);
+            if self.sleep.is_some_and(|sleep| self.least_idle[island] >= sleep.time.value()) {
+                self.rests[key].island = Some(island);
+                let dynamics = self.bodies[key].as_mut().expect("awake bodies are in the world").dynamics_mut();
+                dynamics.velocity = motion::Velocity::zero();
+                dynamics.angular_velocity = motion::AngularVelocity::zero();
+                stats.sleeping += 1;
+            } else {
+                stats.awake += 1;
+                stats.islands += usize::from(island == key);
+            }
+        }
+        self.stats = stats;
+    }
+}
+
+/// Whether the body under `key` is dynamic and awake.
+fn awake<P: BroadPhase<2, f32>>(bodies: &[Option<RigidBody<D2World<P>>>], rests: &[Rest], key: usize) -> bool {
+    bodies[key].as_ref().is_some_and(|body| body.dynamics().kind == BodyKind::Dynamic) && rests[key].island.is_none()
+}
+
+/// Whether `body` may move anything it touches.
+fn stirring(body: &RigidBody<D2World<impl BroadPhase<2, f32>>>) -> bool {
+    let dynamics = body.dynamics();
+    match dynamics.kind {
+        BodyKind::Static => false,
+        BodyKind::Kinematic => dynamics.velocity != motion::Velocity::zero() || dynamics.angular_velocity != motion::AngularVelocity::zero(),
+        BodyKind::Dynamic => true
+    }
+}
+
+/// The solver's view of `body` as it is now.
+fn solver_body(body: &RigidBody<D2World<impl BroadPhase<2, f32>>>) -> SolverBody<f32> {
+    SolverBody::new(
+        body.position(),
+        body.orientation(),
+        body.velocity(),
+        body.dynamics().angular_velocity,
+        body.dynamics().inverse_mass(),
+        body.dynamics().inverse_inertia()
+    )
+}
+
+impl<P: BroadPhase<2, f32>> Query<f32> for D2World<P> {
+    fn candidates(&self, bounds: &Bounds<2, f32>, filter: &Filter, found: &mut dyn FnMut(usize, &dyn Convex<f32>)) {
+        self.broad_phase.query(bounds, &mut |key| {
+            if let Some(body) = &self.bodies[key] {
+                if filter.admits(key, body.layers) {
+                    found(key, &body.boundary());
+                }
+            }
+        });
+    }
+}
+
+impl<P: BroadPhase<2, f32>> Universe for D2World<P> {
+    type Scalar = f32;
+
+    type Space = space::Meters<2, f32>;
+    type Time = time::Seconds<f32>;
+    type Mass = mass::Kilograms<f32>;
+
+    type Velocity = motion::Velocity<2, f32>;
+    type Acceleration = motion::Acceleration<2, f32>;
+    type Force = force::Newtons<2, f32>;
+
+    type InverseMass = mass::InverseKilograms<f32>;
+
+    type Orientation = Rotation2<f32>;
+    type AngularVelocity = motion::AngularVelocity<1, f32>;
+    type AngularAcceleration = motion::AngularAcceleration<1, f32>;
+    type Torque = force::Torque<1, f32>;
+    type InverseInertia = force::InverseMomentOfInertia<f32>;
+
+    type Units = conversion::SI;
+
+    fn time(&self) -> &Self::Time {
+        &self.abs_time
+    }
+}
+
+impl<P: BroadPhase<2, f32>> Simulation for D2World<P> {
+    /// Applies gravity and gathered forces, resolves contacts and joints, then moves every body.
+    fn step(&mut self, dt: Self::Time) {
+        self.stir();
+
+        let gravity = UniformGravity::new(self.gravity);
+        for (body, rest) in self.bodies.iter_mut().zip(&self.rests) {
+            let Some(body) = body else {
+                continue;
+            };
+            if rest.island.is_some() {
+                continue;
+            }
+            if body.dynamics().kind == BodyKind::Dynamic {
+                body.exert(&gravity, &dt);
+            }
+            body.dynamics_mut().accelerate(dt);
+        }
+
+        self.find_contacts();
+
+        self.solver_bodies.clear();
+        self.solver_bodies.extend(self.bodies.iter().map(|body| match body {
+            Some(body) => solver_body(body),
+            None => SolverBody::new(
+                space::Meters::zero(),
+                Rotation2::identity(),
+                motion::Velocity::zero(),
+                motion::AngularVelocity::zero(),
+                mass::InverseKilograms::zero(),
+                force::InverseMomentOfInertia::zero()
+            )
+        }));
+
+        let (bodies, rests) = (&self.bodies, &self.rests);
+        let joined = |joint: &&Joint<f32>| awake(bodies, rests, joint.a) || awake(bodies, rests, joint.b);
+        self.solver_joints.clear();
+        self.solver_joints.extend(self.joints.iter().flatten().filter(joined).copied());
+
+        self.solver.solve(&mut self.solver_bodies, &self.contacts, &mut self.solver_joints, dt);
+
+        self.starts.clear();
+        self.starts.extend(self.solver_bodies.iter().map(|solved| (solved.position, solved.rotation)));
+
+        for ((body, solved), rest) in self.bodies.iter_mut().zip(self.solver_bodies.iter_mut()).zip(&self.rests) {
+            let Some(body) = body else {
+                continue;
+            };
+            if body.dynamics().kind == BodyKind::Static || rest.island.is_some() {
+                continue;
+            }
+
+            solved.integrate(dt);
+            body.set_position(solved.position);
+
+            let dynamics = body.dynamics_mut();
+            dynamics.velocity = solved.velocity;
+            dynamics.angular_velocity = solved.angular_velocity;
+            dynamics.orientation = solved.rotation;
+        }
+
+        let (bodies, rests) = (&self.bodies, &self.rests);
+        let joined = |joint: &&mut Joint<f32>| awake(bodies, rests, joint.a) || awake(bodies, rests, joint.b);
+        for (joint, solved) in self.joints.iter_mut().flatten().filter(joined).zip(self.solver_joints.iter_mut()) {
+            solved.measure(&self.solver_bodies[solved.a], &self.solver_bodies[solved.b]);
+            *joint = *solved;
+        }
+
+        // So that queries between steps, and sweeps, see the bodies where they are.
+        self.track();
+        self.sweep_continuous(dt);
+        self.report();
+        self.settle(dt);
+
+        self.abs_time += dt;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use physics::units::force::Newtons;
+    use physics::units::mass::Kilograms;
+    use physics::units::motion::{Acceleration, AngularVelocity, Velocity};
+    use crate::areas::{Capsule, Circle, NonRotatingBox, OrientedBox};
+    use crate::solver::Material;
+    use super::*;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    fn at(x: f32, y: f32) -> space::Meters<2, f32> {
+        space::Meters::from_components(&[x, y])
+    }
+
+    /// The bullets fired at the wall, each about a centimetre across.
+    fn bullet(kind: usize, x: f32) -> RigidBody<D2World> {
+        let body = match kind {
+            0 => RigidBody::dynamic(Circle::new(at(x, 0.0), space::Meters::new(0.005)), Kilograms::new(0.01)),
+            1 => RigidBody::dynamic(OrientedBox::new(at(x, 0.0), at(0.01, 0.005), Rotation2::from_angle(0.3)), Kilograms::new(0.01)),
+            _ => RigidBody::dynamic(Capsule::new(at(x - 0.005, 0.0), at(x + 0.005, 0.0), space::Meters::new(0.0025)), Kilograms::new(0.01))
+        };
+        body.continuous(true).material(Material::new(0.3, 0.0))
+    }
+
+    /// Fires a bullet of `kind` at `speed` and `angle` from the x axis at a 2 cm wall whose
+    /// near face is at x = 1, returning the furthest the bullet got and where it ended up. The
+    /// wall is tall enough that a bullet glancing off it can't slide round its end in a second.
+    fn fire(kind: usize, speed: f32, angle: f32, spin: f32) -> (f32, f32) {
+        let mut world = D2World::new().gravity(Acceleration::zero());
+        world.add(RigidBody::fixed(NonRotatingBox::new(at(1.0, -10_000.0), at(1.02, 10_000.0))));
+
+        let key = world.add(bullet(kind, 0.0));
+        let dynamics = world.body_mut(key).unwrap().dynamics_mut();
+        dynamics.velocity = Velocity::from_components(&[speed * angle.cos(), speed * angle.sin()]);
+        dynamics.angular_velocity = AngularVelocity::new(spin);
+
+        let mut furthest = f32::MIN;
+        for _ in 0..60 {
+            world.step(time::Seconds::new(DT));
+            furthest = furthest.max(world.body(key).unwrap().position().values()[0]);
+        }
+
+        (furthest, world.body(key).unwrap().position().values()[0])
+    }
+
+    #[test]
+    fn bullets_never_pass_through_walls() {
+        for kind in 0..3 {
+            for speed in [100.0, 1000.0, 5000.0] {
+                for angle in [-1.0, -0.5, 0.0, 0.3, 0.5, 1.0] {
+                    for spin in [0.0, 300.0] {
+                        let (furthest, end) = fire(kind, speed, angle, spin);
+                        assert!(furthest < 1.02 && end < 1.0,
+                            "bullet {kind} at {speed} m/s and {angle} rad reached {furthest} and ended at {end}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn discrete_bullets_tunnel() {
+        let mut world = D2World::new().gravity(Acceleration::zero());
+        world.add(RigidBody::fixed(NonRotatingBox::new(at(1.0, -50.0), at(1.02, 50.0))));
+        let key = world.add(bullet(0, 0.0).continuous(false));
+        world.body_mut(key).unwrap().dynamics_mut().velocity = Velocity::from_components(&[1000.0, 0.0]);
+
+        for _ in 0..5 {
+            world.step(time::Seconds::new(DT));
+        }
+        assert!(world.body(key).unwrap().position().values()[0] > 1.02);
+    }
+
+    #[test]
+    fn bouncy_bullets_come_back() {
+        let mut world = D2World::new().gravity(Acceleration::zero());
+        world.add(RigidBody::fixed(NonRotatingBox::new(at(1.0, -50.0), at(1.02, 50.0))).material(Material::new(0.3, 1.0)));
+        let key = world.add(bullet(0, 0.0).material(Material::new(0.3, 1.0)));
+        world.body_mut(key).unwrap().dynamics_mut().velocity = Velocity::from_components(&[400.0, 0.0]);
+
+        for _ in 0..60 {
+            world.step(time::Seconds::new(DT));
+            assert!(world.body(key).unwrap().position().values()[0] < 1.0);
+        }
+        let [speed, _] = world.body(key).unwrap().velocity().values();
+        assert!((speed + 400.0).abs() < 4.0, "came back at {speed}");
+    }
+
+    #[test]
+    fn dropped_bodies_report_what_they_pass_and_land_on() {
+        let mut world = D2World::new().sleep(None);
+        let ground = world.add(RigidBody::fixed(NonRotatingBox::new(at(-10.0, -1.0), at(10.0, 0.0))));
+        let sensor = world.add(RigidBody::fixed(NonRotatingBox::new(at(-0.5, 1.0), at(0.5, 2.0))).sensor(true));
+        let ball = world.add(RigidBody::dynamic(Circle::new(at(0.0, 3.0), space::Meters::new(0.25)), Kilograms::new(1.0)));
+
+        let mut drained = Vec::new();
+        for step in 1..=60 {
+            world.step(time::Seconds::new(DT));
+            drained.extend(world.drain_events().map(|event| (step, event)));
+        }
+
+        // Having fallen g·dt²·n(n + 1)/2 after n steps, the ball first overlaps the sensor
+        // after 23, is clear of it after 41 and reaches the ground after 45, each found at the
+        // start of the next step
+        let mut expected = alloc::vec![
+            (24, "enter", sensor, ball),
+            (42, "exit", sensor, ball),
+            (46, "begin", ground, ball)
+        ];
+        expected.extend((47..=60).map(|step| (step, "persist", ground, ball)));
+
+        let kinds: Vec<_> = drained.iter().map(|&(step, event)| match event {
+            Event::BeginContact(touch) => (step, "begin", touch.a, touch.b),
+            Event::PersistContact(touch) => (step, "persist", touch.a, touch.b),
+            Event::EndContact(a, b) => (step, "end", a, b),
+            Event::TriggerEnter { sensor, visitor } => (step, "enter", sensor, visitor),
+            Event::TriggerExit { sensor, visitor } => (step, "exit", sensor, visitor)
+        }).collect();
+        assert_eq!(kinds, expected);
+
+        // Landing takes the ball's momentum after 46 steps of falling, and resting a step's
+        // worth of its weight, both pushing up
+        let weight = 9.81 * DT;
+        for &(step, event) in &drained[2..] {
+            let (Event::BeginContact(touch) | Event::PersistContact(touch)) = event else {
+                unreachable!("only contacts are left");
+            };
+            let impulse = if step == 46 { 46.0 * weight } else { weight };
+            assert!((touch.impulse.value() - impulse).abs() < 1e-3, "step {step}: {}", touch.impulse.value());
+            assert!((touch.normal.values()[1] - 1.0).abs() < 1e-6);
+        }
+    }
+
+    /// A metre-wide box of 1 kg centred at `x`, `y`.
+    fn crate_at(x: f32, y: f32) -> RigidBody<D2World> {
+        RigidBody::dynamic(NonRotatingBox::new(at(x - 0.5, y - 0.5), at(x + 0.5, y + 0.5)), Kilograms::new(1.0))
+    }
+
+    /// Two piles of four boxes, 10 m apart on the ground, returning the ground's key and the
+    /// boxes', bottom up and the left pile first.
+    fn piles(world: &mut D2World) -> (usize, Vec<usize>) {
+        let ground = world.add(RigidBody::fixed(NonRotatingBox::new(at(-20.0, -1.0), at(20.0, 0.0))));
+        let boxes = [-5.0, 5.0].into_iter()
+            .flat_map(|x| (0..4).map(move |i| (x, 0.5 + i as f32)))
+            .map(|(x, y)| world.add(crate_at(x, y)))
+            .collect();
+        (ground, boxes)
+    }
+
+    /// Steps until nothing is awake, returning how many steps that took.
+    fn settle(world: &mut D2World) -> usize {
+        (1..=600).find(|_| {
+            world.step(time::Seconds::new(DT));
+            world.stats().awake == 0
+        }).unwrap_or_else(|| panic!("still awake: {:?}", world.stats()))
+    }
+
+    #[test]
+    fn piles_sleep_as_islands() {
+        let mut world = D2World::new();
+        let (_, boxes) = piles(&mut world);
+
+        world.step(time::Seconds::new(DT));
+        assert_eq!(world.stats(), StepStats { awake: 8, sleeping: 0, islands: 2 });
+
+        // Resting from the start, they only have to stay still for the half second
+        let steps = settle(&mut world);
+        assert!(steps <= 40, "fell asleep after {steps} steps");
+        assert_eq!(world.stats(), StepStats { awake: 0, sleeping: 8, islands: 0 });
+
+        let resting: Vec<_> = boxes.iter().map(|&key| world.body(key).unwrap().position()).collect();
+        for _ in 0..120 {
+            world.step(time::Seconds::new(DT));
+        }
+        assert!(boxes.iter().zip(&resting).all(|(&key, &position)| world.body(key).unwrap().position() == position));
+        assert!(boxes.iter().all(|&key| world.is_asleep(key)));
+        assert!(world.contacts().is_empty());
+
+        // Without sleep, nothing ever does
+        let mut world = D2World::new().sleep(None);
+        piles(&mut world);
+        for _ in 0..120 {
+            world.step(time::Seconds::new(DT));
+        }
+        assert_eq!(world.stats(), StepStats { awake: 8, sleeping: 0, islands: 2 });
+    }
+
+    #[test]
+    fn sleepers_wake_to_forces_and_landings() {
+        let mut world = D2World::new();
+        let (_, boxes) = piles(&mut world);
+        settle(&mut world);
+
+        // A push on the top of the left pile wakes that pile alone
+        world.body_mut(boxes[3]).unwrap().dynamics_mut().apply_force(Newtons::from_components(&[5.0, 0.0]));
+        world.step(time::Seconds::new(DT));
+        assert_eq!(world.stats(), StepStats { awake: 4, sleeping: 4, islands: 1 });
+        assert!(!world.is_asleep(boxes[0]) && world.is_asleep(boxes[4]));
+        settle(&mut world);
+
+        // A box dropped on the right pile wakes it as it lands, and not before
+        let dropped = world.add(crate_at(5.0, 6.0));
+        let mut woke = None;
+        for step in 1..=60 {
+            world.step(time::Seconds::new(DT));
+            if woke.is_none() && !world.is_asleep(boxes[4]) {
+                woke = Some((step, world.body(dropped).unwrap().position().values()[1]));
+            }
+            assert!(world.is_asleep(boxes[0]), "the left pile woke");
+        }
+        let (step, height) = woke.expect("the right pile never woke");
+        assert!(step > 10 && height < 4.6, "woke after {step} steps with the box at {height}");
+
+        settle(&mut world);
+        assert_eq!(world.stats(), StepStats { awake: 0, sleeping: 9, islands: 0 });
+    }
+
+    #[test]
+    fn removing_what_sleepers_rest_on_wakes_them() {
+        let mut world = D2World::new();
+        let (ground, boxes) = piles(&mut world);
+        settle(&mut world);
+
+        world.remove(ground);
+        assert!(boxes.iter().all(|&key| !world.is_asleep(key)));
+        world.step(time::Seconds::new(DT));
+        assert_eq!(world.stats(), StepStats { awake: 8, sleeping: 0, islands: 2 });
+        for _ in 0..30 {
+            world.step(time::Seconds::new(DT));
+        }
+        assert!(world.body(boxes[0]).unwrap().position().values()[1] < 0.0);
+
+        // A box hanging still from a rope falls once the rope is cut
+        let mut world = D2World::new();
+        let peg = world.add(RigidBody::fixed(Circle::new(at(0.0, 10.0), space::Meters::new(0.1))));
+        let hanging = world.add(crate_at(0.0, 8.0));
+        let rope = world.connect(peg, hanging, JointKind::Distance { length: space::Meters::new(1.5) }, at(0.0, 10.0), at(0.0, 8.5));
+        let rope = world.add_joint(rope);
+        settle(&mut world);
+        assert!(world.is_asleep(hanging));
+
+        world.remove_joint(rope);
+        assert!(!world.is_asleep(hanging));
+        for _ in 0..30 {
+            world.step(time::Seconds::new(DT));
+        }
+        assert!(world.body(hanging).unwrap().position().values()[1] < 7.0);
+    }
+
+    /// Adds `body`, moving along the x axis at `speed`.
+    fn rolling(world: &mut D2World, body: RigidBody<D2World>, speed: f32) -> usize {
+        let key = world.add(body);
+        world.body_mut(key).unwrap().dynamics_mut().velocity = Velocity::from_components(&[speed, 0.0]);
+        key
+    }
+
+    /// A ball of 1 kg and half a metre across at `x` on the x axis.
+    fn ball(x: f32) -> RigidBody<D2World> {
+        RigidBody::dynamic(Circle::new(at(x, 0.0), space::Meters::new(0.25)), Kilograms::new(1.0))
+    }
+
+    #[test]
+    fn enemies_pass_through_each_other_but_hit_the_player() {
+        // Enemies are on layer 2 and only run into layer 1, where the player is
+        let enemy = |x| ball(x).layers(2).mask(1);
+        let player = NonRotatingBox::new(at(6.0, -1.0), at(7.0, 1.0));
+        assert!(!enemy(0.0).can_collide(&enemy(0.0)));
+        assert!(enemy(0.0).can_collide(&RigidBody::dynamic(player, Kilograms::new(1.0))));
+
+        // Both sides must agree: a player that ignores layer 2 is passed through as well
+        let aloof = RigidBody::dynamic(player, Kilograms::new(1.0)).mask(1);
+        assert!(!enemy(0.0).can_collide(&aloof) && !aloof.can_collide(&enemy(0.0)));
+
+        let mut world = D2World::new().gravity(Acceleration::zero());
+        let charging = rolling(&mut world, enemy(-2.0), 4.0);
+        let standing = world.add(enemy(2.0));
+        let player = world.add(RigidBody::dynamic(player, Kilograms::new(1.0)));
+
+        for _ in 0..120 {
+            world.step(time::Seconds::new(DT));
+            assert!(world.contacts().iter().all(|contact| contact.b == player));
+            assert_eq!(world.body(standing).unwrap().velocity(), Velocity::zero());
+        }
+
+        // Clean through the enemy standing in the way and into the player, who it shoves along
+        let touches: Vec<_> = world.drain_events().filter_map(|event| match event {
+            Event::BeginContact(touch) => Some((touch.a, touch.b)),
+            _ => None
+        }).collect();
+        assert_eq!(touches, [(charging, player)]);
+        assert!(world.body(player).unwrap().velocity().values()[0] > 1.0);
+        assert!(world.body(charging).unwrap().velocity().values()[0] < 3.0);
+    }
+
+    #[test]
+    fn pair_filters_keep_bodies_apart() {
+        // The ball added third is kept from the ground, and falls through it
+        let mut world = D2World::new().pair_filter(|a, _, b, _| a != 2 && b != 2);
+        let ground = world.add(RigidBody::fixed(NonRotatingBox::new(at(-10.0, -1.0), at(10.0, 0.0))));
+        let landing = world.add(RigidBody::dynamic(Circle::new(at(-2.0, 1.0), space::Meters::new(0.25)), Kilograms::new(1.0)));
+        let falling = world.add(RigidBody::dynamic(Circle::new(at(2.0, 1.0), space::Meters::new(0.25)), Kilograms::new(1.0)));
+        assert_eq!(falling, 2);
+
+        let mut pairs = Vec::new();
+        for _ in 0..60 {
+            world.step(time::Seconds::new(DT));
+            assert!(world.contacts().iter().all(|contact| (contact.a, contact.b) == (ground, landing)));
+            pairs.extend(world.drain_events().filter_map(|event| match event {
+                Event::BeginContact(touch) | Event::PersistContact(touch) => Some((touch.a, touch.b)),
+                Event::EndContact(a, b) => Some((a, b)),
+                _ => None
+            }));
+        }
+
+        assert!(!pairs.is_empty() && pairs.iter().all(|&pair| pair == (ground, landing)));
+        assert!((world.body(landing).unwrap().position().values()[1] - 0.25).abs() < 0.05);
+        assert!(world.body(falling).unwrap().position().values()[1] < -2.0);
+    }
+
+    #[test]
+    fn dynamic_sensors_notice_without_stopping_anything() {
+        let mut world = D2World::new().gravity(Acceleration::zero());
+        let sensor = world.add(RigidBody::dynamic(NonRotatingBox::new(at(-0.5, -0.5), at(0.5, 0.5)), Kilograms::new(1.0)).sensor(true));
+        let passing = rolling(&mut world, ball(-3.0), 4.0);
+
+        let mut events = Vec::new();
+        for _ in 0..120 {
+            world.step(time::Seconds::new(DT));
+            assert!(world.contacts().is_empty());
+            events.extend(world.drain_events().map(|event| match event {
+                Event::TriggerEnter { sensor, visitor } => ("enter", sensor, visitor),
+                Event::TriggerExit { sensor, visitor } => ("exit", sensor, visitor),
+                _ => ("contact", sensor, passing)
+            }));
+        }
+        assert_eq!(events, [("enter", sensor, passing), ("exit", sensor, passing)]);
+
+        // Neither pushed the other
+        assert_eq!(world.body(passing).unwrap().velocity().values(), [4.0, 0.0]);
+        assert_eq!(world.body(sensor).unwrap().velocity(), Velocity::zero());
+        assert_eq!(world.body(sensor).unwrap().position(), at(0.0, 0.0));
+    }
+}
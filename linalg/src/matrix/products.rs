@@ -37,4 +37,29 @@ impl <T: Mobile, const L: usize> Vector<T, L>
             *self.value_at(m, n) * *other.value_at(m, n)
         })
     }
-}
\ No newline at end of file
+}
+impl <T: Mobile, const L: usize> Vector<T, L>
+    where T: ops::Mul<Output = T>
+{
+    pub fn dot(&self, other: &Vector<T, L>) -> T {
+        let mut sum = T::zero();
+        for i in 0..L {
+            sum = sum + (*self.value_at(i, 0) * *other.value_at(i, 0));
+        }
+
+        sum
+    }
+}
+
+impl <T: Mobile> Vector<T, 3>
+    where T: ops::Mul<Output = T> + ops::Sub<Output = T>
+{
+    pub fn cross(&self, other: &Vector<T, 3>) -> Self {
+        let (a, b) = (self.data, other.data);
+        Vector::vector([
+            (a[1][0] * b[2][0]) - (a[2][0] * b[1][0]),
+            (a[2][0] * b[0][0]) - (a[0][0] * b[2][0]),
+            (a[0][0] * b[1][0]) - (a[1][0] * b[0][0]),
+        ])
+    }
+}
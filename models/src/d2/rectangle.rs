@@ -8,10 +8,10 @@ pub struct Rectangle {
 impl Rectangle {
     pub fn new() -> Self {
         let normal = [0.0, 0.0, -1.0];
-        let t_left = Vertex {pos: Vector::vector([-0.5, -0.5, 0.0]), normal: Vector::vector(normal)};
-        let t_right = Vertex {pos: Vector::vector([-0.5, 0.5, 0.0]), normal: Vector::vector(normal)};
-        let b_left = Vertex {pos: Vector::vector([0.5, -0.5, 0.0]), normal: Vector::vector(normal)};
-        let b_right = Vertex {pos: Vector::vector([0.5, 0.5, 0.0]), normal: Vector::vector(normal)};
+        let t_left = Vertex {pos: Vector::vector([-0.5, -0.5, 0.0]), normal: Vector::vector(normal), ..Default::default()};
+        let t_right = Vertex {pos: Vector::vector([-0.5, 0.5, 0.0]), normal: Vector::vector(normal), ..Default::default()};
+        let b_left = Vertex {pos: Vector::vector([0.5, -0.5, 0.0]), normal: Vector::vector(normal), ..Default::default()};
+        let b_right = Vertex {pos: Vector::vector([0.5, 0.5, 0.0]), normal: Vector::vector(normal), ..Default::default()};
 
         let vertices = [t_left, t_right, b_left, b_right];
         let indices = [0, 1, 2, 1, 2, 3];
@@ -8,9 +8,9 @@ pub struct Triangle {
 impl Triangle {
     pub fn new() -> Self {
         let normal = [0.0, 0.0, -1.0];
-        let top = Vertex {pos: Vector::vector([0.0, -0.5, 0.0]), normal: Vector::vector(normal)};
-        let b_left = Vertex {pos: Vector::vector([-0.5, 0.5, 0.0]), normal: Vector::vector(normal)};
-        let b_right = Vertex {pos: Vector::vector([0.5, 0.5, 0.0]), normal: Vector::vector(normal)};
+        let top = Vertex {pos: Vector::vector([0.0, -0.5, 0.0]), normal: Vector::vector(normal), ..Default::default()};
+        let b_left = Vertex {pos: Vector::vector([-0.5, 0.5, 0.0]), normal: Vector::vector(normal), ..Default::default()};
+        let b_right = Vertex {pos: Vector::vector([0.5, 0.5, 0.0]), normal: Vector::vector(normal), ..Default::default()};
         
         let vertices = [b_left, top, b_right];
         let indices = [0, 1, 2];
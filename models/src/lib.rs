@@ -2,6 +2,11 @@
 pub mod d2;
 pub mod d3;
 
+pub mod mesh;
+pub use mesh::Mesh;
+
+pub mod skinning;
+
 pub use linalg::Vector;
 use world::ModelMatrix;
 
@@ -9,13 +14,19 @@ use world::ModelMatrix;
 pub struct Vertex {
     pub pos: Vector<f32, 3>,
     pub normal: Vector<f32, 3>,
+    /// Indices into a `world::Skeleton`, for the joints that move this vertex.
+    pub joints: [u32; 4],
+    /// How much each of `joints` contributes. All zeroes leaves the vertex unskinned.
+    pub weights: Vector<f32, 4>,
 }
 
 impl core::default::Default for Vertex {
     fn default() -> Self {
         Self {
             pos: Vector::vector([0.0, 0.0, 0.0]),
-            normal: Vector::vector([0.0, 0.0, 0.0])
+            normal: Vector::vector([0.0, 0.0, 0.0]),
+            joints: [0; 4],
+            weights: Vector::vector([0.0, 0.0, 0.0, 0.0])
         }
     }
 }
@@ -69,7 +80,8 @@ impl<const V: usize, const I: usize, M: Modelable<V, I>> Transformable<V, I> for
         let vertices = model.vertices.map(|v| {
             Vertex {
                 pos: (m.matrix() * Vector::vec4(v.pos, 1.0)).vec3(),
-                normal: (m.matrix() * Vector::vec4(v.normal, 1.0)).vec3()
+                normal: (m.matrix() * Vector::vec4(v.normal, 1.0)).vec3(),
+                ..v
             }
         });
 
@@ -90,7 +102,8 @@ impl<const V: usize, const I: usize, M: Modelable<V, I>> Transformable<V, I> for
         let vertices = model.vertices.map(|v| {
             Vertex {
                 pos: (m.matrix() * Vector::vec4(v.pos, 1.0)).vec3(),
-                normal: (m.matrix() * Vector::vec4(v.normal, 1.0)).vec3()
+                normal: (m.matrix() * Vector::vec4(v.normal, 1.0)).vec3(),
+                ..v
             }
         });
 
@@ -111,7 +124,8 @@ impl<const V: usize, const I: usize, M: Modelable<V, I>> Transformable<V, I> for
         let vertices = model.vertices.map(|v| {
             Vertex {
                 pos: (m.matrix() * Vector::vec4(v.pos, 1.0)).vec3(),
-                normal: (m.matrix() * Vector::vec4(v.normal, 1.0)).vec3()
+                normal: (m.matrix() * Vector::vec4(v.normal, 1.0)).vec3(),
+                ..v
             }
         });
 
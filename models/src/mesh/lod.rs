@@ -0,0 +1,113 @@
+use super::{Mesh, Simplify};
+
+/// One level of detail, with the error it introduced relative to the source mesh.
+#[derive(Clone, Debug)]
+pub struct Lod {
+    pub mesh: Mesh,
+    pub error: f32
+}
+
+/// Progressively simplified copies of a mesh, finest first.
+#[derive(Clone, Debug)]
+pub struct LodChain {
+    levels: Vec<Lod>,
+    radius: f32
+}
+
+impl LodChain {
+    /// Builds `levels` levels, each keeping `reduction` of the previous level's triangles.
+    ///
+    /// Every level is simplified from the source mesh rather than from its predecessor, so the
+    /// recorded errors are all measured against the original surface. Simplification stops
+    /// early once a level fails to remove any more triangles.
+    pub fn build(mesh: &Mesh, levels: usize, reduction: f32) -> Self {
+        let (_, radius) = mesh.bounding_sphere();
+        let mut chain = vec![Lod { mesh: mesh.clone(), error: 0.0 }];
+
+        let mut target = mesh.triangle_count() as f32;
+        for _ in 1..levels {
+            target *= reduction;
+
+            let previous = chain[chain.len() - 1].mesh.triangle_count();
+            let (simplified, error) = mesh.simplify(&Simplify::to_triangles(target as usize));
+            if simplified.triangle_count() >= previous {
+                break;
+            }
+
+            chain.push(Lod { mesh: simplified, error });
+        }
+
+        Self {
+            levels: chain,
+            radius
+        }
+    }
+
+    pub fn levels(&self) -> &[Lod] {
+        &self.levels
+    }
+
+    /// Picks the coarsest level whose error stays under `max_screen_error` once drawn.
+    ///
+    /// Both `screen_size` and `max_screen_error` are fractions of the viewport height: the
+    /// former as returned by `screen_size`, the latter typically one pixel's worth.
+    pub fn select(&self, screen_size: f32, max_screen_error: f32) -> &Lod {
+        let diameter = (self.radius * 2.0).max(f32::EPSILON);
+
+        self.levels.iter()
+            .rev()
+            .find(|lod| (lod.error / diameter) * screen_size <= max_screen_error)
+            .unwrap_or(&self.levels[0])
+    }
+}
+
+/// The fraction of the viewport height covered by a bounding sphere under a perspective projection.
+pub fn screen_size(radius: f32, distance: f32, vertical_fov: f32) -> f32 {
+    let distance = distance.max(radius).max(f32::EPSILON);
+    radius / (distance * (vertical_fov * 0.5).tan())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::grid;
+
+    /// A grid with gentle bumps, so that every collapse costs something.
+    fn bumpy(n: usize) -> Mesh {
+        grid(n, |x, y| 0.1 * (x * 9.0).sin() * (y * 7.0).cos())
+    }
+
+    #[test]
+    fn levels_get_coarser() {
+        let chain = LodChain::build(&bumpy(12), 4, 0.5);
+        let levels = chain.levels();
+
+        assert_eq!(levels.len(), 4);
+        assert_eq!(levels[0].error, 0.0);
+        for pair in levels.windows(2) {
+            assert!(pair[1].mesh.triangle_count() < pair[0].mesh.triangle_count());
+            assert!(pair[1].error >= pair[0].error);
+        }
+    }
+
+    #[test]
+    fn selection_follows_screen_size() {
+        let chain = LodChain::build(&bumpy(12), 4, 0.5);
+        let levels = chain.levels();
+        let pixel = 1.0 / 1080.0;
+
+        // Filling the screen needs the source mesh, a speck on the horizon the coarsest level
+        let near = chain.select(10.0, pixel);
+        assert_eq!(near.mesh.triangle_count(), levels[0].mesh.triangle_count());
+
+        let far = chain.select(1e-6, pixel);
+        assert_eq!(far.mesh.triangle_count(), levels[levels.len() - 1].mesh.triangle_count());
+
+        let mut previous = usize::MAX;
+        for distance in [1.0, 5.0, 25.0, 125.0, 625.0] {
+            let chosen = chain.select(screen_size(0.75, distance, 1.0), pixel);
+            assert!(chosen.mesh.triangle_count() <= previous);
+            previous = chosen.mesh.triangle_count();
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{Model, Renderable, Vertex, Vector};
+
+mod simplify;
+pub use simplify::Simplify;
+
+mod lod;
+pub use lod::{Lod, LodChain, screen_size};
+
+mod subdivide;
+pub use subdivide::Creases;
+
+/// An indexed triangle list whose size is only known at runtime.
+///
+/// `Model` is sized at compile time, which suits the hand-built shapes in `d2` and `d3`.
+/// Imported and generated geometry goes through `Mesh` instead.
+#[derive(Clone, Debug, Default)]
+pub struct Mesh {
+    pub vertices: Vec<Vertex>,
+    pub indices: Vec<usize>
+}
+
+impl Mesh {
+    pub fn new(vertices: Vec<Vertex>, indices: Vec<usize>) -> Self {
+        Self {
+            vertices,
+            indices
+        }
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    pub fn triangles(&self) -> impl Iterator<Item = [usize; 3]> + '_ {
+        self.indices.chunks_exact(3).map(|t| [t[0], t[1], t[2]])
+    }
+
+    /// Centre and radius of a sphere enclosing every vertex.
+    pub fn bounding_sphere(&self) -> (Vector<f32, 3>, f32) {
+        if self.vertices.is_empty() {
+            return (Vector::vector([0.0, 0.0, 0.0]), 0.0);
+        }
+
+        let mut min = self.vertices[0].pos;
+        let mut max = self.vertices[0].pos;
+        for v in &self.vertices {
+            for i in 0..3 {
+                min.set(i, 0, min.value_at(i, 0).min(*v.pos.value_at(i, 0)));
+                max.set(i, 0, max.value_at(i, 0).max(*v.pos.value_at(i, 0)));
+            }
+        }
+
+        let center = (min + max) * 0.5;
+        let radius = self.vertices.iter()
+            .map(|v| {
+                let d = v.pos - center;
+                d.dot(&d)
+            })
+            .fold(0.0f32, f32::max)
+            .sqrt();
+
+        (center, radius)
+    }
+}
+
+impl<const V: usize, const I: usize> From<Model<V, I>> for Mesh {
+    fn from(model: Model<V, I>) -> Self {
+        Self {
+            vertices: model.vertices.to_vec(),
+            indices: model.indices.to_vec()
+        }
+    }
+}
+
+impl Renderable for Mesh {
+    fn vertices(&self) -> &[Vertex] {
+        &self.vertices
+    }
+
+    fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+}
+
+/// Vertices closer than this are treated as the same point, absorbing rounding from `Transformable`.
+const WELD_DISTANCE: f32 = 1e-5;
+
+pub(crate) fn weld_key(v: Vector<f32, 3>) -> [i64; 3] {
+    [0, 1, 2].map(|i| (*v.value_at(i, 0) / WELD_DISTANCE).round() as i64)
+}
+
+pub(crate) fn normalize(v: Vector<f32, 3>) -> Vector<f32, 3> {
+    let len = v.dot(&v).sqrt();
+    if len > f32::EPSILON {
+        v / len
+    } else {
+        v
+    }
+}
+
+/// A unit square in the xy plane split into `n` by `n` cells, raised by `height`.
+#[cfg(test)]
+pub(crate) fn grid(n: usize, height: impl Fn(f32, f32) -> f32) -> Mesh {
+    let mut vertices = vec![];
+    for y in 0..=n {
+        for x in 0..=n {
+            let (x, y) = (x as f32 / n as f32, y as f32 / n as f32);
+            vertices.push(Vertex {
+                pos: Vector::vector([x, y, height(x, y)]),
+                normal: Vector::vector([0.0, 0.0, 1.0]),
+                ..Vertex::default()
+            });
+        }
+    }
+
+    let mut indices = vec![];
+    for y in 0..n {
+        for x in 0..n {
+            let i = y * (n + 1) + x;
+            indices.extend([i, i + 1, i + n + 2, i, i + n + 2, i + n + 1]);
+        }
+    }
+
+    Mesh::new(vertices, indices)
+}
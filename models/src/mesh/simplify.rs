@@ -0,0 +1,552 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use linalg::Matrix;
+use crate::{Vertex, Vector};
+use super::{Mesh, normalize, weld_key};
+
+/// How strongly open borders resist being pulled inwards, relative to a face plane.
+const BORDER_WEIGHT: f64 = 1000.0;
+
+/// Quadric error metric edge-collapse simplification (Garland & Heckbert).
+///
+/// Collapsing stops at whichever limit is reached first: the triangle count falling to
+/// `target_triangles`, or the next collapse costing more than `max_error`. The error is
+/// expressed in model units, as the distance a vertex has drifted from its original planes.
+///
+/// Open borders are kept in place by constraint planes, and vertices that sit on an attribute
+/// seam (the same position carrying several normals, as on every edge of `d3::Cube`) or on a
+/// non-manifold edge are never moved.
+///
+/// `Vertex` carries no texture coordinates, so UV seams are not detected: a mesh whose seams
+/// only show up in its UVs will have them collapsed like any other edge. Seams that also split
+/// the normals are still preserved.
+#[derive(Copy, Clone, Debug)]
+pub struct Simplify {
+    pub target_triangles: usize,
+    pub max_error: f32
+}
+
+impl Simplify {
+    pub fn to_triangles(target_triangles: usize) -> Self {
+        Self {
+            target_triangles,
+            max_error: f32::INFINITY
+        }
+    }
+
+    pub fn to_error(max_error: f32) -> Self {
+        Self {
+            target_triangles: 0,
+            max_error
+        }
+    }
+
+    pub fn max_error(self, max_error: f32) -> Self {
+        Self {
+            max_error,
+            ..self
+        }
+    }
+}
+
+impl Mesh {
+    /// Simplifies the mesh, returning the result and the largest error any collapse introduced.
+    pub fn simplify(&self, options: &Simplify) -> (Mesh, f32) {
+        let mut state = State::new(self);
+        let error = state.run(options);
+        (state.into_mesh(), error)
+    }
+}
+
+type Quadric = Matrix<f64, 4, 4>;
+
+fn plane_quadric(normal: [f64; 3], point: [f64; 3], weight: f64) -> Quadric {
+    let d = -(normal[0] * point[0] + normal[1] * point[1] + normal[2] * point[2]);
+    let p = [normal[0], normal[1], normal[2], d];
+
+    let mut q = Matrix::new([[0.0; 4]; 4]);
+    for m in 0..4 {
+        for n in 0..4 {
+            q.set(m, n, p[m] * p[n] * weight);
+        }
+    }
+
+    q
+}
+
+fn quadric_error(q: &Quadric, p: [f64; 3]) -> f64 {
+    let v = [p[0], p[1], p[2], 1.0];
+    let mut sum = 0.0;
+    for m in 0..4 {
+        for n in 0..4 {
+            sum += v[m] * *q.value_at(m, n) * v[n];
+        }
+    }
+
+    sum.max(0.0)
+}
+
+/// The point minimising `q`, if the quadric is well conditioned enough to have one.
+fn quadric_minimum(q: &Quadric) -> Option<[f64; 3]> {
+    let a = |m: usize, n: usize| *q.value_at(m, n);
+    let det3 = |c: [[f64; 3]; 3]| {
+        c[0][0] * (c[1][1] * c[2][2] - c[1][2] * c[2][1])
+            - c[0][1] * (c[1][0] * c[2][2] - c[1][2] * c[2][0])
+            + c[0][2] * (c[1][0] * c[2][1] - c[1][1] * c[2][0])
+    };
+
+    let system = [
+        [a(0, 0), a(0, 1), a(0, 2)],
+        [a(1, 0), a(1, 1), a(1, 2)],
+        [a(2, 0), a(2, 1), a(2, 2)]
+    ];
+    let rhs = [-a(0, 3), -a(1, 3), -a(2, 3)];
+
+    let det = det3(system);
+    if det.abs() < 1e-12 {
+        return None;
+    }
+
+    let mut result = [0.0; 3];
+    for (col, out) in result.iter_mut().enumerate() {
+        let mut replaced = system;
+        for row in 0..3 {
+            replaced[row][col] = rhs[row];
+        }
+        *out = det3(replaced) / det;
+    }
+
+    Some(result)
+}
+
+fn to_f64(v: Vector<f32, 3>) -> [f64; 3] {
+    [*v.value_at(0, 0) as f64, *v.value_at(1, 0) as f64, *v.value_at(2, 0) as f64]
+}
+
+fn to_f32(v: [f64; 3]) -> Vector<f32, 3> {
+    Vector::vector([v[0] as f32, v[1] as f32, v[2] as f32])
+}
+
+fn edge_key(a: usize, b: usize) -> (usize, usize) {
+    if a < b { (a, b) } else { (b, a) }
+}
+
+fn link(links: &mut [Vec<usize>], a: usize, b: usize) {
+    if !links[a].contains(&b) {
+        links[a].push(b);
+        links[b].push(a);
+    }
+}
+
+fn face_normal(a: Vector<f32, 3>, b: Vector<f32, 3>, c: Vector<f32, 3>) -> Vector<f32, 3> {
+    (b - a).cross(&(c - a))
+}
+
+#[derive(Copy, Clone, PartialEq)]
+struct Candidate {
+    cost: f64,
+    from: usize,
+    into: usize,
+    target: [f64; 3],
+    stamps: (u32, u32)
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    // Reversed, so the `BinaryHeap` pops the cheapest collapse first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+struct State {
+    vertices: Vec<Vertex>,
+    position_of: Vec<usize>,
+    quadrics: Vec<Quadric>,
+    locked: Vec<bool>,
+    border: Vec<bool>,
+    /// For each vertex, the vertices it shares an open border edge with.
+    border_links: Vec<Vec<usize>>,
+    stamps: Vec<u32>,
+    faces: Vec<[usize; 3]>,
+    alive: Vec<bool>,
+    vertex_faces: Vec<Vec<usize>>,
+    triangle_count: usize,
+    heap: BinaryHeap<Candidate>
+}
+
+impl State {
+    fn new(mesh: &Mesh) -> Self {
+        // Weld identical vertices, and group the survivors by position so that seams are visible
+        let mut welded: HashMap<[i64; 6], usize> = HashMap::new();
+        let mut positions: HashMap<[i64; 3], usize> = HashMap::new();
+        let mut vertices = vec![];
+        let mut position_of = vec![];
+        let remap: Vec<usize> = mesh.vertices.iter().map(|v| {
+            let p = weld_key(v.pos);
+            let n = weld_key(v.normal);
+
+            *welded.entry([p[0], p[1], p[2], n[0], n[1], n[2]]).or_insert_with(|| {
+                let next_position = positions.len();
+                position_of.push(*positions.entry(p).or_insert(next_position));
+                vertices.push(*v);
+                vertices.len() - 1
+            })
+        }).collect();
+
+        let faces: Vec<[usize; 3]> = mesh.triangles()
+            .map(|t| t.map(|i| remap[i]))
+            .filter(|t| {
+                let p = t.map(|i| position_of[i]);
+                p[0] != p[1] && p[1] != p[2] && p[0] != p[2]
+            })
+            .collect();
+
+        let mut copies = vec![0usize; positions.len()];
+        for p in &position_of {
+            copies[*p] += 1;
+        }
+
+        let mut edge_faces: HashMap<(usize, usize), usize> = HashMap::new();
+        for face in &faces {
+            for k in 0..3 {
+                let key = edge_key(position_of[face[k]], position_of[face[(k + 1) % 3]]);
+                *edge_faces.entry(key).or_insert(0) += 1;
+            }
+        }
+
+        let mut locked: Vec<bool> = position_of.iter().map(|p| copies[*p] > 1).collect();
+        let mut border = vec![false; vertices.len()];
+        let mut border_links = vec![vec![]; vertices.len()];
+        let mut quadrics = vec![Matrix::new([[0.0; 4]; 4]); vertices.len()];
+        let mut vertex_faces = vec![vec![]; vertices.len()];
+
+        for (f, face) in faces.iter().enumerate() {
+            let [a, b, c] = face.map(|i| vertices[i].pos);
+            let normal = normalize(face_normal(a, b, c));
+            let q = plane_quadric(to_f64(normal), to_f64(a), 1.0);
+
+            for k in 0..3 {
+                let (i, j) = (face[k], face[(k + 1) % 3]);
+                vertex_faces[i].push(f);
+                quadrics[i] = quadrics[i] + q;
+
+                match edge_faces[&edge_key(position_of[i], position_of[j])] {
+                    1 => {
+                        // A plane through the border edge, perpendicular to the face, keeps
+                        // the border from sliding inwards as neighbouring vertices collapse.
+                        let edge = vertices[j].pos - vertices[i].pos;
+                        let constraint = normalize(edge.cross(&normal));
+                        let weight = BORDER_WEIGHT * edge.dot(&edge) as f64;
+                        let q = plane_quadric(to_f64(constraint), to_f64(vertices[i].pos), weight);
+                        quadrics[i] = quadrics[i] + q;
+                        quadrics[j] = quadrics[j] + q;
+
+                        border[i] = true;
+                        border[j] = true;
+                        link(&mut border_links, i, j);
+                    },
+                    2 => {},
+                    _ => {
+                        locked[i] = true;
+                        locked[j] = true;
+                    }
+                }
+            }
+        }
+
+        let triangle_count = faces.len();
+        let mut state = Self {
+            stamps: vec![0; vertices.len()],
+            alive: vec![true; faces.len()],
+            heap: BinaryHeap::new(),
+            vertices,
+            position_of,
+            quadrics,
+            locked,
+            border,
+            border_links,
+            faces,
+            vertex_faces,
+            triangle_count
+        };
+
+        for v in 0..state.vertices.len() {
+            state.push_candidates(v);
+        }
+
+        state
+    }
+
+    fn neighbours(&self, v: usize) -> HashSet<usize> {
+        let mut result = HashSet::new();
+        for f in &self.vertex_faces[v] {
+            if self.alive[*f] {
+                result.extend(self.faces[*f].iter().filter(|i| **i != v));
+            }
+        }
+
+        result
+    }
+
+    fn push_candidates(&mut self, v: usize) {
+        for n in self.neighbours(v) {
+            if let Some(candidate) = self.candidate(v, n) {
+                self.heap.push(candidate);
+            }
+        }
+    }
+
+    /// Works out which way an edge may collapse and where the surviving vertex would end up.
+    fn candidate(&self, a: usize, b: usize) -> Option<Candidate> {
+        let (from, into, fixed) = match (self.locked[a], self.locked[b]) {
+            (true, true) => return None,
+            (true, false) => (b, a, true),
+            (false, true) => (a, b, true),
+            (false, false) => match (self.border[a], self.border[b]) {
+                (true, true) if !self.border_links[a].contains(&b) => return None,
+                (true, false) => (b, a, true),
+                (false, true) => (a, b, true),
+                _ => (a, b, false)
+            }
+        };
+
+        let q = self.quadrics[from] + self.quadrics[into];
+        let p_from = to_f64(self.vertices[from].pos);
+        let p_into = to_f64(self.vertices[into].pos);
+
+        let mut options = vec![p_into];
+        if !fixed {
+            options.push(p_from);
+            options.push([0, 1, 2].map(|i| (p_from[i] + p_into[i]) * 0.5));
+            options.extend(quadric_minimum(&q));
+        }
+
+        let (target, cost) = options.into_iter()
+            .map(|p| (p, quadric_error(&q, p)))
+            .min_by(|x, y| x.1.total_cmp(&y.1))?;
+
+        Some(Candidate {
+            cost,
+            from,
+            into,
+            target,
+            stamps: (self.stamps[from], self.stamps[into])
+        })
+    }
+
+    /// Rejects collapses that would pinch the surface or fold a triangle over.
+    fn can_collapse(&self, from: usize, into: usize, target: Vector<f32, 3>) -> bool {
+        let shared_faces = self.vertex_faces[from].iter()
+            .filter(|f| self.alive[**f] && self.faces[**f].contains(&into))
+            .count();
+
+        let positions = |v: usize| -> HashSet<usize> {
+            self.neighbours(v).into_iter().map(|n| self.position_of[n]).collect()
+        };
+        if positions(from).intersection(&positions(into)).count() != shared_faces {
+            return false;
+        }
+
+        for v in [from, into] {
+            for f in &self.vertex_faces[v] {
+                let face = self.faces[*f];
+                if !self.alive[*f] || (face.contains(&from) && face.contains(&into)) {
+                    continue;
+                }
+
+                let before = face.map(|i| self.vertices[i].pos);
+                let after = face.map(|i| if i == from || i == into { target } else { self.vertices[i].pos });
+                let n_before = face_normal(before[0], before[1], before[2]);
+                let n_after = face_normal(after[0], after[1], after[2]);
+
+                if n_before.dot(&n_after) <= 0.0 {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    fn run(&mut self, options: &Simplify) -> f32 {
+        let max_cost = (options.max_error as f64).powi(2);
+        let mut worst = 0.0f64;
+
+        while self.triangle_count > options.target_triangles {
+            let Some(c) = self.heap.pop() else { break };
+            if c.stamps != (self.stamps[c.from], self.stamps[c.into]) {
+                continue;
+            }
+            if c.cost > max_cost {
+                break;
+            }
+
+            let target = to_f32(c.target);
+            if !self.can_collapse(c.from, c.into, target) {
+                continue;
+            }
+
+            self.collapse(c.from, c.into, target);
+            worst = worst.max(c.cost);
+        }
+
+        worst.sqrt() as f32
+    }
+
+    fn collapse(&mut self, from: usize, into: usize, target: Vector<f32, 3>) {
+        let start = self.vertices[from];
+        let end = self.vertices[into];
+
+        // Carry the normal along the edge to wherever the vertex has landed
+        let edge = end.pos - start.pos;
+        let length = edge.dot(&edge);
+        let t = if length > f32::EPSILON {
+            ((target - start.pos).dot(&edge) / length).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        self.vertices[into] = Vertex {
+            pos: target,
+            normal: normalize(start.normal * (1.0 - t) + end.normal * t),
+            ..end
+        };
+        self.quadrics[into] = self.quadrics[into] + self.quadrics[from];
+        self.border[into] = self.border[into] || self.border[from];
+
+        for f in std::mem::take(&mut self.vertex_faces[from]) {
+            if !self.alive[f] {
+                continue;
+            }
+
+            if self.faces[f].contains(&into) {
+                self.alive[f] = false;
+                self.triangle_count -= 1;
+            } else {
+                for i in self.faces[f].iter_mut() {
+                    if *i == from {
+                        *i = into;
+                    }
+                }
+                self.vertex_faces[into].push(f);
+            }
+        }
+
+        // Only the border edges that ran through `from` change, and they now run through `into`
+        for other in std::mem::take(&mut self.border_links[from]) {
+            self.border_links[other].retain(|v| *v != from);
+            if other != into {
+                link(&mut self.border_links, other, into);
+            }
+        }
+
+        self.stamps[from] += 1;
+        self.stamps[into] += 1;
+        for n in self.neighbours(into) {
+            self.stamps[n] += 1;
+            self.push_candidates(n);
+        }
+    }
+
+    fn into_mesh(self) -> Mesh {
+        let mut remap = vec![None; self.vertices.len()];
+        let mut vertices = vec![];
+        let mut indices = vec![];
+
+        for (f, face) in self.faces.iter().enumerate() {
+            if !self.alive[f] {
+                continue;
+            }
+
+            for i in face {
+                let idx = *remap[*i].get_or_insert_with(|| {
+                    vertices.push(self.vertices[*i]);
+                    vertices.len() - 1
+                });
+                indices.push(idx);
+            }
+        }
+
+        Mesh::new(vertices, indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::d3::Cube;
+    use crate::Modelable;
+    use crate::mesh::grid;
+
+    fn area(mesh: &Mesh) -> f32 {
+        mesh.triangles()
+            .map(|t| {
+                let [a, b, c] = t.map(|i| mesh.vertices[i].pos);
+                let n = face_normal(a, b, c);
+                n.dot(&n).sqrt() * 0.5
+            })
+            .sum()
+    }
+
+    fn has_point(mesh: &Mesh, x: f32, y: f32) -> bool {
+        mesh.vertices.iter().any(|v| (*v.pos.value_at(0, 0) - x).abs() < 1e-4 && (*v.pos.value_at(1, 0) - y).abs() < 1e-4)
+    }
+
+    #[test]
+    fn flat_grid_reaches_triangle_target() {
+        let mesh = grid(8, |_, _| 0.0);
+        assert_eq!(mesh.triangle_count(), 128);
+
+        for target in [64, 20, 8, 2] {
+            let (simplified, error) = mesh.simplify(&Simplify::to_triangles(target));
+            assert!(simplified.triangle_count() <= target, "{} > {target}", simplified.triangle_count());
+            assert!(error < 1e-3, "a flat grid should collapse for free, got {error}");
+        }
+    }
+
+    #[test]
+    fn borders_stay_in_place() {
+        let mesh = grid(8, |_, _| 0.0);
+        let (simplified, _) = mesh.simplify(&Simplify::to_triangles(2));
+
+        for (x, y) in [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)] {
+            assert!(has_point(&simplified, x, y), "lost the corner at ({x}, {y})");
+        }
+        assert!((area(&simplified) - 1.0).abs() < 1e-4, "area became {}", area(&simplified));
+        for v in &simplified.vertices {
+            for i in 0..2 {
+                let c = *v.pos.value_at(i, 0);
+                assert!((-1e-4..=1.0 + 1e-4).contains(&c), "vertex left the square at {c}");
+            }
+        }
+    }
+
+    #[test]
+    fn max_error_stops_collapsing() {
+        let mesh = grid(8, |x, y| 0.2 * (x * 6.0).sin() * (y * 6.0).cos());
+
+        let (coarse, coarse_error) = mesh.simplify(&Simplify::to_triangles(0));
+        let (fine, fine_error) = mesh.simplify(&Simplify::to_error(0.001));
+
+        assert!(fine_error <= 0.001);
+        assert!(fine.triangle_count() > coarse.triangle_count());
+        assert!(coarse_error > fine_error);
+    }
+
+    #[test]
+    fn seams_are_never_moved() {
+        let cube = Mesh::from(Cube::new().model());
+        let (simplified, error) = cube.simplify(&Simplify::to_triangles(0));
+
+        assert_eq!(simplified.triangle_count(), 12);
+        assert_eq!(error, 0.0);
+    }
+}
@@ -0,0 +1,618 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::{Vertex, Vector};
+use super::{Mesh, normalize, weld_key};
+
+/// Edges that subdivision should keep sharp, keyed by the vertex indices at either end.
+///
+/// A sharpness of `1.0` or more uses the sharp rules for that many levels, fractional values
+/// blend between smooth and sharp, and `f32::INFINITY` keeps the edge sharp at every level.
+/// Open borders are always treated as infinitely sharp.
+#[derive(Clone, Debug, Default)]
+pub struct Creases {
+    edges: Vec<(usize, usize, f32)>
+}
+
+impl Creases {
+    pub fn new() -> Self {
+        Self {
+            edges: vec![]
+        }
+    }
+
+    pub fn edge(self, a: usize, b: usize, sharpness: f32) -> Self {
+        let mut edges = self.edges;
+        edges.push((a, b, sharpness));
+
+        Self {
+            edges
+        }
+    }
+
+    /// Creases every edge where the faces on either side disagree about a vertex's attributes,
+    /// such as the hard edges of `d3::Cube`.
+    pub fn seams(mesh: &Mesh, sharpness: f32) -> Self {
+        let keys: Vec<[i64; 3]> = mesh.vertices.iter().map(|v| weld_key(v.pos)).collect();
+
+        let mut sides: HashMap<WeldedEdge, HashSet<(usize, usize)>> = HashMap::new();
+        for t in mesh.triangles() {
+            for k in 0..3 {
+                let (a, b) = (t[k], t[(k + 1) % 3]);
+                let (key, pair) = if keys[a] < keys[b] { ((keys[a], keys[b]), (a, b)) } else { ((keys[b], keys[a]), (b, a)) };
+                sides.entry(key).or_default().insert(pair);
+            }
+        }
+
+        let mut edges: Vec<(usize, usize, f32)> = sides.values()
+            .filter(|pairs| pairs.len() > 1)
+            .filter_map(|pairs| pairs.iter().min().map(|(a, b)| (*a, *b, sharpness)))
+            .collect();
+        edges.sort_by_key(|(a, b, _)| (*a, *b));
+
+        Self {
+            edges
+        }
+    }
+}
+
+impl Mesh {
+    /// Loop subdivision, returning one mesh per level with freshly computed normals.
+    ///
+    /// Every face is treated as a triangle, so quads should be split beforehand.
+    pub fn loop_subdivide(&self, levels: usize, creases: &Creases) -> Vec<Mesh> {
+        let mut polygons = Polygons::new(self, creases, false);
+        (0..levels).map(|_| {
+            polygons = polygons.loop_step();
+            polygons.mesh()
+        }).collect()
+    }
+
+    /// Catmull-Clark subdivision, returning one mesh per level with freshly computed normals.
+    ///
+    /// Coplanar triangle pairs are first merged back into quads, so meshes built from
+    /// `d2::Rectangle` faces subdivide as the quads they were authored as.
+    pub fn catmull_clark(&self, levels: usize, creases: &Creases) -> Vec<Mesh> {
+        let mut polygons = Polygons::new(self, creases, true);
+        (0..levels).map(|_| {
+            polygons = polygons.catmull_clark_step();
+            polygons.mesh()
+        }).collect()
+    }
+}
+
+type Point = Vector<f32, 3>;
+type WeldedEdge = ([i64; 3], [i64; 3]);
+
+fn edge_key(a: usize, b: usize) -> (usize, usize) {
+    if a < b { (a, b) } else { (b, a) }
+}
+
+fn lerp(a: Point, b: Point, t: f32) -> Point {
+    a * (1.0 - t) + b * t
+}
+
+fn average(points: impl Iterator<Item = Point>) -> Point {
+    let mut sum = Vector::vector([0.0, 0.0, 0.0]);
+    let mut count = 0.0;
+    for p in points {
+        sum = sum + p;
+        count += 1.0;
+    }
+
+    sum / count
+}
+
+/// Newell's method, which is robust for non-planar polygons as well as triangles.
+fn polygon_normal(points: &[Point]) -> Point {
+    let mut normal = Vector::vector([0.0, 0.0, 0.0]);
+    for i in 0..points.len() {
+        normal = normal + points[i].cross(&points[(i + 1) % points.len()]);
+    }
+
+    normal
+}
+
+/// How a vertex moves, decided by the sharp edges that meet at it.
+enum VertexRule {
+    Smooth,
+    Crease(usize, usize, f32),
+    Corner(f32)
+}
+
+/// A welded polygon mesh with its crease sharpness, as the subdivision schemes see it.
+struct Polygons {
+    positions: Vec<Point>,
+    faces: Vec<Vec<usize>>,
+    creases: HashMap<(usize, usize), f32>,
+    edge_faces: HashMap<(usize, usize), Vec<usize>>,
+    vertex_edges: Vec<Vec<usize>>
+}
+
+impl Polygons {
+    fn new(mesh: &Mesh, creases: &Creases, merge_quads: bool) -> Self {
+        let mut welded: HashMap<[i64; 3], usize> = HashMap::new();
+        let mut positions = vec![];
+        let remap: Vec<usize> = mesh.vertices.iter().map(|v| {
+            *welded.entry(weld_key(v.pos)).or_insert_with(|| {
+                positions.push(v.pos);
+                positions.len() - 1
+            })
+        }).collect();
+
+        let mut faces: Vec<Vec<usize>> = vec![];
+        let mut authored: Vec<Point> = vec![];
+        for t in mesh.triangles() {
+            let face: Vec<usize> = t.iter().map(|i| remap[*i]).collect();
+            if face[0] == face[1] || face[1] == face[2] || face[0] == face[2] {
+                continue;
+            }
+
+            faces.push(face);
+            authored.push(average(t.iter().map(|i| mesh.vertices[*i].normal)));
+        }
+
+        let creases = creases.edges.iter()
+            .map(|(a, b, s)| (edge_key(remap[*a], remap[*b]), *s))
+            .collect();
+
+        let mut polygons = Self::from_faces(positions, faces, creases);
+        polygons.orient(&authored);
+        if merge_quads {
+            polygons.merge_quads();
+        }
+
+        polygons
+    }
+
+    fn from_faces(positions: Vec<Point>, faces: Vec<Vec<usize>>, creases: HashMap<(usize, usize), f32>) -> Self {
+        let mut edge_faces: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        let mut vertex_edges = vec![vec![]; positions.len()];
+        for (f, face) in faces.iter().enumerate() {
+            for k in 0..face.len() {
+                let (a, b) = (face[k], face[(k + 1) % face.len()]);
+                let faces = edge_faces.entry(edge_key(a, b)).or_default();
+                if faces.is_empty() {
+                    vertex_edges[a].push(b);
+                    vertex_edges[b].push(a);
+                }
+                faces.push(f);
+            }
+        }
+
+        Self {
+            positions,
+            faces,
+            creases,
+            edge_faces,
+            vertex_edges
+        }
+    }
+
+    /// Makes neighbouring faces agree on winding, facing each island along its authored normals.
+    fn orient(&mut self, authored: &[Point]) {
+        let mut visited = vec![false; self.faces.len()];
+        for seed in 0..self.faces.len() {
+            if visited[seed] {
+                continue;
+            }
+
+            visited[seed] = true;
+            let mut island = vec![seed];
+            let mut queue = VecDeque::from([seed]);
+            while let Some(f) = queue.pop_front() {
+                let face = self.faces[f].clone();
+                for k in 0..face.len() {
+                    let (a, b) = (face[k], face[(k + 1) % face.len()]);
+                    let neighbours = &self.edge_faces[&edge_key(a, b)];
+                    if neighbours.len() != 2 {
+                        continue;
+                    }
+
+                    let n = if neighbours[0] == f { neighbours[1] } else { neighbours[0] };
+                    if visited[n] {
+                        continue;
+                    }
+
+                    let other = &self.faces[n];
+                    let i = other.iter().position(|v| *v == a).unwrap();
+                    if other[(i + 1) % other.len()] == b {
+                        self.faces[n].reverse();
+                    }
+
+                    visited[n] = true;
+                    island.push(n);
+                    queue.push_back(n);
+                }
+            }
+
+            let agreement: f32 = island.iter()
+                .map(|f| self.face_normal(*f).dot(&authored[*f]))
+                .sum();
+            if agreement < 0.0 {
+                for f in island {
+                    self.faces[f].reverse();
+                }
+            }
+        }
+    }
+
+    /// Pairs up coplanar triangles across smooth edges into quads.
+    fn merge_quads(&mut self) {
+        let mut merged = vec![false; self.faces.len()];
+        let mut faces = vec![];
+
+        for f in 0..self.faces.len() {
+            if merged[f] || self.faces[f].len() != 3 {
+                continue;
+            }
+
+            let face = self.faces[f].clone();
+            let normal = normalize(self.face_normal(f));
+            let partner = (0..3).find_map(|k| {
+                let (a, b) = (face[k], face[(k + 1) % 3]);
+                if self.creases.contains_key(&edge_key(a, b)) {
+                    return None;
+                }
+
+                let n = *self.edge_faces[&edge_key(a, b)].iter().find(|n| **n != f)?;
+                if merged[n] || self.faces[n].len() != 3 || normalize(self.face_normal(n)).dot(&normal) < 0.999 {
+                    return None;
+                }
+
+                let opposite = *self.faces[n].iter().find(|v| **v != a && **v != b)?;
+                Some((n, [face[(k + 2) % 3], a, opposite, b]))
+            });
+
+            if let Some((n, quad)) = partner {
+                merged[f] = true;
+                merged[n] = true;
+                faces.push(quad.to_vec());
+            }
+        }
+
+        for (f, face) in self.faces.iter().enumerate() {
+            if !merged[f] {
+                faces.push(face.clone());
+            }
+        }
+
+        let positions = std::mem::take(&mut self.positions);
+        let creases = std::mem::take(&mut self.creases);
+        *self = Self::from_faces(positions, faces, creases);
+    }
+
+    fn face_normal(&self, f: usize) -> Point {
+        let points: Vec<Point> = self.faces[f].iter().map(|v| self.positions[*v]).collect();
+        polygon_normal(&points)
+    }
+
+    fn sharpness(&self, a: usize, b: usize) -> f32 {
+        let key = edge_key(a, b);
+        if self.edge_faces[&key].len() != 2 {
+            return f32::INFINITY;
+        }
+
+        self.creases.get(&key).copied().unwrap_or(0.0)
+    }
+
+    fn vertex_rule(&self, v: usize) -> VertexRule {
+        let sharp: Vec<(usize, f32)> = self.vertex_edges[v].iter()
+            .map(|n| (*n, self.sharpness(v, *n)))
+            .filter(|(_, s)| *s > 0.0)
+            .collect();
+
+        let sharpness = sharp.iter().map(|(_, s)| *s).sum::<f32>() / sharp.len().max(1) as f32;
+        match sharp.len() {
+            0 | 1 => VertexRule::Smooth,
+            2 => VertexRule::Crease(sharp[0].0, sharp[1].0, sharpness),
+            _ => VertexRule::Corner(sharpness)
+        }
+    }
+
+    /// Positions a vertex under its rule, blending towards `smooth` for semi-sharp creases.
+    fn apply_rule(&self, v: usize, smooth: impl Fn() -> Point, crease: impl Fn(Point, Point) -> Point) -> Point {
+        let p = self.positions[v];
+        let (sharp, s) = match self.vertex_rule(v) {
+            VertexRule::Smooth => return smooth(),
+            VertexRule::Crease(a, b, s) => (crease(self.positions[a], self.positions[b]), s),
+            VertexRule::Corner(s) => (p, s)
+        };
+
+        if s >= 1.0 { sharp } else { lerp(smooth(), sharp, s) }
+    }
+
+    fn edge_point(&self, a: usize, b: usize, smooth: impl Fn() -> Point) -> Point {
+        let midpoint = (self.positions[a] + self.positions[b]) * 0.5;
+        match self.sharpness(a, b) {
+            s if s >= 1.0 => midpoint,
+            s if s > 0.0 => lerp(smooth(), midpoint, s),
+            _ => smooth()
+        }
+    }
+
+    /// Creases on the two halves of every split edge, one level less sharp.
+    fn child_creases(&self, edge_points: &HashMap<(usize, usize), usize>) -> HashMap<(usize, usize), f32> {
+        let mut creases = HashMap::new();
+        for ((a, b), s) in &self.creases {
+            let Some(mid) = edge_points.get(&(*a, *b)) else { continue };
+            if *s > 1.0 {
+                creases.insert(edge_key(*a, *mid), s - 1.0);
+                creases.insert(edge_key(*mid, *b), s - 1.0);
+            }
+        }
+
+        creases
+    }
+
+    fn loop_step(&self) -> Self {
+        let mut positions: Vec<Point> = (0..self.positions.len()).map(|v| {
+            let neighbours = &self.vertex_edges[v];
+            let smooth = || {
+                let n = neighbours.len() as f32;
+                let beta = {
+                    let c = 3.0 / 8.0 + 0.25 * (2.0 * std::f32::consts::PI / n).cos();
+                    (5.0 / 8.0 - c * c) / n
+                };
+
+                self.positions[v] * (1.0 - n * beta) + average(neighbours.iter().map(|n| self.positions[*n])) * (n * beta)
+            };
+
+            self.apply_rule(v, smooth, |a, b| self.positions[v] * 0.75 + (a + b) * 0.125)
+        }).collect();
+
+        let mut edge_points = HashMap::new();
+        let mut edges: Vec<&(usize, usize)> = self.edge_faces.keys().collect();
+        edges.sort();
+        for (a, b) in edges {
+            let faces = &self.edge_faces[&(*a, *b)];
+            let smooth = || {
+                let opposite = faces.iter().map(|f| {
+                    let face = &self.faces[*f];
+                    self.positions[*face.iter().find(|v| *v != a && *v != b).unwrap_or(a)]
+                });
+                (self.positions[*a] + self.positions[*b]) * 0.375 + average(opposite) * 0.25
+            };
+
+            edge_points.insert((*a, *b), positions.len());
+            positions.push(self.edge_point(*a, *b, smooth));
+        }
+
+        let mut faces = vec![];
+        for face in &self.faces {
+            let [a, b, c] = [face[0], face[1], face[2]];
+            let ab = edge_points[&edge_key(a, b)];
+            let bc = edge_points[&edge_key(b, c)];
+            let ca = edge_points[&edge_key(c, a)];
+
+            faces.push(vec![a, ab, ca]);
+            faces.push(vec![ab, b, bc]);
+            faces.push(vec![ca, bc, c]);
+            faces.push(vec![ab, bc, ca]);
+        }
+
+        Self::from_faces(positions, faces, self.child_creases(&edge_points))
+    }
+
+    fn catmull_clark_step(&self) -> Self {
+        let face_points: Vec<Point> = self.faces.iter()
+            .map(|face| average(face.iter().map(|v| self.positions[*v])))
+            .collect();
+
+        let mut vertex_faces = vec![vec![]; self.positions.len()];
+        for (f, face) in self.faces.iter().enumerate() {
+            for v in face {
+                vertex_faces[*v].push(f);
+            }
+        }
+
+        let mut positions: Vec<Point> = (0..self.positions.len()).map(|v| {
+            let smooth = || {
+                let p = self.positions[v];
+                let n = self.vertex_edges[v].len() as f32;
+                let f = average(vertex_faces[v].iter().map(|f| face_points[*f]));
+                let r = average(self.vertex_edges[v].iter().map(|e| (p + self.positions[*e]) * 0.5));
+
+                (f + r * 2.0 + p * (n - 3.0)) / n
+            };
+
+            self.apply_rule(v, smooth, |a, b| (a + b + self.positions[v] * 6.0) / 8.0)
+        }).collect();
+
+        let mut edge_points = HashMap::new();
+        let mut edges: Vec<&(usize, usize)> = self.edge_faces.keys().collect();
+        edges.sort();
+        for (a, b) in edges {
+            let faces = &self.edge_faces[&(*a, *b)];
+            let smooth = || {
+                let f = average(faces.iter().map(|f| face_points[*f]));
+                (self.positions[*a] + self.positions[*b] + f * 2.0) * 0.25
+            };
+
+            edge_points.insert((*a, *b), positions.len());
+            positions.push(self.edge_point(*a, *b, smooth));
+        }
+
+        let mut faces = vec![];
+        for (f, face) in self.faces.iter().enumerate() {
+            let center = positions.len();
+            positions.push(face_points[f]);
+
+            for k in 0..face.len() {
+                let prev = face[(k + face.len() - 1) % face.len()];
+                let next = face[(k + 1) % face.len()];
+                faces.push(vec![
+                    face[k],
+                    edge_points[&edge_key(face[k], next)],
+                    center,
+                    edge_points[&edge_key(prev, face[k])]
+                ]);
+            }
+        }
+
+        Self::from_faces(positions, faces, self.child_creases(&edge_points))
+    }
+
+    /// Triangulates into a `Mesh`, averaging normals across smooth edges and splitting them
+    /// across sharp ones.
+    fn mesh(&self) -> Mesh {
+        let normals: Vec<Point> = (0..self.faces.len()).map(|f| self.face_normal(f)).collect();
+
+        // Faces around a vertex that meet across a smooth edge share a normal
+        let mut group: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut parents: Vec<usize> = vec![];
+        for (f, face) in self.faces.iter().enumerate() {
+            for v in face {
+                group.insert((*v, f), parents.len());
+                parents.push(parents.len());
+            }
+        }
+
+        fn root(parents: &mut [usize], mut i: usize) -> usize {
+            while parents[i] != i {
+                parents[i] = parents[parents[i]];
+                i = parents[i];
+            }
+            i
+        }
+
+        for ((a, b), faces) in &self.edge_faces {
+            if faces.len() != 2 || self.sharpness(*a, *b) > 0.0 {
+                continue;
+            }
+
+            for v in [a, b] {
+                let x = root(&mut parents, group[&(*v, faces[0])]);
+                let y = root(&mut parents, group[&(*v, faces[1])]);
+                parents[x] = y;
+            }
+        }
+
+        let mut sums: HashMap<usize, Point> = HashMap::new();
+        for (f, face) in self.faces.iter().enumerate() {
+            for v in face {
+                let r = root(&mut parents, group[&(*v, f)]);
+                let sum = sums.entry(r).or_insert(Vector::vector([0.0, 0.0, 0.0]));
+                *sum = *sum + normals[f];
+            }
+        }
+
+        let mut vertices = vec![];
+        let mut emitted: HashMap<usize, usize> = HashMap::new();
+        let mut indices = vec![];
+        for (f, face) in self.faces.iter().enumerate() {
+            let corners: Vec<usize> = face.iter().map(|v| {
+                let r = root(&mut parents, group[&(*v, f)]);
+                *emitted.entry(r).or_insert_with(|| {
+                    vertices.push(Vertex {
+                        pos: self.positions[*v],
+                        normal: normalize(sums[&r]),
+                        ..Default::default()
+                    });
+                    vertices.len() - 1
+                })
+            }).collect();
+
+            for k in 1..corners.len() - 1 {
+                indices.extend([corners[0], corners[k], corners[k + 1]]);
+            }
+        }
+
+        Mesh::new(vertices, indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::d3::Cube;
+    use crate::Modelable;
+
+    fn tetrahedron() -> Mesh {
+        let vertices = [[1.0, 1.0, 1.0], [1.0, -1.0, -1.0], [-1.0, 1.0, -1.0], [-1.0, -1.0, 1.0]]
+            .map(|p| Vertex {
+                pos: Vector::vector(p),
+                normal: normalize(Vector::vector(p)),
+                ..Vertex::default()
+            });
+
+        Mesh::new(vertices.to_vec(), vec![0, 1, 2, 0, 3, 1, 0, 2, 3, 1, 3, 2])
+    }
+
+    fn positions(mesh: &Mesh) -> usize {
+        mesh.vertices.iter().map(|v| weld_key(v.pos)).collect::<HashSet<_>>().len()
+    }
+
+    fn largest_coordinate(p: Point) -> f32 {
+        (0..3).map(|i| p.value_at(i, 0).abs()).fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn loop_counts_per_level() {
+        let levels = tetrahedron().loop_subdivide(3, &Creases::new());
+
+        // Each triangle splits in four, and each edge gains a vertex: V' = V + E, E' = 2E + 3F
+        let expected = [(16, 10), (64, 34), (256, 130)];
+        for (mesh, (triangles, vertices)) in levels.iter().zip(expected) {
+            assert_eq!(mesh.triangle_count(), triangles);
+            assert_eq!(positions(mesh), vertices);
+        }
+    }
+
+    #[test]
+    fn catmull_clark_counts_per_level() {
+        let cube = Mesh::from(Cube::new().model());
+        let levels = cube.catmull_clark(3, &Creases::new());
+
+        // Each quad splits in four, and V' = V + E + F over the quads
+        let expected = [(48, 26), (192, 98), (768, 386)];
+        for (mesh, (triangles, vertices)) in levels.iter().zip(expected) {
+            assert_eq!(mesh.triangle_count(), triangles);
+            assert_eq!(positions(mesh), vertices);
+        }
+    }
+
+    #[test]
+    fn smooth_cube_rounds_off() {
+        let cube = Mesh::from(Cube::new().model());
+        let levels = cube.catmull_clark(3, &Creases::new());
+
+        let nearest = levels[2].vertices.iter().map(|v| largest_coordinate(v.pos)).fold(f32::INFINITY, f32::min);
+        assert!(nearest < 0.4, "a smooth cube should pull in from its faces, got {nearest}");
+    }
+
+    #[test]
+    fn creased_edges_stay_sharp() {
+        let cube = Mesh::from(Cube::new().model());
+        let creases = Creases::seams(&cube, f32::INFINITY);
+
+        for levels in [cube.catmull_clark(3, &creases), cube.loop_subdivide(3, &creases)] {
+            let last = &levels[2];
+            for v in &last.vertices {
+                assert!((largest_coordinate(v.pos) - 0.5).abs() < 1e-5, "a vertex left the cube's faces");
+            }
+            for corner in 0..8 {
+                let p = Vector::vector([0, 1, 2].map(|i| if corner & (1 << i) == 0 { -0.5 } else { 0.5 }));
+                assert!(last.vertices.iter().any(|v| weld_key(v.pos) == weld_key(p)), "lost a corner of the cube");
+            }
+
+            // Normals split across the creases, so every vertex faces straight out of its face
+            for v in &last.vertices {
+                assert!((largest_coordinate(v.normal) - 1.0).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn semi_sharp_creases_soften() {
+        let cube = Mesh::from(Cube::new().model());
+        let sharp = cube.catmull_clark(3, &Creases::seams(&cube, f32::INFINITY));
+        let soft = cube.catmull_clark(3, &Creases::seams(&cube, 1.0));
+        let smooth = cube.catmull_clark(3, &Creases::new());
+
+        let corner = |mesh: &Mesh| mesh.vertices.iter()
+            .map(|v| (0..3).map(|i| v.pos.value_at(i, 0).abs()).sum::<f32>())
+            .fold(0.0, f32::max);
+
+        assert!(corner(&sharp[2]) > corner(&soft[2]));
+        assert!(corner(&soft[2]) > corner(&smooth[2]));
+    }
+}
@@ -0,0 +1,130 @@
+use linalg::Matrix;
+use world::transform::identity;
+use crate::{Mesh, Vertex, Vector};
+
+/// Linear blend skinning on the CPU, mirroring what `skinned.vert` does on the GPU.
+///
+/// `joint_matrices` come from `world::Pose::joint_matrices`. Vertices whose weights are all zero
+/// are returned unchanged, and joints past the end of `joint_matrices` count as the identity,
+/// like the unused slots of a `world::JointPalette`. Normals are carried by the same matrices and renormalized, which is
+/// exact for rotations and uniform scale.
+pub fn skin(vertices: &[Vertex], joint_matrices: &[Matrix<f32, 4, 4>]) -> Vec<Vertex> {
+    vertices.iter().map(|v| skin_vertex(v, joint_matrices)).collect()
+}
+
+fn skin_vertex(v: &Vertex, joint_matrices: &[Matrix<f32, 4, 4>]) -> Vertex {
+    let mut total = 0.0;
+    let mut blended = Matrix::new([[0.0; 4]; 4]);
+    for i in 0..4 {
+        let weight = *v.weights.value_at(i, 0);
+        if weight == 0.0 {
+            continue;
+        }
+
+        let joint = joint_matrices.get(v.joints[i] as usize).copied().unwrap_or_else(identity);
+        blended = blended + joint * weight;
+        total += weight;
+    }
+
+    if total == 0.0 {
+        return *v;
+    }
+
+    let blended = blended * (1.0 / total);
+    let normal = (blended * Vector::vec4(v.normal, 0.0)).vec3();
+    let length = normal.dot(&normal).sqrt();
+
+    Vertex {
+        pos: (blended * Vector::vec4(v.pos, 1.0)).vec3(),
+        normal: if length > f32::EPSILON { normal / length } else { normal },
+        ..*v
+    }
+}
+
+impl Mesh {
+    pub fn skinned(&self, joint_matrices: &[Matrix<f32, 4, 4>]) -> Mesh {
+        Mesh::new(skin(&self.vertices, joint_matrices), self.indices.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use world::{Quaternion, Skeleton, Transform};
+
+    fn vertex(pos: [f32; 3], joints: [u32; 4], weights: [f32; 4]) -> Vertex {
+        Vertex {
+            pos: Vector::vector(pos),
+            normal: Vector::vector([0.0, 1.0, 0.0]),
+            joints,
+            weights: Vector::vector(weights)
+        }
+    }
+
+    fn translation(x: f32, y: f32, z: f32) -> Matrix<f32, 4, 4> {
+        Transform::from_translation(x, y, z).matrix()
+    }
+
+    fn close(a: Vector<f32, 3>, b: [f32; 3]) -> bool {
+        (0..3).all(|i| (a.value_at(i, 0) - b[i]).abs() < 1e-5)
+    }
+
+    #[test]
+    fn unweighted_vertices_stay_put() {
+        let v = vertex([1.0, 2.0, 3.0], [0; 4], [0.0; 4]);
+        let skinned = skin(&[v], &[translation(5.0, 0.0, 0.0)]);
+
+        assert!(close(skinned[0].pos, [1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn weights_blend_and_normalize() {
+        let joints = [translation(2.0, 0.0, 0.0), translation(0.0, 4.0, 0.0)];
+
+        let v = vertex([0.0, 0.0, 0.0], [0, 1, 0, 0], [0.5, 0.5, 0.0, 0.0]);
+        assert!(close(skin(&[v], &joints)[0].pos, [1.0, 2.0, 0.0]));
+
+        // Weights that don't add up to one are scaled so that they do
+        let v = vertex([0.0, 0.0, 0.0], [0, 1, 0, 0], [3.0, 1.0, 0.0, 0.0]);
+        assert!(close(skin(&[v], &joints)[0].pos, [1.5, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn missing_joints_are_identity() {
+        let joints = [translation(2.0, 0.0, 0.0)];
+        let v = vertex([1.0, 1.0, 1.0], [0, 7, 0, 0], [0.5, 0.5, 0.0, 0.0]);
+
+        assert!(close(skin(&[v], &joints)[0].pos, [2.0, 1.0, 1.0]));
+        assert!(close(skin(&[v], &[])[0].pos, [1.0, 1.0, 1.0]));
+    }
+
+    #[test]
+    fn normals_rotate_without_translating() {
+        let spin = Transform {
+            rotation: Quaternion::from_axis_angle(Vector::vector([0.0, 0.0, 1.0]), 90.0),
+            ..Transform::from_translation(3.0, 0.0, 0.0)
+        };
+        let v = vertex([1.0, 0.0, 0.0], [0; 4], [1.0, 0.0, 0.0, 0.0]);
+        let skinned = skin(&[v], &[spin.matrix()])[0];
+
+        assert!(close(skinned.pos, [3.0, 1.0, 0.0]));
+        assert!(close(skinned.normal, [-1.0, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn rest_pose_leaves_mesh_in_place() {
+        let skeleton = Skeleton::new()
+            .joint("root", None, Transform::from_translation(0.0, 1.0, 0.0))
+            .joint("arm", Some(0), Transform::from_translation(1.0, 0.0, 0.0));
+        let matrices = skeleton.rest_pose().joint_matrices(&skeleton);
+
+        let mesh = Mesh::new(vec![
+            vertex([0.5, 1.0, 0.0], [0, 1, 0, 0], [0.25, 0.75, 0.0, 0.0]),
+            vertex([2.0, 1.0, 0.0], [1, 0, 0, 0], [1.0, 0.0, 0.0, 0.0])
+        ], vec![0, 1, 1]);
+
+        for (before, after) in mesh.vertices.iter().zip(&mesh.skinned(&matrices).vertices) {
+            assert!(close(after.pos, [0, 1, 2].map(|i| *before.pos.value_at(i, 0))));
+        }
+    }
+}
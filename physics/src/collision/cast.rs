@@ -0,0 +1,237 @@
+//! Where moving shapes first touch, by conservative advancement: the shapes are moved forward
+//! by as much as they can be without meeting, judged from the distance between them and how
+//! fast it could close, until they touch.
+use crate::Real;
+use crate::rotation::Rotation2;
+use crate::units::space::Meters;
+use super::{Convex, Direction, Polygon};
+use super::gjk;
+
+const MAX_ITERATIONS: usize = 32;
+
+/// Where a moving shape first touches another.
+#[derive(Copy, Clone)]
+pub struct Impact<B: Real> {
+    /// How far along its translation the shape had moved, from zero to one.
+    pub fraction: B,
+    /// On the surface of the shape that was hit.
+    pub point: Meters<2, B>,
+    /// Unit length, out of the shape that was hit, towards the one that moved.
+    pub normal: Direction<B>
+}
+
+/// A shape moved by a fixed amount.
+struct Translated<'a, B: Real, S: ?Sized> {
+    shape: &'a S,
+    by: Meters<2, B>
+}
+
+impl<B: Real, S: Convex<B> + ?Sized> Convex<B> for Translated<'_, B, S> {
+    fn support(&self, direction: &Direction<B>) -> Meters<2, B> {
+        self.shape.support(direction) + self.by
+    }
+
+    fn radius(&self) -> Meters<1, B> {
+        self.shape.radius()
+    }
+
+    fn polygon(&self) -> Option<&dyn Polygon<B>> {
+        None
+    }
+}
+
+/// Where `moving`, moved by `translation`, first touches `target`, if it does. Shapes that
+/// already touch meet at fraction zero, with the normal against the translation.
+pub fn cast<B, A, C>(moving: &A, translation: Meters<2, B>, target: &C) -> Option<Impact<B>>
+    where B: Real, A: Convex<B> + ?Sized, C: Convex<B> + ?Sized
+{
+    let radii = moving.radius() + target.radius();
+    let tolerance = Meters::new(B::from_f32(1e-4));
+    let backwards = -translation.direction();
+
+    let mut fraction = B::zero();
+    for _ in 0..MAX_ITERATIONS {
+        let moved = Translated {
+            shape: moving,
+            by: translation.scale(fraction)
+        };
+
+        let Some(closest) = gjk::closest(&moved, target) else {
+            // Only reached at the start, since every advance stops short of touching.
+            return Some(Impact {
+                fraction,
+                point: moved.support(&-backwards),
+                normal: backwards
+            });
+        };
+
+        let towards = (closest.on_b - closest.on_a).direction();
+        let gap = closest.distance - radii;
+        if gap <= tolerance {
+            return Some(Impact {
+                fraction,
+                point: closest.on_b - towards * target.radius(),
+                normal: -towards
+            });
+        }
+
+        let closing = translation.dot(&towards);
+        if closing <= Meters::zero() {
+            return None;
+        }
+
+        // The distance between convex shapes falls no faster than it starts to, so this
+        // never passes the moment they touch.
+        fraction = fraction + ((gap - tolerance.scale(B::from_f32(0.5))) / closing).value();
+        if fraction > B::one() {
+            return None;
+        }
+    }
+
+    let moved = Translated {
+        shape: moving,
+        by: translation.scale(fraction)
+    };
+    Some(Impact {
+        fraction,
+        point: moved.support(&-backwards),
+        normal: backwards
+    })
+}
+
+/// How a body moves over a step: from its position and rotation at the start, by
+/// `translation`, turning by `turn` radians about its position.
+#[derive(Copy, Clone)]
+pub struct Sweep<B: Real> {
+    pub position: Meters<2, B>,
+    pub rotation: Rotation2<B>,
+    pub translation: Meters<2, B>,
+    pub turn: B
+}
+
+impl<B: Real> Sweep<B> {
+    /// A body that stays where it is.
+    pub fn still(position: Meters<2, B>, rotation: Rotation2<B>) -> Self {
+        Self {
+            position,
+            rotation,
+            translation: Meters::zero(),
+            turn: B::zero()
+        }
+    }
+
+    /// The sweep from one position and rotation to another, turning the shorter way.
+    pub fn between(start: (Meters<2, B>, Rotation2<B>), end: (Meters<2, B>, Rotation2<B>)) -> Self {
+        Self {
+            position: start.0,
+            rotation: start.1,
+            translation: end.0 - start.0,
+            turn: (end.1 * start.1.inverse()).angle()
+        }
+    }
+
+    /// How far the point `arm` from the body's position moves over the sweep, as it starts.
+    fn velocity_at(&self, arm: Meters<2, B>) -> Meters<2, B> {
+        let [x, y] = arm.values();
+        self.translation + Meters::from_components(&[-y, x]).scale(self.turn)
+    }
+
+    /// Where the body is, and how it is turned, `fraction` of the way through.
+    pub fn at(&self, fraction: B) -> (Meters<2, B>, Rotation2<B>) {
+        (
+            self.position + self.translation.scale(fraction),
+            Rotation2::from_angle(self.turn * fraction) * self.rotation
+        )
+    }
+}
+
+/// A shape given about the origin, turned and moved into place.
+struct Placed<'a, B: Real, S: ?Sized> {
+    shape: &'a S,
+    position: Meters<2, B>,
+    rotation: Rotation2<B>
+}
+
+impl<B: Real, S: Convex<B> + ?Sized> Convex<B> for Placed<'_, B, S> {
+    fn support(&self, direction: &Direction<B>) -> Meters<2, B> {
+        let local = self.rotation.inverse().rotate(direction);
+        self.rotation.rotate(&self.shape.support(&local)) + self.position
+    }
+
+    fn radius(&self) -> Meters<1, B> {
+        self.shape.radius()
+    }
+}
+
+/// How far any point of `shape` can be from the origin, or a little further.
+fn reach<B: Real, S: Convex<B> + ?Sized>(shape: &S) -> Meters<1, B> {
+    let along = |x: f32, y: f32| shape.support(&Direction::from_components(&[B::from_f32(x), B::from_f32(y)]));
+    let x = along(1.0, 0.0).value_at(0).max(-along(-1.0, 0.0).value_at(0));
+    let y = along(0.0, 1.0).value_at(1).max(-along(0.0, -1.0).value_at(1));
+
+    Meters::new((x * x + y * y).sqrt()) + shape.radius()
+}
+
+/// The first moment `a` and `b`, given about their positions and moving as swept, come within
+/// `target` of each other, as a fraction of the sweeps. `None` if they never do, or if they
+/// already overlap at the start. Shapes that start out within `target` of each other, with
+/// their nearest points moving apart, may close to half their gap before they count as meeting.
+pub fn time_of_impact<B, A, C>(a: &A, sweep_a: &Sweep<B>, b: &C, sweep_b: &Sweep<B>, target: Meters<1, B>) -> Option<Impact<B>>
+    where B: Real, A: Convex<B> + ?Sized, C: Convex<B> + ?Sized
+{
+    let radii = a.radius() + b.radius();
+    let spin = reach(a).scale(sweep_a.turn.abs()) + reach(b).scale(sweep_b.turn.abs());
+    let approach = sweep_a.translation - sweep_b.translation;
+
+    let mut target = target;
+    let mut fraction = B::zero();
+    for iteration in 0..MAX_ITERATIONS {
+        let (position_a, rotation_a) = sweep_a.at(fraction);
+        let (position_b, rotation_b) = sweep_b.at(fraction);
+        let placed_a = Placed { shape: a, position: position_a, rotation: rotation_a };
+        let placed_b = Placed { shape: b, position: position_b, rotation: rotation_b };
+
+        // Every advance stops short of touching, so only shapes that start out touching get here.
+        let closest = gjk::closest(&placed_a, &placed_b)?;
+
+        let towards = (closest.on_b - closest.on_a).direction();
+        let gap = closest.distance - radii;
+        if iteration == 0 && gap <= Meters::zero() {
+            return None;
+        }
+
+        let tolerance = target.scale(B::from_f32(0.25));
+        if gap <= target + tolerance {
+            let closing = (sweep_a.velocity_at(closest.on_a - position_a) - sweep_b.velocity_at(closest.on_b - position_b)).dot(&towards);
+            if iteration > 0 || closing > tolerance {
+                return Some(Impact {
+                    fraction,
+                    point: closest.on_b - towards * b.radius(),
+                    normal: -towards
+                });
+            }
+            target = gap.scale(B::from_f32(0.5));
+        }
+
+        // No point of either shape moves towards the other faster than this.
+        let bound = approach.dot(&towards) + spin;
+        if bound <= Meters::zero() {
+            return None;
+        }
+
+        fraction = fraction + ((gap - target) / bound).value();
+        if fraction > B::one() {
+            return None;
+        }
+    }
+
+    // Out of iterations while still closing in: stop here rather than risk passing through.
+    let (position_a, rotation_a) = sweep_a.at(fraction);
+    let placed_a = Placed { shape: a, position: position_a, rotation: rotation_a };
+    let normal = -approach.direction();
+    Some(Impact {
+        fraction,
+        point: placed_a.support(&-normal),
+        normal
+    })
+}
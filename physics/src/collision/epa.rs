@@ -0,0 +1,145 @@
+//! How far two overlapping cores reach into each other, by the expanding polytope algorithm:
+//! the simplex GJK stopped with is grown outwards until its nearest edge to the origin is on
+//! the boundary of the Minkowski difference.
+use crate::Real;
+use crate::units::space::Meters;
+use super::{Convex, Direction};
+use super::gjk::{Simplex, Vertex};
+
+const MAX_VERTICES: usize = 32;
+
+pub(super) struct Penetration<B: Real> {
+    /// Pointing from `a` into `b`.
+    pub normal: Direction<B>,
+    pub depth: Meters<1, B>,
+    pub on_a: Meters<2, B>,
+    pub on_b: Meters<2, B>
+}
+
+pub(super) fn penetration<B, A, C>(a: &A, b: &C, simplex: Simplex<B>) -> Penetration<B>
+    where B: Real, A: Convex<B> + ?Sized, C: Convex<B> + ?Sized
+{
+    let mut polytope = [simplex.vertices[0]; MAX_VERTICES];
+    polytope[..simplex.count].copy_from_slice(&simplex.vertices[..simplex.count]);
+
+    let mut count = simplex.count;
+    if count == 3 && area(&polytope) == B::zero() {
+        count = 2;
+    }
+    while count < 3 {
+        match widen(a, b, &polytope[..count]) {
+            Some(vertex) => {
+                polytope[count] = vertex;
+                count += 1;
+            },
+            None => return flat(&polytope[..count])
+        }
+    }
+
+    if area(&polytope) < B::zero() {
+        polytope.swap(1, 2);
+    }
+
+    let tolerance = B::from_f32(1e-4);
+
+    loop {
+        let (edge, normal, distance) = nearest_edge(&polytope[..count]);
+        let next = Vertex::support(a, b, &normal);
+        let reach = next.w.dot(&normal).value();
+
+        let repeated = polytope[..count].iter().any(|v| v.w == next.w);
+        if repeated || count == MAX_VERTICES || reach - distance <= tolerance * (B::one() + distance.abs()) {
+            return on_edge(&polytope[edge], &polytope[(edge + 1) % count], normal, distance);
+        }
+
+        polytope.copy_within(edge + 1..count, edge + 2);
+        polytope[edge + 1] = next;
+        count += 1;
+    }
+}
+
+/// Twice the signed area of the first three vertices, positive when counter-clockwise.
+fn area<B: Real>(polytope: &[Vertex<B>]) -> B {
+    (polytope[1].w - polytope[0].w).cross(&(polytope[2].w - polytope[0].w)).value()
+}
+
+/// A vertex that gives the degenerate `polytope` some width, if the cores have any.
+fn widen<B, A, C>(a: &A, b: &C, polytope: &[Vertex<B>]) -> Option<Vertex<B>>
+    where B: Real, A: Convex<B> + ?Sized, C: Convex<B> + ?Sized
+{
+    let (one, zero) = (B::one(), B::zero());
+    let tolerance = B::from_f32(1e-6);
+
+    let candidates = match polytope {
+        [_] => [[one, zero], [-one, zero], [zero, one], [zero, -one]],
+        [start, end] => {
+            let [x, y] = (end.w - start.w).values();
+            [[-y, x], [y, -x], [-y, x], [y, -x]]
+        },
+        _ => unreachable!("only a point or a segment needs widening")
+    };
+
+    candidates.iter()
+        .map(|d| Vertex::support(a, b, &Direction::from_components(d)))
+        .find(|vertex| match polytope {
+            [point] => (vertex.w - point.w).magnitude_squared().value() > tolerance,
+            [start, end] => (end.w - start.w).cross(&(vertex.w - start.w)).value().abs() > tolerance,
+            _ => false
+        })
+}
+
+/// Cores that only touch along a line or at a point, such as two concentric circles.
+fn flat<B: Real>(polytope: &[Vertex<B>]) -> Penetration<B> {
+    let normal = match polytope {
+        [start, end, ..] => {
+            let [x, y] = (end.w - start.w).values();
+            Meters::<2, B>::from_components(&[y, -x]).direction()
+        },
+        _ => Direction::from_components(&[B::one(), B::zero()])
+    };
+
+    Penetration {
+        normal,
+        depth: Meters::zero(),
+        on_a: polytope[0].a,
+        on_b: polytope[0].b
+    }
+}
+
+/// The edge of the counter-clockwise `polytope` nearest the origin, by its first vertex, with
+/// its outward normal and distance.
+fn nearest_edge<B: Real>(polytope: &[Vertex<B>]) -> (usize, Direction<B>, B) {
+    let mut nearest = None;
+
+    for i in 0..polytope.len() {
+        let start = polytope[i].w;
+        let [x, y] = (polytope[(i + 1) % polytope.len()].w - start).values();
+        let normal = Meters::<2, B>::from_components(&[y, -x]).direction();
+        let distance = normal.dot(&start).value();
+
+        match nearest {
+            Some((_, _, closest)) if closest <= distance => {},
+            _ => nearest = Some((i, normal, distance))
+        }
+    }
+
+    nearest.expect("a polytope has edges")
+}
+
+fn on_edge<B: Real>(start: &Vertex<B>, end: &Vertex<B>, normal: Direction<B>, distance: B) -> Penetration<B> {
+    let edge = end.w - start.w;
+    let length_squared = edge.magnitude_squared().value();
+
+    let t = if length_squared > B::zero() {
+        (-start.w.dot(&edge).value() / length_squared).max(B::zero()).min(B::one())
+    } else {
+        B::zero()
+    };
+
+    Penetration {
+        normal,
+        depth: Meters::new(distance),
+        on_a: start.a + (end.a - start.a).scale(t),
+        on_b: start.b + (end.b - start.b).scale(t)
+    }
+}
@@ -0,0 +1,223 @@
+//! The distance between two convex cores by the Gilbert–Johnson–Keerthi algorithm, which
+//! walks a simplex of the Minkowski difference towards the origin.
+use crate::Real;
+use crate::units::space::Meters;
+use super::{Convex, Direction};
+
+const MAX_ITERATIONS: usize = 32;
+
+/// The nearest points of two separate cores.
+#[derive(Copy, Clone)]
+pub struct Closest<B: Real> {
+    pub on_a: Meters<2, B>,
+    pub on_b: Meters<2, B>,
+    pub distance: Meters<1, B>
+}
+
+pub(super) enum Proximity<B: Real> {
+    Separated(Closest<B>),
+    /// The simplex that encloses the origin, or touches it.
+    Overlapping(Simplex<B>)
+}
+
+/// A point of the Minkowski difference `a - b`, remembering where it came from.
+#[derive(Copy, Clone)]
+pub(super) struct Vertex<B: Real> {
+    pub a: Meters<2, B>,
+    pub b: Meters<2, B>,
+    pub w: Meters<2, B>,
+    weight: B
+}
+
+impl<B: Real> Vertex<B> {
+    pub fn support<A, C>(a: &A, b: &C, direction: &Direction<B>) -> Self
+        where A: Convex<B> + ?Sized, C: Convex<B> + ?Sized
+    {
+        let on_a = a.support(direction);
+        let on_b = b.support(&-*direction);
+
+        Self {
+            a: on_a,
+            b: on_b,
+            w: on_a - on_b,
+            weight: B::one()
+        }
+    }
+}
+
+pub(super) struct Simplex<B: Real> {
+    pub vertices: [Vertex<B>; 3],
+    pub count: usize
+}
+
+/// The nearest points of the cores of `a` and `b`, or `None` where they overlap or touch.
+pub fn closest<B, A, C>(a: &A, b: &C) -> Option<Closest<B>>
+    where B: Real, A: Convex<B> + ?Sized, C: Convex<B> + ?Sized
+{
+    match proximity(a, b) {
+        Proximity::Separated(closest) => Some(closest),
+        Proximity::Overlapping(_) => None
+    }
+}
+
+pub(super) fn proximity<B, A, C>(a: &A, b: &C) -> Proximity<B>
+    where B: Real, A: Convex<B> + ?Sized, C: Convex<B> + ?Sized
+{
+    let first = Vertex::support(a, b, &Direction::from_components(&[B::one(), B::zero()]));
+    let mut simplex = Simplex {
+        vertices: [first; 3],
+        count: 1
+    };
+
+    let tolerance = B::from_f32(1e-5);
+
+    for _ in 0..MAX_ITERATIONS {
+        simplex.solve();
+        if simplex.count == 3 {
+            return Proximity::Overlapping(simplex);
+        }
+
+        let nearest = simplex.nearest();
+        let distance_squared = nearest.magnitude_squared().value();
+        if distance_squared <= tolerance * tolerance {
+            return Proximity::Overlapping(simplex);
+        }
+
+        let towards = simplex.towards_origin(nearest);
+        let next = Vertex::support(a, b, &towards);
+
+        let repeated = simplex.vertices[..simplex.count].iter().any(|v| v.w == next.w);
+        // How much nearer the origin the new vertex reaches than the simplex does, measured
+        // from a held vertex rather than the rounded nearest point.
+        let progress = (next.w - simplex.vertices[0].w).dot(&towards).value();
+        if repeated || progress <= tolerance * distance_squared.sqrt() * towards.magnitude().value() {
+            break;
+        }
+
+        simplex.vertices[simplex.count] = next;
+        simplex.count += 1;
+    }
+
+    // Out of iterations, the last vertex added hasn't been weighed yet.
+    simplex.solve();
+    if simplex.count == 3 {
+        return Proximity::Overlapping(simplex);
+    }
+
+    let (on_a, on_b) = simplex.witnesses();
+    Proximity::Separated(Closest {
+        on_a,
+        on_b,
+        distance: (on_b - on_a).magnitude()
+    })
+}
+
+impl<B: Real> Simplex<B> {
+    fn nearest(&self) -> Meters<2, B> {
+        self.weighted(|v| v.w)
+    }
+
+    /// Which way to look for the next vertex. From a segment, that is straight out of it
+    /// rather than along `-nearest`, whose rounding tilts it enough, on long segments, to find
+    /// a vertex already held and stop short.
+    fn towards_origin(&self, nearest: Meters<2, B>) -> Direction<B> {
+        if self.count != 2 {
+            return Direction::from_components(&(-nearest).values());
+        }
+
+        let [v1, v2, _] = self.vertices;
+        let [x, y] = (v2.w - v1.w).values();
+        let out = if (v2.w - v1.w).cross(&-v1.w).value() > B::zero() { [-y, x] } else { [y, -x] };
+        Direction::from_components(&out)
+    }
+
+    fn witnesses(&self) -> (Meters<2, B>, Meters<2, B>) {
+        (self.weighted(|v| v.a), self.weighted(|v| v.b))
+    }
+
+    fn weighted(&self, point: impl Fn(&Vertex<B>) -> Meters<2, B>) -> Meters<2, B> {
+        self.vertices[..self.count].iter().fold(Meters::zero(), |sum, v| sum + point(v).scale(v.weight))
+    }
+
+    /// Drops the vertices not needed to reach the point nearest the origin, and weights the
+    /// rest to give that point.
+    fn solve(&mut self) {
+        match self.count {
+            2 => self.solve_segment(),
+            3 => self.solve_triangle(),
+            _ => self.vertices[0].weight = B::one()
+        }
+    }
+
+    fn solve_segment(&mut self) {
+        let [v1, v2, _] = self.vertices;
+        let edge = v2.w - v1.w;
+
+        let toward_2 = -v1.w.dot(&edge).value();
+        let toward_1 = v2.w.dot(&edge).value();
+
+        if toward_2 <= B::zero() {
+            self.keep(0);
+        } else if toward_1 <= B::zero() {
+            self.keep(1);
+        } else {
+            self.weigh(&[(0, toward_1), (1, toward_2)]);
+        }
+    }
+
+    fn solve_triangle(&mut self) {
+        let [w1, w2, w3] = self.vertices.map(|v| v.w);
+
+        let e12 = w2 - w1;
+        let d12_1 = w2.dot(&e12).value();
+        let d12_2 = -w1.dot(&e12).value();
+
+        let e13 = w3 - w1;
+        let d13_1 = w3.dot(&e13).value();
+        let d13_2 = -w1.dot(&e13).value();
+
+        let e23 = w3 - w2;
+        let d23_1 = w3.dot(&e23).value();
+        let d23_2 = -w2.dot(&e23).value();
+
+        let area = e12.cross(&e13).value();
+        let d123_1 = area * w2.cross(&w3).value();
+        let d123_2 = area * w3.cross(&w1).value();
+        let d123_3 = area * w1.cross(&w2).value();
+
+        let zero = B::zero();
+        if d12_2 <= zero && d13_2 <= zero {
+            self.keep(0);
+        } else if d12_1 > zero && d12_2 > zero && d123_3 <= zero {
+            self.weigh(&[(0, d12_1), (1, d12_2)]);
+        } else if d13_1 > zero && d13_2 > zero && d123_2 <= zero {
+            self.weigh(&[(0, d13_1), (2, d13_2)]);
+        } else if d12_1 <= zero && d23_2 <= zero {
+            self.keep(1);
+        } else if d13_1 <= zero && d23_1 <= zero {
+            self.keep(2);
+        } else if d23_1 > zero && d23_2 > zero && d123_1 <= zero {
+            self.weigh(&[(1, d23_1), (2, d23_2)]);
+        } else {
+            self.weigh(&[(0, d123_1), (1, d123_2), (2, d123_3)]);
+        }
+    }
+
+    fn keep(&mut self, i: usize) {
+        self.weigh(&[(i, B::one())]);
+    }
+
+    /// Keeps the given vertices, weighted in proportion.
+    fn weigh(&mut self, weights: &[(usize, B)]) {
+        let total = weights.iter().fold(B::zero(), |sum, &(_, weight)| sum + weight);
+        let old = self.vertices;
+
+        for (slot, &(i, weight)) in weights.iter().enumerate() {
+            self.vertices[slot] = Vertex {
+                weight: weight / total,
+                ..old[i]
+            };
+        }
+        self.count = weights.len();
+    }
+}
@@ -0,0 +1,150 @@
+//! Narrow-phase collision detection between convex shapes in the plane.
+//!
+//! A shape is a convex core, found through its support points, rounded out by a radius: a
+//! circle is a rounded point and a capsule a rounded segment. Pairs of polygons are separated
+//! along their edge normals; everything else goes through GJK, and EPA once the cores overlap.
+use crate::Real;
+use crate::units::Quantity;
+use crate::units::dimension::Dimensionless;
+use crate::units::space::Meters;
+
+pub mod gjk;
+mod epa;
+mod sat;
+
+mod cast;
+pub use cast::{cast, time_of_impact, Impact, Sweep};
+
+/// A direction in the plane. Unit length where stated, any length otherwise.
+pub type Direction<B> = Quantity<2, B, Dimensionless>;
+
+/// A convex shape, given by the furthest point of its core in any direction.
+pub trait Convex<B: Real> {
+    /// The point of the core furthest along `direction`, which need not be unit length.
+    fn support(&self, direction: &Direction<B>) -> Meters<2, B>;
+
+    /// How far the shape reaches beyond its core in every direction.
+    fn radius(&self) -> Meters<1, B> {
+        Meters::zero()
+    }
+
+    /// The shape as a polygon, if it is one, so pairs of polygons can be separated by SAT.
+    fn polygon(&self) -> Option<&dyn Polygon<B>> {
+        None
+    }
+}
+
+/// A single point, as a shape with nothing to it.
+impl<B: Real> Convex<B> for Meters<2, B> {
+    fn support(&self, _direction: &Direction<B>) -> Meters<2, B> {
+        *self
+    }
+}
+
+/// A convex polygon with its vertices in counter-clockwise order.
+pub trait Polygon<B: Real>: Convex<B> {
+    fn vertex_count(&self) -> usize;
+    fn vertex(&self, i: usize) -> Meters<2, B>;
+
+    /// The outward unit normal of the edge from vertex `i` to the next.
+    fn normal(&self, i: usize) -> Direction<B> {
+        let edge = self.vertex((i + 1) % self.vertex_count()) - self.vertex(i);
+        let [x, y] = edge.values();
+        Meters::<2, B>::from_components(&[y, -x]).direction()
+    }
+}
+
+/// One point where two shapes touch, and how far they overlap there.
+#[derive(Copy, Clone)]
+pub struct Contact<B: Real> {
+    /// Halfway between the two surfaces.
+    pub point: Meters<2, B>,
+    pub depth: Meters<1, B>
+}
+
+/// Where two shapes touch: one or two contacts sharing a normal.
+#[derive(Copy, Clone)]
+pub struct Manifold<B: Real> {
+    /// Unit length, pointing from the first shape into the second.
+    pub normal: Direction<B>,
+    contacts: [Contact<B>; 2],
+    count: usize
+}
+
+impl<B: Real> Manifold<B> {
+    /// A manifold of the first one or two of `contacts`.
+    pub fn new(normal: Direction<B>, contacts: &[Contact<B>]) -> Self {
+        assert!(!contacts.is_empty() && contacts.len() <= 2, "a manifold holds one or two contacts");
+
+        Self {
+            normal,
+            contacts: [contacts[0], contacts[contacts.len() - 1]],
+            count: contacts.len()
+        }
+    }
+
+    pub fn contacts(&self) -> &[Contact<B>] {
+        &self.contacts[..self.count]
+    }
+
+    /// The deepest overlap of any contact.
+    pub fn depth(&self) -> Meters<1, B> {
+        self.contacts().iter().fold(Meters::zero(), |deepest, contact| {
+            if contact.depth > deepest { contact.depth } else { deepest }
+        })
+    }
+
+    /// The same contact seen from the second shape.
+    pub fn flipped(&self) -> Self {
+        Self {
+            normal: -self.normal,
+            ..*self
+        }
+    }
+}
+
+/// Whether `a` and `b` touch, and where.
+pub fn collide<B, A, C>(a: &A, b: &C) -> Option<Manifold<B>>
+    where B: Real, A: Convex<B> + ?Sized, C: Convex<B> + ?Sized
+{
+    match (a.polygon(), b.polygon()) {
+        (Some(a), Some(b)) => sat::polygons(a, b),
+        _ => rounded(a, b)
+    }
+}
+
+/// Whether `a` and `b` overlap or touch, which is cheaper to find than how.
+pub fn overlaps<B, A, C>(a: &A, b: &C) -> bool
+    where B: Real, A: Convex<B> + ?Sized, C: Convex<B> + ?Sized
+{
+    gjk::closest(a, b).is_none_or(|closest| closest.distance <= a.radius() + b.radius())
+}
+
+/// Collides any two shapes through the distance between their cores, measuring the overlap of
+/// the cores themselves when they meet.
+fn rounded<B, A, C>(a: &A, b: &C) -> Option<Manifold<B>>
+    where B: Real, A: Convex<B> + ?Sized, C: Convex<B> + ?Sized
+{
+    let (radius_a, radius_b) = (a.radius(), b.radius());
+
+    let (normal, core_depth, on_a, on_b) = match gjk::proximity(a, b) {
+        gjk::Proximity::Separated(closest) => {
+            if closest.distance > radius_a + radius_b {
+                return None;
+            }
+            ((closest.on_b - closest.on_a).direction(), -closest.distance, closest.on_a, closest.on_b)
+        },
+        gjk::Proximity::Overlapping(simplex) => {
+            let penetration = epa::penetration(a, b, simplex);
+            (penetration.normal, penetration.depth, penetration.on_a, penetration.on_b)
+        }
+    };
+
+    let surface_a = on_a + normal * radius_a;
+    let surface_b = on_b - normal * radius_b;
+
+    Some(Manifold::new(normal, &[Contact {
+        point: (surface_a + surface_b).scale(B::from_f32(0.5)),
+        depth: core_depth + radius_a + radius_b
+    }]))
+}
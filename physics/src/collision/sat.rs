@@ -0,0 +1,105 @@
+//! Polygons against polygons by the separating axis theorem: if no edge normal of either
+//! polygon separates them, the one they overlap least along gives the contact, and the
+//! opposite polygon's most facing edge is clipped to it for up to two contact points.
+use crate::Real;
+use crate::units::space::Meters;
+use super::{Contact, Manifold, Polygon};
+
+pub(super) fn polygons<B: Real>(a: &dyn Polygon<B>, b: &dyn Polygon<B>) -> Option<Manifold<B>> {
+    let (edge_a, separation_a) = deepest_separation(a, b);
+    if separation_a > B::zero() {
+        return None;
+    }
+
+    let (edge_b, separation_b) = deepest_separation(b, a);
+    if separation_b > B::zero() {
+        return None;
+    }
+
+    // Prefer the first polygon's face unless the second's is clearly better, so the contact
+    // doesn't flip between them from one step to the next.
+    let flip = separation_b > separation_a + B::from_f32(5e-4);
+    let (reference, incident, edge) = if flip { (b, a, edge_b) } else { (a, b, edge_a) };
+
+    let normal = reference.normal(edge);
+    let face_start = reference.vertex(edge);
+    let face_end = reference.vertex((edge + 1) % reference.vertex_count());
+
+    let facing = (0..incident.vertex_count())
+        .map(|i| (i, incident.normal(i).dot(&normal).value()))
+        .fold(None, |best: Option<(usize, B)>, (i, d)| match best {
+            Some((_, lowest)) if lowest <= d => best,
+            _ => Some((i, d))
+        })
+        .map(|(i, _)| i)
+        .expect("a polygon has edges");
+
+    let segment = [
+        incident.vertex(facing),
+        incident.vertex((facing + 1) % incident.vertex_count())
+    ];
+
+    let tangent = (face_end - face_start).direction();
+    let segment = clip(segment, -tangent, -tangent.dot(&face_start).value())?;
+    let segment = clip(segment, tangent, tangent.dot(&face_end).value())?;
+
+    let mut contacts = [None; 2];
+    for (contact, point) in contacts.iter_mut().zip(segment) {
+        let separation = normal.dot(&(point - face_start)).value();
+        if separation <= B::zero() {
+            *contact = Some(Contact {
+                point: point - normal * Meters::<1, B>::new(separation * B::from_f32(0.5)),
+                depth: Meters::new(-separation)
+            });
+        }
+    }
+
+    let manifold = match contacts {
+        [Some(first), Some(second)] => Manifold::new(normal, &[first, second]),
+        [Some(only), None] | [None, Some(only)] => Manifold::new(normal, &[only]),
+        [None, None] => return None
+    };
+
+    Some(if flip { manifold.flipped() } else { manifold })
+}
+
+/// The edge of `polygon` whose normal separates `other` from it the most, and by how much.
+/// Negative when every edge is overlapped.
+fn deepest_separation<B: Real>(polygon: &dyn Polygon<B>, other: &dyn Polygon<B>) -> (usize, B) {
+    let mut deepest = None;
+
+    for i in 0..polygon.vertex_count() {
+        let normal = polygon.normal(i);
+        let start = polygon.vertex(i);
+
+        let separation = (0..other.vertex_count())
+            .map(|j| normal.dot(&(other.vertex(j) - start)).value())
+            .fold(None, |lowest: Option<B>, s| Some(lowest.map_or(s, |lowest| lowest.min(s))))
+            .expect("a polygon has vertices");
+
+        match deepest {
+            Some((_, most)) if most >= separation => {},
+            _ => deepest = Some((i, separation))
+        }
+    }
+
+    deepest.expect("a polygon has edges")
+}
+
+/// The part of `segment` behind the line of points `p` with `normal · p = offset`, if any.
+fn clip<B: Real>(segment: [Meters<2, B>; 2], normal: super::Direction<B>, offset: B) -> Option<[Meters<2, B>; 2]> {
+    let [start, end] = segment;
+    let distance_start = normal.dot(&start).value() - offset;
+    let distance_end = normal.dot(&end).value() - offset;
+
+    let zero = B::zero();
+    match (distance_start <= zero, distance_end <= zero) {
+        (true, true) => Some(segment),
+        (false, false) => None,
+        (start_kept, _) => {
+            let t = distance_start / (distance_start - distance_end);
+            let crossing = start + (end - start).scale(t);
+            Some(if start_kept { [start, crossing] } else { [crossing, end] })
+        }
+    }
+}
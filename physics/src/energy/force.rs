@@ -1,6 +1,103 @@
-use crate::Universe;
+use crate::{Universe, Zero};
 
-/// The movement of `Mass` through `Space` over an amount of `Time`
+/// The state of a body that a force acts on, at one moment.
+pub struct Subject<U: Universe> {
+    pub position: U::Space,
+    pub velocity: U::Velocity,
+    pub mass: U::Mass
+}
+
+impl<U: Universe> Subject<U> {
+    pub fn new(position: U::Space, velocity: U::Velocity, mass: U::Mass) -> Self {
+        Self {
+            position,
+            velocity,
+            mass
+        }
+    }
+}
+
+impl<U: Universe> Clone for Subject<U> {
+    fn clone(&self) -> Self { *self }
+}
+impl<U: Universe> Copy for Subject<U> {}
+
+/// A push on `Mass` that changes how it moves through `Space` over `Time`
+///
+/// Several forces act as one when grouped in a tuple or a slice, so everything acting on a body
+/// in a step can be summed with a single call.
 pub trait Force<U: Universe> {
-    fn applied(&self, point: &U::Space, duration: &U::Time) -> (U::Mass, U::Space, U::Time);
-}
\ No newline at end of file
+    /// The force felt by `body`, held for `duration`.
+    fn applied(&self, body: &Subject<U>, duration: &U::Time) -> U::Force;
+}
+
+impl<U: Universe, F: Force<U> + ?Sized> Force<U> for &F {
+    fn applied(&self, body: &Subject<U>, duration: &U::Time) -> U::Force {
+        (**self).applied(body, duration)
+    }
+}
+
+impl<U: Universe, F: Force<U>> Force<U> for [F] {
+    fn applied(&self, body: &Subject<U>, duration: &U::Time) -> U::Force {
+        self.iter().fold(U::Force::zero(), |total, f| total + f.applied(body, duration))
+    }
+}
+
+macro_rules! tuple_forces {
+    ($($name:ident),*) => {
+        impl<U: Universe, $($name: Force<U>),*> Force<U> for ($($name,)*) {
+            #[allow(non_snake_case)]
+            fn applied(&self, body: &Subject<U>, duration: &U::Time) -> U::Force {
+                let ($($name,)*) = self;
+                U::Force::zero() $(+ $name.applied(body, duration))*
+            }
+        }
+    };
+}
+
+tuple_forces!(A);
+tuple_forces!(A, C);
+tuple_forces!(A, C, D);
+tuple_forces!(A, C, D, E);
+tuple_forces!(A, C, D, E, F);
+tuple_forces!(A, C, D, E, F, G);
+tuple_forces!(A, C, D, E, F, G, H);
+tuple_forces!(A, C, D, E, F, G, H, I);
+
+#[cfg(test)]
+mod tests {
+    use crate::forces::{LinearDrag, Spring, UniformGravity};
+    use crate::testing::{subject, Plane};
+    use crate::units::motion::Acceleration;
+    use crate::units::space::Meters;
+    use crate::units::time::Seconds;
+    use super::*;
+
+    #[test]
+    fn tuples_sum_their_forces() {
+        let gravity = UniformGravity::<Plane>::new(Acceleration::from_components(&[0.0, -10.0]));
+        let drag = LinearDrag::new(0.5);
+        let body = subject([0.0, 0.0], [4.0, -2.0], 3.0);
+        let dt = Seconds::new(0.1);
+
+        let total = (&gravity, &drag).applied(&body, &dt);
+        assert_eq!(total.values(), (gravity.applied(&body, &dt) + drag.applied(&body, &dt)).values());
+        assert_eq!(total.values(), [-2.0, -29.0]);
+    }
+
+    #[test]
+    fn slices_sum_their_forces() {
+        // Springs either side, each stretched a metre, pull towards the stiffer one
+        let springs = [
+            Spring::new(Meters::from_components(&[-2.0, 0.0]), Meters::new(1.0), 10.0),
+            Spring::new(Meters::from_components(&[2.0, 0.0]), Meters::new(1.0), 30.0)
+        ];
+        let dt = Seconds::new(0.1);
+
+        let middle = Force::<Plane>::applied(&springs[..], &subject([0.0, 0.0], [0.0, 0.0], 1.0), &dt);
+        assert_eq!(middle.values(), [20.0, 0.0]);
+
+        let empty: &[Spring<2, f64>] = &[];
+        assert_eq!(Force::<Plane>::applied(empty, &subject([0.0, 0.0], [0.0, 0.0], 1.0), &dt).values(), [0.0, 0.0]);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{Force, Real, Subject, Universe};
+use crate::units::force::Newtons;
+use crate::units::mass::Kilograms;
+use crate::units::motion::Acceleration;
+use crate::units::space::Meters;
+
+/// Lifts a body against `gravity` by the weight of the fluid it displaces below `surface`.
+///
+/// The body is treated as `height` tall along the vertical, centred on its position, and
+/// displaces `displaced` of fluid when fully under. Heights are measured against gravity.
+pub struct Buoyancy<const D: usize, B: Real> {
+    pub gravity: Acceleration<D, B>,
+    pub surface: Meters<1, B>,
+    pub displaced: Kilograms<B>,
+    pub height: Meters<1, B>
+}
+
+impl<const D: usize, B: Real> Buoyancy<D, B> {
+    pub fn new(gravity: Acceleration<D, B>, surface: Meters<1, B>, displaced: Kilograms<B>, height: Meters<1, B>) -> Self {
+        Self {
+            gravity,
+            surface,
+            displaced,
+            height
+        }
+    }
+
+    /// How much of a body at `position` is under the surface, from `0` to `1`.
+    pub fn submerged(&self, position: &Meters<D, B>) -> B {
+        let up = -self.gravity.direction();
+        let depth = (self.surface - position.dot(&up)).value();
+
+        if self.height.value() <= B::zero() {
+            return if depth > B::zero() { B::one() } else { B::zero() };
+        }
+
+        (depth / self.height.value() + B::from_f32(0.5)).max(B::zero()).min(B::one())
+    }
+}
+
+impl<const D: usize, B: Real, U> Force<U> for Buoyancy<D, B>
+    where U: Universe<Space = Meters<D, B>, Force = Newtons<D, B>>
+{
+    fn applied(&self, body: &Subject<U>, _duration: &U::Time) -> U::Force {
+        -(self.gravity * self.displaced).scale(self.submerged(&body.position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::forces::{LinearDrag, UniformGravity};
+    use crate::testing::{simulate, subject, Plane};
+    use super::*;
+
+    fn water() -> Buoyancy<2, f64> {
+        Buoyancy::new(Acceleration::from_components(&[0.0, -10.0]), Meters::new(0.0), Kilograms::new(4.0), Meters::new(2.0))
+    }
+
+    #[test]
+    fn submerged_fraction() {
+        let water = water();
+        for (height, fraction) in [(2.0, 0.0), (1.0, 0.0), (0.5, 0.25), (0.0, 0.5), (-0.5, 0.75), (-1.0, 1.0), (-3.0, 1.0)] {
+            assert!((water.submerged(&Meters::from_components(&[7.0, height])) - fraction).abs() < 1e-12, "at {height}");
+        }
+    }
+
+    #[test]
+    fn floats_where_weight_matches_displacement() {
+        // A 3 kg body displacing 4 kg of water floats three quarters under, its centre half a
+        // metre down
+        let gravity = UniformGravity::<Plane>::new(Acceleration::from_components(&[0.0, -10.0]));
+        let forces = (water(), gravity, LinearDrag::new(6.0));
+        let end = simulate(&forces, subject([0.0, 3.0], [0.0, 0.0], 3.0), 0.01, 3000, |_, _| {});
+
+        assert!((end.position.values()[1] + 0.5).abs() < 1e-6, "floats at {}", end.position.values()[1]);
+        assert!(end.velocity.magnitude().value() < 1e-6);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{Force, Real, Subject, Universe};
+use crate::units::Quantity;
+use crate::units::dimension::{Length, Mass, Quotient, Time};
+use crate::units::force::Newtons;
+use crate::units::motion::Velocity;
+
+/// Resistance in proportion to speed, as felt by small, slow bodies in a thick fluid.
+pub struct LinearDrag<B: Real> {
+    /// In kilograms per second.
+    pub coefficient: B
+}
+
+impl<B: Real> LinearDrag<B> {
+    pub fn new(coefficient: B) -> Self {
+        Self {
+            coefficient
+        }
+    }
+}
+
+impl<const D: usize, B: Real, U> Force<U> for LinearDrag<B>
+    where U: Universe<Velocity = Velocity<D, B>, Force = Newtons<D, B>>
+{
+    fn applied(&self, body: &Subject<U>, _duration: &U::Time) -> U::Force {
+        body.velocity * Quantity::<1, B, Quotient<Mass, Time>>::new(-self.coefficient)
+    }
+}
+
+/// Resistance in proportion to the square of speed, as felt by anything moving quickly
+/// through air or water.
+pub struct QuadraticDrag<B: Real> {
+    /// In kilograms per metre: half the fluid density times the drag coefficient and the
+    /// cross-sectional area.
+    pub coefficient: B
+}
+
+impl<B: Real> QuadraticDrag<B> {
+    pub fn new(coefficient: B) -> Self {
+        Self {
+            coefficient
+        }
+    }
+}
+
+impl<const D: usize, B: Real, U> Force<U> for QuadraticDrag<B>
+    where U: Universe<Velocity = Velocity<D, B>, Force = Newtons<D, B>>
+{
+    fn applied(&self, body: &Subject<U>, _duration: &U::Time) -> U::Force {
+        let resistance = body.velocity.magnitude() * Quantity::<1, B, Quotient<Mass, Length>>::new(-self.coefficient);
+        body.velocity * resistance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libm::exp;
+    use crate::testing::{simulate, subject};
+    use super::*;
+
+    #[test]
+    fn linear_drag_decays_exponentially() {
+        let (k, m, v0) = (0.8, 2.0, 5.0);
+        simulate(&LinearDrag::new(k), subject([0.0, 0.0], [v0, 0.0], m), 0.01, 300, |t, body| {
+            let expected = v0 * exp(-k * t / m);
+            assert!((body.velocity.values()[0] - expected).abs() < 1e-8, "at {t}s");
+            assert_eq!(body.velocity.values()[1], 0.0);
+        });
+    }
+
+    #[test]
+    fn quadratic_drag_decays_hyperbolically() {
+        let (c, m, v0) = (0.3, 1.5, 10.0);
+        simulate(&QuadraticDrag::new(c), subject([0.0, 0.0], [0.0, -v0], m), 0.001, 3000, |t, body| {
+            let expected = v0 / (1.0 + c * v0 * t / m);
+            assert!((body.velocity.values()[1] + expected).abs() < 1e-6, "at {t}s");
+        });
+    }
+}
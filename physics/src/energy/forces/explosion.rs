@@ -0,0 +1,85 @@
+use crate::{Force, Real, Subject, Universe};
+use crate::units::force::{Impulse, Newtons};
+use crate::units::space::Meters;
+use crate::units::time::Seconds;
+
+/// A blast at `center` that throws bodies outwards, fading linearly to nothing at `radius`.
+///
+/// Applied as a force, it delivers its whole impulse over the step it is applied in, so it
+/// should be applied for a single step.
+pub struct Explosion<const D: usize, B: Real> {
+    pub center: Meters<D, B>,
+    /// The impulse given to a body right at the centre.
+    pub impulse: Impulse<1, B>,
+    pub radius: Meters<1, B>
+}
+
+impl<const D: usize, B: Real> Explosion<D, B> {
+    pub fn new(center: Meters<D, B>, impulse: Impulse<1, B>, radius: Meters<1, B>) -> Self {
+        Self {
+            center,
+            impulse,
+            radius
+        }
+    }
+
+    /// The change in momentum of a body at `position`.
+    pub fn impulse_at(&self, position: &Meters<D, B>) -> Impulse<D, B> {
+        let offset = *position - self.center;
+        let distance = offset.magnitude();
+        if distance >= self.radius || self.radius.value() <= B::zero() {
+            return Impulse::zero();
+        }
+
+        let falloff = B::one() - distance.value() / self.radius.value();
+        offset.direction() * self.impulse.scale(falloff)
+    }
+}
+
+impl<const D: usize, B: Real, U> Force<U> for Explosion<D, B>
+    where U: Universe<Space = Meters<D, B>, Time = Seconds<B>, Force = Newtons<D, B>>
+{
+    fn applied(&self, body: &Subject<U>, duration: &U::Time) -> U::Force {
+        if duration.value() <= B::zero() {
+            return Newtons::zero();
+        }
+
+        self.impulse_at(&body.position) / *duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{subject, Plane};
+    use super::*;
+
+    fn blast() -> Explosion<2, f64> {
+        Explosion::new(Meters::from_components(&[1.0, 1.0]), Impulse::new(12.0), Meters::new(4.0))
+    }
+
+    #[test]
+    fn falls_off_linearly_to_the_radius() {
+        let blast = blast();
+        for (distance, expected) in [(0.5, 10.5), (1.0, 9.0), (2.0, 6.0), (3.0, 3.0), (4.0, 0.0), (6.0, 0.0)] {
+            let impulse = blast.impulse_at(&Meters::from_components(&[1.0, 1.0 + distance]));
+            assert!((impulse.values()[1] - expected).abs() < 1e-12, "{distance} m away");
+            assert_eq!(impulse.values()[0], 0.0);
+        }
+
+        // Always outwards from the centre
+        let impulse = blast.impulse_at(&Meters::from_components(&[0.0, 0.0]));
+        assert!(impulse.values()[0] < 0.0 && impulse.values()[1] < 0.0);
+    }
+
+    #[test]
+    fn delivers_its_impulse_over_one_step() {
+        let blast = blast();
+        let body = subject([3.0, 1.0], [0.0, 0.0], 2.0);
+
+        for dt in [0.01, 0.1] {
+            let force = Force::<Plane>::applied(&blast, &body, &Seconds::new(dt));
+            assert!((force.values()[0] * dt - 6.0).abs() < 1e-9);
+        }
+        assert_eq!(Force::<Plane>::applied(&blast, &body, &Seconds::new(0.0)).values(), [0.0, 0.0]);
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{Force, Real, Subject, Universe};
+use crate::units::force::Newtons;
+use crate::units::mass::Kilograms;
+use crate::units::space::Meters;
+
+/// Newton's gravitational constant, in m³/(kg·s²).
+pub const GRAVITATIONAL_CONSTANT: f32 = 6.674_30e-11;
+
+/// The same acceleration everywhere, such as the pull of the ground near a planet's surface.
+pub struct UniformGravity<U: Universe> {
+    pub acceleration: U::Acceleration
+}
+
+impl<U: Universe> UniformGravity<U> {
+    pub fn new(acceleration: U::Acceleration) -> Self {
+        Self {
+            acceleration
+        }
+    }
+}
+
+impl<U: Universe> Force<U> for UniformGravity<U> {
+    fn applied(&self, body: &Subject<U>, _duration: &U::Time) -> U::Force {
+        self.acceleration * body.mass
+    }
+}
+
+/// The pull of a point mass fixed in place, falling off with the square of distance.
+///
+/// Bodies passing very close to the point are flung away at enormous speed; a `softening`
+/// length caps the pull, as if the mass were spread over a ball of that radius. Nothing pulls
+/// back on the point, so for bodies that attract one another use `MutualGravity`.
+pub struct PointGravity<const D: usize, B: Real> {
+    pub position: Meters<D, B>,
+    pub mass: Kilograms<B>,
+    pub constant: B,
+    pub softening: Meters<1, B>
+}
+
+impl<const D: usize, B: Real> PointGravity<D, B> {
+    pub fn new(position: Meters<D, B>, mass: Kilograms<B>) -> Self {
+        Self {
+            position,
+            mass,
+            constant: B::from_f32(GRAVITATIONAL_CONSTANT),
+            softening: Meters::zero()
+        }
+    }
+
+    /// Replaces the gravitational constant, as games usually want far stronger gravity.
+    pub fn constant(self, constant: B) -> Self {
+        Self {
+            constant,
+            ..self
+        }
+    }
+
+    pub fn softening(self, softening: Meters<1, B>) -> Self {
+        Self {
+            softening,
+            ..self
+        }
+    }
+
+    /// The pull on a body of `mass` at `position`.
+    fn pull(&self, position: &Meters<D, B>, mass: &Kilograms<B>) -> Newtons<D, B> {
+        let offset = self.position - *position;
+        let distance_squared = offset.magnitude_squared().value() + self.softening.magnitude_squared().value();
+        if distance_squared <= B::zero() {
+            return Newtons::zero();
+        }
+
+        let strength = self.constant * self.mass.value() * mass.value() / distance_squared;
+        offset.direction() * Newtons::<1, B>::new(strength)
+    }
+}
+
+impl<const D: usize, B: Real, U> Force<U> for PointGravity<D, B>
+    where U: Universe<Space = Meters<D, B>, Mass = Kilograms<B>, Force = Newtons<D, B>>
+{
+    fn applied(&self, body: &Subject<U>, _duration: &U::Time) -> U::Force {
+        self.pull(&body.position, &body.mass)
+    }
+}
+
+/// The pull between two bodies that attract each other, such as a planet and its moon.
+///
+/// Unlike a `PointGravity` at each body, the forces are worked out once for the pair, so they
+/// are always equal and opposite and the pair's momentum is kept.
+pub struct MutualGravity<B: Real> {
+    pub constant: B,
+    pub softening: Meters<1, B>
+}
+
+impl<B: Real> MutualGravity<B> {
+    pub fn new() -> Self {
+        Self {
+            constant: B::from_f32(GRAVITATIONAL_CONSTANT),
+            softening: Meters::zero()
+        }
+    }
+
+    pub fn constant(self, constant: B) -> Self {
+        Self {
+            constant,
+            ..self
+        }
+    }
+
+    pub fn softening(self, softening: Meters<1, B>) -> Self {
+        Self {
+            softening,
+            ..self
+        }
+    }
+
+    /// The forces on `a` and on `b`, each pulled towards the other.
+    pub fn between<const D: usize, U>(&self, a: &Subject<U>, b: &Subject<U>) -> (U::Force, U::Force)
+        where U: Universe<Space = Meters<D, B>, Mass = Kilograms<B>, Force = Newtons<D, B>>
+    {
+        let on_a = PointGravity::new(b.position, b.mass)
+            .constant(self.constant)
+            .softening(self.softening)
+            .pull(&a.position, &a.mass);
+
+        (on_a, -on_a)
+    }
+}
+
+impl<B: Real> Default for MutualGravity<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{simulate, subject, Plane};
+    use crate::units::motion::Acceleration;
+    use crate::units::time::Seconds;
+    use super::*;
+
+    #[test]
+    fn uniform_gravity_falls_as_a_parabola() {
+        let gravity = UniformGravity::<Plane>::new(Acceleration::from_components(&[0.0, -9.81]));
+        let end = simulate(&gravity, subject([0.0, 0.0], [2.0, 0.0], 3.0), 0.01, 100, |_, _| {});
+
+        assert!((end.position.values()[0] - 2.0).abs() < 1e-9);
+        assert!((end.position.values()[1] + 0.5 * 9.81).abs() < 1e-9);
+        assert!((end.velocity.values()[1] + 9.81).abs() < 1e-9);
+    }
+
+    #[test]
+    fn point_gravity_falls_off_with_square_of_distance() {
+        let point = PointGravity::new(Meters::zero(), Kilograms::new(1e10));
+        let second = Seconds::new(1.0);
+
+        let near = Force::<Plane>::applied(&point, &subject([2.0, 0.0], [0.0, 0.0], 5.0), &second);
+        let far = Force::<Plane>::applied(&point, &subject([4.0, 0.0], [0.0, 0.0], 5.0), &second);
+
+        let expected = GRAVITATIONAL_CONSTANT as f64 * 1e10 * 5.0 / 4.0;
+        assert!((near.values()[0] + expected).abs() < expected * 1e-6, "pulls towards the point");
+        assert!((near.values()[0] / far.values()[0] - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn softening_caps_the_pull() {
+        let point = PointGravity::new(Meters::zero(), Kilograms::new(1.0)).constant(1.0).softening(Meters::new(1.0));
+        let second = Seconds::new(1.0);
+
+        let close = Force::<Plane>::applied(&point, &subject([0.001, 0.0], [0.0, 0.0], 1.0), &second);
+        assert!(close.magnitude().value() < 1.0);
+        assert_eq!(Force::<Plane>::applied(&point, &subject([0.0, 0.0], [0.0, 0.0], 1.0), &second).values(), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn mutual_gravity_is_equal_and_opposite() {
+        let gravity = MutualGravity::new().constant(1.0);
+        let mut bodies = [subject([-1.0, 0.0], [0.0, -0.1], 9.0), subject([9.0, 0.0], [0.0, 0.9], 1.0)];
+        let momentum = |bodies: &[crate::Subject<Plane>; 2]| bodies.iter()
+            .fold([0.0; 2], |sum, b| [0, 1].map(|i| sum[i] + b.velocity.values()[i] * b.mass.value()));
+        let start = momentum(&bodies);
+
+        let (on_a, on_b) = gravity.between::<2, Plane>(&bodies[0], &bodies[1]);
+        assert!((on_a.values()[0] - 0.09).abs() < 1e-12);
+        assert_eq!(on_b.values(), (-on_a).values());
+
+        // Orbit about the common centre of mass, which never moves
+        let dt = Seconds::new(0.01);
+        for _ in 0..10_000 {
+            let (on_a, on_b) = gravity.between::<2, Plane>(&bodies[0], &bodies[1]);
+            for (body, force) in bodies.iter_mut().zip([on_a, on_b]) {
+                body.velocity += force / body.mass * dt;
+                body.position += body.velocity * dt;
+            }
+        }
+
+        let end = momentum(&bodies);
+        assert!((0..2).all(|i| (end[i] - start[i]).abs() < 1e-9));
+        let distance = (bodies[1].position - bodies[0].position).magnitude().value();
+        assert!((distance - 10.0).abs() < 1.0, "the pair drifted apart to {distance}");
+    }
+}
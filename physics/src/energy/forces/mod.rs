@@ -0,0 +1,18 @@
+//! Common forces, for universes measured in real-valued SI quantities.
+mod gravity;
+pub use gravity::{MutualGravity, PointGravity, UniformGravity, GRAVITATIONAL_CONSTANT};
+
+mod drag;
+pub use drag::{LinearDrag, QuadraticDrag};
+
+mod spring;
+pub use spring::Spring;
+
+mod buoyancy;
+pub use buoyancy::Buoyancy;
+
+mod wind;
+pub use wind::Wind;
+
+mod explosion;
+pub use explosion::Explosion;
@@ -0,0 +1,138 @@
+use crate::{Force, Real, Subject, Universe};
+use crate::units::Quantity;
+use crate::units::dimension::{self, Quotient};
+use crate::units::force::Newtons;
+use crate::units::motion::Velocity;
+use crate::units::space::Meters;
+
+/// A damped Hooke spring from `anchor` to a body, `rest_length` long when relaxed.
+///
+/// To join two bodies, apply one spring anchored at each body's position and velocity to the
+/// other.
+pub struct Spring<const D: usize, B: Real> {
+    pub anchor: Meters<D, B>,
+    pub anchor_velocity: Velocity<D, B>,
+    pub rest_length: Meters<1, B>,
+    /// In newtons per metre.
+    pub stiffness: B,
+    /// In newton seconds per metre, resisting the ends moving apart or together.
+    pub damping: B
+}
+
+impl<const D: usize, B: Real> Spring<D, B> {
+    pub fn new(anchor: Meters<D, B>, rest_length: Meters<1, B>, stiffness: B) -> Self {
+        Self {
+            anchor,
+            anchor_velocity: Velocity::zero(),
+            rest_length,
+            stiffness,
+            damping: B::zero()
+        }
+    }
+
+    pub fn damping(self, damping: B) -> Self {
+        Self {
+            damping,
+            ..self
+        }
+    }
+
+    /// Sets how fast the anchor itself is moving, so damping only resists relative motion.
+    pub fn moving(self, anchor_velocity: Velocity<D, B>) -> Self {
+        Self {
+            anchor_velocity,
+            ..self
+        }
+    }
+}
+
+impl<const D: usize, B: Real, U> Force<U> for Spring<D, B>
+    where U: Universe<Space = Meters<D, B>, Velocity = Velocity<D, B>, Force = Newtons<D, B>>
+{
+    fn applied(&self, body: &Subject<U>, _duration: &U::Time) -> U::Force {
+        let offset = body.position - self.anchor;
+        let direction = offset.direction();
+
+        let stretch = offset.magnitude() - self.rest_length;
+        let separating = (body.velocity - self.anchor_velocity).dot(&direction);
+
+        let tension = stretch * Quantity::<1, B, Quotient<dimension::Force, dimension::Length>>::new(self.stiffness)
+            + separating * Quantity::<1, B, Quotient<dimension::Force, dimension::Velocity>>::new(self.damping);
+
+        direction * -tension
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::TAU;
+    use libm::{exp, sqrt};
+    use crate::testing::{simulate, subject};
+    use super::*;
+
+    /// The times at which the body passes through the rest length going outwards.
+    fn crossings(spring: &Spring<2, f64>, mass: f64, start: f64, dt: f64, steps: usize) -> [f64; 4] {
+        let (mut found, mut count) = ([0.0; 4], 0);
+        let mut previous = start;
+        simulate(spring, subject([start, 0.0], [0.0, 0.0], mass), dt, steps, |t, body| {
+            let x = body.position.values()[0] - 1.0;
+            if previous < 0.0 && x >= 0.0 && count < 4 {
+                // Interpolate within the step for the moment of crossing
+                found[count] = t - dt * x / (x - previous);
+                count += 1;
+            }
+            previous = x;
+        });
+
+        found
+    }
+
+    #[test]
+    fn undamped_period() {
+        let (k, m) = (40.0, 2.5);
+        let spring = Spring::new(Meters::zero(), Meters::new(1.0), k);
+        let times = crossings(&spring, m, 1.2, 0.0005, 20_000);
+
+        let period = TAU * sqrt(m / k);
+        for pair in times.windows(2) {
+            assert!((pair[1] - pair[0] - period).abs() < 1e-5, "measured {} for {period}", pair[1] - pair[0]);
+        }
+    }
+
+    #[test]
+    fn damping_envelope() {
+        let (k, m, c) = (40.0, 2.5, 1.0);
+        let spring = Spring::new(Meters::zero(), Meters::new(1.0), k).damping(c);
+
+        // Underdamped, the oscillation slows a little and its peaks fall along e^(-ct/2m)
+        let omega = sqrt(k / m - (c / (2.0 * m)) * (c / (2.0 * m)));
+        let times = crossings(&spring, m, 1.2, 0.0005, 20_000);
+        assert!((times[2] - times[1] - TAU / omega).abs() < 1e-4);
+
+        let mut previous = f64::INFINITY;
+        let mut peaks = [0.0; 2];
+        let mut count = 0;
+        simulate(&spring, subject([1.2, 0.0], [0.0, 0.0], m), 0.0005, 20_000, |t, body| {
+            let speed = body.velocity.values()[0];
+            if previous > 0.0 && speed <= 0.0 && count < 2 {
+                let expected = 0.2 * exp(-c * t / (2.0 * m));
+                peaks[count] = body.position.values()[0] - 1.0;
+                assert!((peaks[count] - expected).abs() < 2e-3, "peak {} at {t}s, expected {expected}", peaks[count]);
+                count += 1;
+            }
+            previous = speed;
+        });
+        assert_eq!(count, 2);
+        assert!((peaks[1] / peaks[0] - exp(-c * TAU / omega / (2.0 * m))).abs() < 1e-3);
+    }
+
+    #[test]
+    fn moving_anchor_only_damps_relative_motion() {
+        let spring = Spring::new(Meters::zero(), Meters::new(1.0), 10.0)
+            .damping(3.0)
+            .moving(Velocity::from_components(&[2.0, 0.0]));
+        let body = subject([1.0, 0.0], [2.0, 0.0], 1.0);
+
+        assert_eq!(Force::applied(&spring, &body, &crate::units::time::Seconds::new(0.1)).values(), [0.0, 0.0]);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{Force, Real, Subject, Universe};
+use crate::units::Quantity;
+use crate::units::dimension::{Mass, Quotient, Time};
+use crate::units::force::Newtons;
+use crate::units::motion::Velocity;
+use crate::units::space::Meters;
+
+/// Air moving at a velocity given by `field` at each point, dragging bodies along with it in
+/// proportion to how fast it moves past them.
+pub struct Wind<F, B: Real> {
+    pub field: F,
+    /// In kilograms per second.
+    pub coefficient: B
+}
+
+impl<F, B: Real> Wind<F, B> {
+    pub fn new(field: F, coefficient: B) -> Self {
+        Self {
+            field,
+            coefficient
+        }
+    }
+}
+
+impl<const D: usize, B: Real, F, U> Force<U> for Wind<F, B>
+    where U: Universe<Space = Meters<D, B>, Velocity = Velocity<D, B>, Force = Newtons<D, B>>,
+          F: Fn(&Meters<D, B>) -> Velocity<D, B>
+{
+    fn applied(&self, body: &Subject<U>, _duration: &U::Time) -> U::Force {
+        let relative = (self.field)(&body.position) - body.velocity;
+        relative * Quantity::<1, B, Quotient<Mass, Time>>::new(self.coefficient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libm::exp;
+    use crate::testing::{simulate, subject};
+    use super::*;
+
+    #[test]
+    fn bodies_catch_up_with_the_wind() {
+        let (k, m) = (0.5, 2.0);
+        let wind = Wind::new(|_: &Meters<2, f64>| Velocity::from_components(&[4.0, 0.0]), k);
+
+        simulate(&wind, subject([0.0, 0.0], [0.0, 0.0], m), 0.01, 500, |t, body| {
+            let expected = 4.0 * (1.0 - exp(-k * t / m));
+            assert!((body.velocity.values()[0] - expected).abs() < 1e-8, "at {t}s");
+        });
+    }
+}
@@ -1,3 +1,4 @@
-
 mod force;
-pub use force::Force;
\ No newline at end of file
+pub use force::{Force, Subject};
+
+pub mod forces;
@@ -1,5 +1,6 @@
-use crate::Universe;
+use crate::{Cross, Force, Subject, Universe};
 use crate::space::Area;
+use super::{BodyKind, Dynamics};
 
 /// A particular `Mass` moving through a `Space` and `Time`
 pub trait Body<U: Universe> {
@@ -7,5 +8,50 @@ pub trait Body<U: Universe> {
 
     fn boundary(&self) -> Self::Boundary;
     fn position(&self) -> U::Space;
+    fn set_position(&mut self, position: U::Space);
     fn mass(&self) -> U::Mass;
-}
\ No newline at end of file
+
+    fn dynamics(&self) -> &Dynamics<U>;
+    fn dynamics_mut(&mut self) -> &mut Dynamics<U>;
+
+    fn velocity(&self) -> U::Velocity {
+        self.dynamics().velocity
+    }
+
+    fn orientation(&self) -> U::Orientation {
+        self.dynamics().orientation
+    }
+
+    /// Gathers a force acting through `point` rather than the centre of mass, which also turns
+    /// the body.
+    fn apply_force_at(&mut self, force: U::Force, point: U::Space) {
+        let torque = (point - self.position()).cross(&force);
+        let dynamics = self.dynamics_mut();
+        dynamics.apply_force(force);
+        dynamics.apply_torque(torque);
+    }
+
+    /// The body as forces see it.
+    fn subject(&self) -> Subject<U> {
+        Subject::new(self.position(), self.velocity(), self.mass())
+    }
+
+    /// Gathers the force `force` exerts on this body over the coming `duration`.
+    fn exert<F: Force<U> + ?Sized>(&mut self, force: &F, duration: &U::Time) where Self: Sized {
+        let applied = force.applied(&self.subject(), duration);
+        self.dynamics_mut().apply_force(applied);
+    }
+
+    /// Moves and turns the body through one step of `dt` with semi-implicit Euler, using and
+    /// then clearing the forces gathered for the step.
+    fn integrate(&mut self, dt: U::Time) {
+        if self.dynamics().kind == BodyKind::Static {
+            return;
+        }
+
+        self.dynamics_mut().accelerate(dt);
+        self.dynamics_mut().turn(dt);
+        let position = self.position() + self.velocity() * dt;
+        self.set_position(position);
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{Orientation, Universe, Zero};
+
+/// How a body responds to the simulation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BodyKind {
+    /// Never moves, as if infinitely heavy. Walls and floors.
+    Static,
+    /// Moves only at the velocity it is given, pushing others without being pushed. Moving
+    /// platforms and doors.
+    Kinematic,
+    /// Moves under the forces and impulses applied to it.
+    Dynamic
+}
+
+/// The motion of a body, and the forces gathered on it during the current step.
+pub struct Dynamics<U: Universe> {
+    pub kind: BodyKind,
+    pub orientation: U::Orientation,
+    pub velocity: U::Velocity,
+    pub angular_velocity: U::AngularVelocity,
+    force: U::Force,
+    torque: U::Torque,
+    inverse_mass: U::InverseMass,
+    inverse_inertia: U::InverseInertia
+}
+
+impl<U: Universe> Dynamics<U> {
+    pub fn dynamic(inverse_mass: U::InverseMass, inverse_inertia: U::InverseInertia) -> Self {
+        Self {
+            kind: BodyKind::Dynamic,
+            orientation: U::Orientation::identity(),
+            velocity: U::Velocity::zero(),
+            angular_velocity: U::AngularVelocity::zero(),
+            force: U::Force::zero(),
+            torque: U::Torque::zero(),
+            inverse_mass,
+            inverse_inertia
+        }
+    }
+
+    pub fn kinematic(velocity: U::Velocity, angular_velocity: U::AngularVelocity) -> Self {
+        Self {
+            kind: BodyKind::Kinematic,
+            velocity,
+            angular_velocity,
+            ..Self::dynamic(U::InverseMass::zero(), U::InverseInertia::zero())
+        }
+    }
+
+    pub fn fixed() -> Self {
+        Self {
+            kind: BodyKind::Static,
+            ..Self::dynamic(U::InverseMass::zero(), U::InverseInertia::zero())
+        }
+    }
+
+    /// Zero unless the body is dynamic, so that solvers treat other bodies as immovable.
+    pub fn inverse_mass(&self) -> U::InverseMass {
+        match self.kind {
+            BodyKind::Dynamic => self.inverse_mass,
+            _ => U::InverseMass::zero()
+        }
+    }
+
+    /// In the body's own frame, and zero unless the body is dynamic.
+    pub fn inverse_inertia(&self) -> U::InverseInertia {
+        match self.kind {
+            BodyKind::Dynamic => self.inverse_inertia,
+            _ => U::InverseInertia::zero()
+        }
+    }
+
+    pub fn set_inverse_mass(&mut self, inverse_mass: U::InverseMass) {
+        self.inverse_mass = inverse_mass;
+    }
+
+    pub fn set_inverse_inertia(&mut self, inverse_inertia: U::InverseInertia) {
+        self.inverse_inertia = inverse_inertia;
+    }
+
+    /// The force gathered so far this step.
+    pub fn force(&self) -> U::Force {
+        self.force
+    }
+
+    pub fn torque(&self) -> U::Torque {
+        self.torque
+    }
+
+    /// Adds a force acting through the centre of mass. Only dynamic bodies gather forces.
+    pub fn apply_force(&mut self, force: U::Force) {
+        if self.kind == BodyKind::Dynamic {
+            self.force = self.force + force;
+        }
+    }
+
+    pub fn apply_torque(&mut self, torque: U::Torque) {
+        if self.kind == BodyKind::Dynamic {
+            self.torque = self.torque + torque;
+        }
+    }
+
+    pub fn clear_forces(&mut self) {
+        self.force = U::Force::zero();
+        self.torque = U::Torque::zero();
+    }
+
+    pub fn acceleration(&self) -> U::Acceleration {
+        self.force * self.inverse_mass()
+    }
+
+    /// The inverse inertia in the body's own frame turned to match how it faces now.
+    pub fn world_inverse_inertia(&self) -> U::InverseInertia {
+        self.orientation.world_inverse_inertia(&self.inverse_inertia())
+    }
+
+    pub fn angular_acceleration(&self) -> U::AngularAcceleration {
+        self.orientation.angular_acceleration(&self.inverse_inertia(), &self.angular_velocity, &self.torque)
+    }
+
+    /// Updates velocities from the gathered forces over `dt`, then clears them. Kinematic and
+    /// static bodies keep the velocities they were given.
+    pub fn accelerate(&mut self, dt: U::Time) {
+        if self.kind == BodyKind::Dynamic {
+            self.velocity = self.velocity + self.acceleration() * dt;
+            self.angular_velocity = self.angular_velocity + self.angular_acceleration() * dt;
+        }
+
+        self.clear_forces();
+    }
+
+    /// Turns the orientation by the angular velocity over `dt`.
+    pub fn turn(&mut self, dt: U::Time) {
+        if self.kind != BodyKind::Static {
+            self.orientation = self.orientation.turned(&self.angular_velocity, &dt);
+        }
+    }
+}
+
+impl<U: Universe> Clone for Dynamics<U> {
+    fn clone(&self) -> Self { *self }
+}
+impl<U: Universe> Copy for Dynamics<U> {}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::TAU;
+    use libm::{sin, sqrt};
+    use crate::testing::Plane;
+    use crate::units::force::{InverseMomentOfInertia, Torque};
+    use crate::units::mass::InverseKilograms;
+    use crate::units::time::Seconds;
+    use super::*;
+
+    fn body(mass: f64, inertia: f64) -> Dynamics<Plane> {
+        Dynamics::dynamic(InverseKilograms::new(1.0 / mass), InverseMomentOfInertia::new(1.0 / inertia))
+    }
+
+    #[test]
+    fn spinning_box() {
+        // A 2 m by 1 m box of 3 kg: I = m (w² + h²) / 12
+        let inertia = 3.0 * (2.0 * 2.0 + 1.0 * 1.0) / 12.0;
+        let mut spinning = body(3.0, inertia);
+        let dt = 0.001;
+
+        // A steady torque turns it by α t² / 2, where α = τ / I
+        for _ in 0..2000 {
+            spinning.apply_torque(Torque::new(0.5));
+            spinning.accelerate(Seconds::new(dt));
+            spinning.turn(Seconds::new(dt));
+        }
+        let alpha = 0.5 / inertia;
+        assert!((spinning.angular_velocity.value() - alpha * 2.0).abs() < 1e-9);
+        assert!((spinning.orientation.angle() - alpha * 2.0 * 2.0 / 2.0).abs() < 1e-3);
+        assert_eq!(spinning.torque().value(), 0.0);
+
+        // Left alone it keeps spinning at the same rate
+        let (omega, start) = (spinning.angular_velocity.value(), spinning.orientation.angle());
+        for _ in 0..1000 {
+            spinning.accelerate(Seconds::new(dt));
+            spinning.turn(Seconds::new(dt));
+        }
+        assert_eq!(spinning.angular_velocity.value(), omega);
+        assert!((spinning.orientation.angle() - start - omega).abs() < 1e-9);
+    }
+
+    #[test]
+    fn only_dynamic_bodies_spin_up() {
+        let mut fixed = Dynamics::<Plane>::fixed();
+        fixed.apply_torque(Torque::new(10.0));
+        fixed.accelerate(Seconds::new(1.0));
+        assert_eq!(fixed.angular_velocity.value(), 0.0);
+
+        let mut turntable = Dynamics::<Plane>::kinematic(Zero::zero(), crate::units::motion::AngularVelocity::new(1.0));
+        turntable.apply_torque(Torque::new(10.0));
+        turntable.accelerate(Seconds::new(0.5));
+        turntable.turn(Seconds::new(0.5));
+        assert_eq!(turntable.angular_velocity.value(), 1.0);
+        assert!((turntable.orientation.angle() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn pendulum() {
+        // A 1 kg bob on a light 2 m rod, swinging about the pivot under its own weight
+        let (mass, length, g) = (1.0, 2.0, 9.81);
+        let mut bob = body(mass, mass * length * length);
+        bob.orientation = Orientation::<Plane>::turned(
+            &bob.orientation, &crate::units::motion::AngularVelocity::new(0.05), &Seconds::new(1.0)
+        );
+
+        let dt = 0.0005;
+        let (mut previous, mut crossings, mut widest) = (bob.orientation.angle(), [0.0; 3], 0.0f64);
+        let mut count = 0;
+        for step in 1..=20_000 {
+            bob.apply_torque(Torque::new(-mass * g * length * sin(bob.orientation.angle())));
+            bob.accelerate(Seconds::new(dt));
+            bob.turn(Seconds::new(dt));
+
+            let angle = bob.orientation.angle();
+            widest = widest.max(angle.abs());
+            if previous > 0.0 && angle <= 0.0 && count < 3 {
+                crossings[count] = step as f64 * dt - dt * angle / (angle - previous);
+                count += 1;
+            }
+            previous = angle;
+        }
+
+        // Small swings keep to 2π √(L/g), and the swing neither grows nor dies away
+        let period = TAU * sqrt(length / g);
+        assert_eq!(count, 3);
+        for pair in crossings.windows(2) {
+            assert!((pair[1] - pair[0] - period).abs() < period * 1e-3, "measured {} for {period}", pair[1] - pair[0]);
+        }
+        assert!((widest - 0.05).abs() < 0.05 * 1e-2);
+    }
+}
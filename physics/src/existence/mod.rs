@@ -1,2 +1,5 @@
 mod body;
-pub use body::Body;
\ No newline at end of file
+pub use body::Body;
+
+mod dynamics;
+pub use dynamics::{BodyKind, Dynamics};
@@ -1,6 +1,8 @@
-use super::{Comparable, Mobile};
+use super::{Additive, Mobile};
 
 /// `Mass` is the fundamental dimension of Matter.
 /// 
 /// The quantity of Matter that is concentrated in a given `Space` is measured by units of `Mass`.
-pub trait Mass: Mobile + Comparable { }
\ No newline at end of file
+///
+/// Masses only need a partial order, so they can be measured in floats.
+pub trait Mass: Mobile + Additive + PartialOrd { }
\ No newline at end of file
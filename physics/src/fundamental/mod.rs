@@ -1,5 +1,11 @@
 mod properties;
-pub use properties::{Combineable, Comparable, Mobile};
+pub use properties::{Additive, Combineable, Comparable, Cross, Magnitude, Mobile, Scale};
+
+mod orientation;
+pub use orientation::Orientation;
+
+mod real;
+pub use real::Real;
 
 pub mod space;
 pub use space::Space;
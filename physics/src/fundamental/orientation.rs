@@ -0,0 +1,17 @@
+use super::{Mobile, Universe};
+
+/// Which way a body faces.
+pub trait Orientation<U: Universe + ?Sized>: Mobile {
+    fn identity() -> Self;
+
+    /// This orientation after turning at `angular_velocity` for `dt`. Implementations
+    /// renormalise, so that rounding never builds up into a skew or a scale.
+    fn turned(&self, angular_velocity: &U::AngularVelocity, dt: &U::Time) -> Self;
+
+    /// An inverse inertia given in the body's own frame, as seen from the world.
+    fn world_inverse_inertia(&self, inverse_inertia: &U::InverseInertia) -> U::InverseInertia;
+
+    /// How fast `torque` changes the angular velocity of a body facing this way, whose inverse
+    /// inertia in its own frame is `inverse_inertia`.
+    fn angular_acceleration(&self, inverse_inertia: &U::InverseInertia, angular_velocity: &U::AngularVelocity, torque: &U::Torque) -> U::AngularAcceleration;
+}
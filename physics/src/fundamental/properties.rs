@@ -1,12 +1,36 @@
 use core::marker::Copy;
-use core::cmp::{Eq, Ord};
+use core::cmp::{Eq, Ord, Ordering};
 use core::ops::{Add, Sub, Div, Mul};
 
 pub trait Comparable: Eq + Ord {}
 impl<T: Eq + Ord> Comparable for T {}
 
+/// Compares how large two values are, such as the lengths of two displacements.
+///
+/// This is kept apart from `PartialEq` and `PartialOrd` because different vectors can have the
+/// same magnitude.
+pub trait Magnitude {
+    /// `None` when either magnitude is undefined, such as a `NaN` component.
+    fn magnitude_cmp(&self, other: &Self) -> Option<Ordering>;
+}
+
+/// Multiplies by a plain number of type `F`, such as halving a time step.
+pub trait Scale<F> {
+    fn scale(&self, factor: F) -> Self;
+}
+
+/// The cross product, such as the torque of a force applied away from the centre.
+pub trait Cross<Rhs> {
+    type Output;
+    fn cross(&self, other: &Rhs) -> Self::Output;
+}
+
 pub trait Mobile: Copy + Sized {}
 impl<T: Copy + Sized> Mobile for T {}
 
+/// Adds and subtracts, starting from zero.
+pub trait Additive: Mobile + linalg::Zero + Add<Output = Self> + Sub<Output = Self> {}
+impl<T: Mobile + linalg::Zero + Add<Output = Self> + Sub<Output = Self>> Additive for T {}
+
 pub trait Combineable: Mobile + Add<Output = Self> + Sub<Output = Self> + Div<Output = Self> + Mul<Output = Self> {}
 impl<T: Mobile + Add<Output = Self> + Sub<Output = Self> + Div<Output = Self> + Mul<Output = Self>> Combineable for T {}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use core::ops::Neg;
+use super::space::BaseUnit;
+
+/// A floating-point base unit, for the parts of physics that need fractions, roots and angles.
+pub trait Real: BaseUnit + Neg<Output = Self> {
+    fn from_f32(v: f32) -> Self;
+    fn to_f32(self) -> f32;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn floor(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn atan2(self, x: Self) -> Self;
+    fn is_finite(self) -> bool;
+
+    fn one() -> Self {
+        Self::from_f32(1.0)
+    }
+
+    /// Half a turn, in radians.
+    fn pi() -> Self {
+        Self::zero().atan2(-Self::one())
+    }
+
+    /// The smaller of the two, preferring `self` when they can't be compared.
+    fn min(self, other: Self) -> Self {
+        if other < self { other } else { self }
+    }
+
+    /// The larger of the two, preferring `self` when they can't be compared.
+    fn max(self, other: Self) -> Self {
+        if other > self { other } else { self }
+    }
+}
+
+impl Real for f32 {
+    fn from_f32(v: f32) -> Self { v }
+    fn to_f32(self) -> f32 { self }
+
+    fn abs(self) -> Self { libm::fabsf(self) }
+    fn sqrt(self) -> Self { libm::sqrtf(self) }
+    fn floor(self) -> Self { libm::floorf(self) }
+    fn sin(self) -> Self { libm::sinf(self) }
+    fn cos(self) -> Self { libm::cosf(self) }
+    fn atan2(self, x: Self) -> Self { libm::atan2f(self, x) }
+    fn is_finite(self) -> bool { f32::is_finite(self) }
+}
+
+impl Real for f64 {
+    fn from_f32(v: f32) -> Self { v as f64 }
+    fn to_f32(self) -> f32 { self as f32 }
+
+    fn abs(self) -> Self { libm::fabs(self) }
+    fn sqrt(self) -> Self { libm::sqrt(self) }
+    fn floor(self) -> Self { libm::floor(self) }
+    fn sin(self) -> Self { libm::sin(self) }
+    fn cos(self) -> Self { libm::cos(self) }
+    fn atan2(self, x: Self) -> Self { libm::atan2(self, x) }
+    fn is_finite(self) -> bool { f64::is_finite(self) }
+}
@@ -1,9 +1,14 @@
-use super::{Combineable, Comparable, Mobile};
+use super::{Additive, Combineable, Magnitude, Mobile};
 
-pub trait BaseUnit: Combineable + Comparable + Mobile + linalg::Mobile {}
-impl<T: Combineable + Comparable + Mobile + linalg::Mobile> BaseUnit for T {}
+/// The number a quantity is counted in. Only a partial order is required, so floats qualify.
+pub trait BaseUnit: Combineable + PartialOrd + Mobile + linalg::Mobile {}
+impl<T: Combineable + PartialOrd + Mobile + linalg::Mobile> BaseUnit for T {}
 
-pub trait Space: Mobile + Comparable + Combineable {
+/// A position or displacement. Positions add and subtract, but multiplying two of them gives
+/// a quantity of a different dimension, so that is left to the concrete units.
+///
+/// Positions have no order of their own; compare their sizes with `Magnitude`.
+pub trait Space: Mobile + PartialEq + Additive + Magnitude {
     const DIMENSIONS: usize;
     type Base: BaseUnit;
 }
@@ -14,7 +19,17 @@ pub trait ObservableSpace<const D: usize>: Space {
 }
 
 pub trait Area<S: Space> {
-    fn amount_of_space(&self) -> S;
+    /// The measure of the area, such as `SquareMeters` for a region of `Meters<2, _>`.
+    type Amount;
+
+    fn amount_of_space(&self) -> Self::Amount;
+}
+
+/// An area that can say how hard it is to spin about its centre, when filled evenly with `M`.
+pub trait Inertia<S: Space, M>: Area<S> {
+    type Moment;
+
+    fn moment_of_inertia(&self, mass: M) -> Self::Moment;
 }
 
 pub trait AreaIntersection<S: Space, A: Area<S>>: Area<S> {
@@ -1,3 +1,7 @@
 use super::Space;
 
-pub trait Time: Space {}
\ No newline at end of file
+/// Moments in time are ordered, though only partially so that they can be floats.
+pub trait Time: Space + PartialOrd {
+    /// The length of this time in seconds, as a plain number.
+    fn seconds(&self) -> Self::Base;
+}
\ No newline at end of file
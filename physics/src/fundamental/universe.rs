@@ -1,9 +1,40 @@
-use super::{Space, Time, Mass};
+use core::ops::{Div, Mul};
+use crate::units::conversion::UnitSystem;
+use super::{Additive, Cross, Mobile, Orientation, Scale, Space, Time, Mass};
+use super::space::BaseUnit;
 
+/// The units that bodies live in, and the quantities derived from them.
+///
+/// The derived quantities are tied together by their operators, so that code generic over a
+/// `Universe` can still move from a force to an acceleration, a velocity and a position.
 pub trait Universe {
-    type Space: Space;
-    type Time: Time;
-    type Mass: Mass;
+    /// The plain number that every quantity in this universe is counted in.
+    type Scalar: BaseUnit;
+
+    type Space: Space<Base = Self::Scalar> + Scale<Self::Scalar> + Div<Self::Time, Output = Self::Velocity>
+        + Cross<Self::Force, Output = Self::Torque>;
+    type Time: Time<Base = Self::Scalar> + Scale<Self::Scalar>;
+    type Mass: Mass + Scale<Self::Scalar>;
+
+    type Velocity: Additive + Scale<Self::Scalar> + Mul<Self::Time, Output = Self::Space> + Div<Self::Time, Output = Self::Acceleration>;
+    type Acceleration: Additive + Scale<Self::Scalar> + Mul<Self::Time, Output = Self::Velocity> + Mul<Self::Mass, Output = Self::Force>;
+    type Force: Additive + Scale<Self::Scalar> + Div<Self::Mass, Output = Self::Acceleration> + Mul<Self::InverseMass, Output = Self::Acceleration>;
+
+    /// One over a mass, which is zero for bodies that nothing can push.
+    type InverseMass: Mobile + linalg::Zero;
+
+    /// Which way a body faces, turned by its angular velocity.
+    type Orientation: Orientation<Self>;
+    type AngularVelocity: Additive + Scale<Self::Scalar>;
+    type AngularAcceleration: Additive + Scale<Self::Scalar> + Mul<Self::Time, Output = Self::AngularVelocity>;
+    type Torque: Additive + Scale<Self::Scalar>;
+
+    /// One over the moment of inertia: a plain quantity in 2D, a tensor in 3D.
+    type InverseInertia: Mobile + linalg::Zero + Mul<Self::Torque, Output = Self::AngularAcceleration>;
+
+    /// The units this universe's quantities are read from and written to outside the simulation,
+    /// through `Quantity::parse_in`, `Quantity::display_in` and `Quantity::from_system`.
+    type Units: UnitSystem;
 
     fn time(&self) -> &Self::Time;
-}
\ No newline at end of file
+}
@@ -0,0 +1,32 @@
+use crate::Universe;
+use super::{Integrator, State};
+
+/// Moves with the velocity from the start of the step, then updates it. First order, and it
+/// adds energy to oscillating systems every step.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ExplicitEuler;
+
+impl<U: Universe> Integrator<U> for ExplicitEuler {
+    fn integrate<A>(&self, state: &State<U>, time: U::Time, dt: U::Time, acceleration: A) -> State<U>
+        where A: Fn(&State<U>, U::Time) -> U::Acceleration
+    {
+        let a = acceleration(state, time);
+
+        State::new(state.position + state.velocity * dt, state.velocity + a * dt)
+    }
+}
+
+/// Updates velocity first, then moves with the new velocity. Still first order, but symplectic,
+/// so the energy of an orbit wobbles around its true value instead of drifting away.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SemiImplicitEuler;
+
+impl<U: Universe> Integrator<U> for SemiImplicitEuler {
+    fn integrate<A>(&self, state: &State<U>, time: U::Time, dt: U::Time, acceleration: A) -> State<U>
+        where A: Fn(&State<U>, U::Time) -> U::Acceleration
+    {
+        let velocity = state.velocity + acceleration(state, time) * dt;
+
+        State::new(state.position + velocity * dt, velocity)
+    }
+}
@@ -0,0 +1,123 @@
+//! Numerical integrators that carry a position and velocity forward through time.
+//!
+//! Each integrator samples an acceleration function some number of times per step. The cheap
+//! ones drift: explicit Euler gains energy on every orbit, while the symplectic ones keep it
+//! bounded for the same cost.
+use crate::Universe;
+
+mod euler;
+pub use euler::{ExplicitEuler, SemiImplicitEuler};
+
+mod verlet;
+pub use verlet::VelocityVerlet;
+
+mod runge_kutta;
+pub use runge_kutta::RungeKutta4;
+
+/// Where something is and how fast it is moving.
+pub struct State<U: Universe> {
+    pub position: U::Space,
+    pub velocity: U::Velocity
+}
+
+impl<U: Universe> State<U> {
+    pub fn new(position: U::Space, velocity: U::Velocity) -> Self {
+        Self {
+            position,
+            velocity
+        }
+    }
+}
+
+impl<U: Universe> Clone for State<U> {
+    fn clone(&self) -> Self { *self }
+}
+impl<U: Universe> Copy for State<U> {}
+
+pub trait Integrator<U: Universe> {
+    /// Advances `state` from `time` to `time + dt`, given the acceleration at any state and time.
+    fn integrate<A>(&self, state: &State<U>, time: U::Time, dt: U::Time, acceleration: A) -> State<U>
+        where A: Fn(&State<U>, U::Time) -> U::Acceleration;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::TAU;
+    use crate::testing::Plane;
+    use crate::units::motion::{Acceleration, Velocity};
+    use crate::units::space::Meters;
+    use crate::units::time::Seconds;
+    use super::*;
+
+    type Accelerate = fn(&State<Plane>) -> Acceleration<2, f64>;
+    type Energy = fn(&State<Plane>) -> f64;
+
+    /// A unit mass on a unit spring, and a unit mass on a circular orbit of unit radius around a
+    /// unit gravitational parameter. Both have a period of 2π.
+    const SYSTEMS: [(&str, Accelerate, Energy); 2] = [
+        ("oscillator", |s| Acceleration::from_components(&s.position.values().map(|x| -x)), |s| {
+            (s.position.magnitude_squared().value() + s.velocity.magnitude_squared().value()) * 0.5
+        }),
+        ("orbit", |s| {
+            let r = s.position.magnitude().value();
+            Acceleration::from_components(&s.position.values().map(|x| -x / (r * r * r)))
+        }, |s| s.velocity.magnitude_squared().value() * 0.5 - 1.0 / s.position.magnitude().value())
+    ];
+
+    /// The largest relative energy error over each of `periods` periods, stepping at `dt`.
+    fn drift<I: Integrator<Plane>>(integrator: &I, accelerate: Accelerate, energy: Energy, periods: usize, dt: f64) -> [f64; 2] {
+        let mut state = State::new(Meters::from_components(&[1.0, 0.0]), Velocity::from_components(&[0.0, 1.0]));
+        let start = energy(&state);
+        let steps = (TAU / dt).round() as usize;
+
+        let mut worst = [0.0f64; 2];
+        let mut time = Seconds::new(0.0);
+        for period in 0..periods {
+            for _ in 0..steps {
+                state = integrator.integrate(&state, time, Seconds::new(dt), |s, _| accelerate(s));
+                time += Seconds::new(dt);
+
+                let error = ((energy(&state) - start) / start).abs();
+                let slot = if period == 0 { 0 } else { 1 };
+                if period == 0 || period == periods - 1 {
+                    worst[slot] = worst[slot].max(error);
+                }
+            }
+        }
+
+        worst
+    }
+
+    #[test]
+    fn energy_drift_ordering() {
+        for (name, accelerate, energy) in SYSTEMS {
+            let [_, euler] = drift(&ExplicitEuler, accelerate, energy, 10, 0.01);
+            let [semi_first, semi] = drift(&SemiImplicitEuler, accelerate, energy, 10, 0.01);
+            let [verlet_first, verlet] = drift(&VelocityVerlet, accelerate, energy, 10, 0.01);
+            let [rk4_first, rk4] = drift(&RungeKutta4, accelerate, energy, 10, 0.01);
+
+            assert!(euler > 0.1, "{name}: explicit Euler only drifted by {euler}");
+            assert!(semi < 1e-3 && verlet < 1e-8 && rk4 < 1e-8, "{name}: {semi}, {verlet}, {rk4}");
+            assert!(euler > semi && semi > verlet, "{name}: drift should fall with order");
+
+            // The symplectic methods wobble about the true energy without moving away from it,
+            // while RK4 steadily leaks a little every period
+            assert!(semi < semi_first * 1.01, "{name}: semi-implicit Euler drifted");
+            assert!(verlet < verlet_first * 1.01, "{name}: Verlet drifted");
+            assert!(rk4 > rk4_first * 5.0, "{name}: RK4 should lose energy steadily");
+        }
+    }
+
+    #[test]
+    fn drift_shrinks_with_order() {
+        let (_, accelerate, energy) = SYSTEMS[0];
+        let ratio = |integrator: &dyn Fn(f64) -> f64| integrator(0.02) / integrator(0.01);
+
+        // Halving the step cuts semi-implicit Euler's energy wobble about fourfold, and RK4's error
+        // by well over its order's sixteen
+        let semi = ratio(&|dt| drift(&SemiImplicitEuler, accelerate, energy, 1, dt)[0]);
+        let rk4 = ratio(&|dt| drift(&RungeKutta4, accelerate, energy, 1, dt)[0]);
+        assert!((3.0..5.0).contains(&semi), "semi-implicit Euler {semi}");
+        assert!(rk4 > 16.0, "RK4 {rk4}");
+    }
+}
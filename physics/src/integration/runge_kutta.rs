@@ -0,0 +1,37 @@
+use crate::{Real, Scale, Universe};
+use super::{Integrator, State};
+
+/// The classic fourth-order Runge-Kutta method, with four acceleration samples per step.
+///
+/// Very accurate over short spans, but not symplectic, so orbits slowly lose energy.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RungeKutta4;
+
+impl<U: Universe> Integrator<U> for RungeKutta4
+    where U::Scalar: Real
+{
+    fn integrate<A>(&self, state: &State<U>, time: U::Time, dt: U::Time, acceleration: A) -> State<U>
+        where A: Fn(&State<U>, U::Time) -> U::Acceleration
+    {
+        let two = U::Scalar::from_f32(2.0);
+        let half_dt = dt.scale(U::Scalar::from_f32(0.5));
+        let sixth_dt = dt.scale(U::Scalar::one() / U::Scalar::from_f32(6.0));
+
+        let along = |velocity: U::Velocity, a: U::Acceleration, step: U::Time| {
+            State::<U>::new(state.position + velocity * step, state.velocity + a * step)
+        };
+
+        let a1 = acceleration(state, time);
+        let s2 = along(state.velocity, a1, half_dt);
+        let a2 = acceleration(&s2, time + half_dt);
+        let s3 = along(s2.velocity, a2, half_dt);
+        let a3 = acceleration(&s3, time + half_dt);
+        let s4 = along(s3.velocity, a3, dt);
+        let a4 = acceleration(&s4, time + dt);
+
+        let velocity = state.velocity + s2.velocity.scale(two) + s3.velocity.scale(two) + s4.velocity;
+        let accel = a1 + a2.scale(two) + a3.scale(two) + a4;
+
+        State::new(state.position + velocity * sixth_dt, state.velocity + accel * sixth_dt)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{Real, Scale, Universe};
+use super::{Integrator, State};
+
+/// Second order and symplectic, with two acceleration samples per step.
+///
+/// The acceleration at the end of the step is sampled with a velocity predicted by Euler, so
+/// velocity-dependent forces such as drag lose the symplectic property.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct VelocityVerlet;
+
+impl<U: Universe> Integrator<U> for VelocityVerlet
+    where U::Scalar: Real
+{
+    fn integrate<A>(&self, state: &State<U>, time: U::Time, dt: U::Time, acceleration: A) -> State<U>
+        where A: Fn(&State<U>, U::Time) -> U::Acceleration
+    {
+        let half = U::Scalar::from_f32(0.5);
+
+        let start = acceleration(state, time);
+        let position = state.position + (state.velocity + start * dt.scale(half)) * dt;
+        let predicted = State::new(position, state.velocity + start * dt);
+        let end = acceleration(&predicted, time + dt);
+
+        State::new(position, state.velocity + (start + end).scale(half) * dt)
+    }
+}
@@ -1,14 +1,25 @@
 #![no_std]
 
 mod existence;
-pub use existence::Body;
+pub use existence::{Body, BodyKind, Dynamics};
 
 mod energy;
-pub use energy::Force;
+pub use energy::{forces, Force, Subject};
 
 mod fundamental;
 pub use fundamental::*;
 
 pub mod units;
 
+pub mod rotation;
+
+pub mod collision;
+
+pub mod integration;
+
+pub mod stepping;
+
+#[cfg(test)]
+mod testing;
+
 pub use linalg::Zero;
\ No newline at end of file
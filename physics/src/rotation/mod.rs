@@ -0,0 +1,6 @@
+//! Orientations of rigid bodies.
+mod rotation2;
+pub use rotation2::Rotation2;
+
+mod rotation3;
+pub use rotation3::Rotation3;
@@ -0,0 +1,146 @@
+use core::ops::Mul;
+use crate::{Orientation, Real, Universe};
+use crate::units::Quantity;
+use crate::units::force::{InverseMomentOfInertia, Torque};
+use crate::units::motion::{AngularAcceleration, AngularVelocity};
+use crate::units::time::Seconds;
+
+/// A rotation in the plane, stored as the cosine and sine of its angle so that composing and
+/// applying it needs no trigonometry.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rotation2<B: Real> {
+    pub cos: B,
+    pub sin: B
+}
+
+impl<B: Real> Rotation2<B> {
+    pub fn identity() -> Self {
+        Self {
+            cos: B::one(),
+            sin: B::zero()
+        }
+    }
+
+    /// Counter-clockwise by `radians`.
+    pub fn from_angle(radians: B) -> Self {
+        Self {
+            cos: radians.cos(),
+            sin: radians.sin()
+        }
+    }
+
+    /// The angle in radians, between minus and plus pi.
+    pub fn angle(&self) -> B {
+        self.sin.atan2(self.cos)
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self {
+            cos: self.cos,
+            sin: -self.sin
+        }
+    }
+
+    /// Scales back to unit length, or to the identity if there is no length left.
+    pub fn normalize(&self) -> Self {
+        let length = (self.cos * self.cos + self.sin * self.sin).sqrt();
+        if !length.is_finite() || length <= B::zero() {
+            return Self::identity();
+        }
+
+        Self {
+            cos: self.cos / length,
+            sin: self.sin / length
+        }
+    }
+
+    pub fn rotate<U>(&self, v: &Quantity<2, B, U>) -> Quantity<2, B, U> {
+        let [x, y] = v.values();
+        Quantity::from_components(&[self.cos * x - self.sin * y, self.sin * x + self.cos * y])
+    }
+}
+
+impl<B: Real> Mul for Rotation2<B> {
+    type Output = Self;
+
+    /// Rotates by `other`, then by `self`.
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            cos: self.cos * other.cos - self.sin * other.sin,
+            sin: self.sin * other.cos + self.cos * other.sin
+        }
+    }
+}
+
+impl<B: Real, U> Orientation<U> for Rotation2<B>
+    where U: Universe<
+        AngularVelocity = AngularVelocity<1, B>,
+        AngularAcceleration = AngularAcceleration<1, B>,
+        Torque = Torque<1, B>,
+        InverseInertia = InverseMomentOfInertia<B>,
+        Time = Seconds<B>
+    > + ?Sized
+{
+    fn identity() -> Self {
+        Rotation2::identity()
+    }
+
+    fn turned(&self, angular_velocity: &U::AngularVelocity, dt: &U::Time) -> Self {
+        (Self::from_angle(angular_velocity.value() * dt.value()) * *self).normalize()
+    }
+
+    /// Inertia about the out-of-plane axis is the same whichever way the body faces.
+    fn world_inverse_inertia(&self, inverse_inertia: &U::InverseInertia) -> U::InverseInertia {
+        *inverse_inertia
+    }
+
+    fn angular_acceleration(&self, inverse_inertia: &U::InverseInertia, _angular_velocity: &U::AngularVelocity, torque: &U::Torque) -> U::AngularAcceleration {
+        *inverse_inertia * *torque
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{FRAC_PI_2, PI};
+    use crate::testing::Plane;
+    use crate::units::motion::AngularVelocity;
+    use crate::units::space::Meters;
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-12
+    }
+
+    #[test]
+    fn angles_add_when_composed() {
+        let turned = Rotation2::from_angle(0.4) * Rotation2::from_angle(1.1);
+        assert!(close(turned.angle(), 1.5));
+        assert!(close((turned * turned.inverse()).angle(), 0.0));
+
+        // Past a half turn the angle comes back round from the negative side
+        assert!(close((Rotation2::from_angle(2.0) * Rotation2::from_angle(2.0)).angle(), 4.0 - 2.0 * PI));
+    }
+
+    #[test]
+    fn rotates_counter_clockwise() {
+        let [x, y] = Rotation2::from_angle(FRAC_PI_2).rotate(&Meters::from_components(&[2.0, 1.0])).values();
+        assert!(close(x, -1.0) && close(y, 2.0));
+    }
+
+    #[test]
+    fn turning_follows_angular_velocity() {
+        let mut rotation = Rotation2::identity();
+        for _ in 0..100 {
+            rotation = Orientation::<Plane>::turned(&rotation, &AngularVelocity::new(3.0), &Seconds::new(0.01));
+        }
+        assert!(close(rotation.angle(), 3.0));
+        assert!(close(rotation.cos * rotation.cos + rotation.sin * rotation.sin, 1.0));
+    }
+
+    #[test]
+    fn normalize_recovers_unit_length() {
+        let drifted = Rotation2 { cos: 0.0, sin: 2.0 }.normalize();
+        assert!(close(drifted.angle(), FRAC_PI_2) && close(drifted.sin, 1.0));
+        assert_eq!(Rotation2 { cos: 0.0, sin: 0.0 }.normalize(), Rotation2::identity());
+    }
+}
@@ -0,0 +1,190 @@
+use core::ops::Mul;
+use crate::{Orientation, Real, Universe};
+use crate::units::Quantity;
+use crate::units::force::{InverseInertiaTensor, Torque};
+use crate::units::motion::{AngularAcceleration, AngularVelocity};
+use crate::units::time::Seconds;
+
+/// A rotation in space, stored as a unit quaternion.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rotation3<B: Real> {
+    pub x: B,
+    pub y: B,
+    pub z: B,
+    pub w: B
+}
+
+impl<B: Real> Rotation3<B> {
+    pub fn identity() -> Self {
+        Self {
+            x: B::zero(),
+            y: B::zero(),
+            z: B::zero(),
+            w: B::one()
+        }
+    }
+
+    /// Counter-clockwise by `radians` about `axis`, which needn't be normalised. A zero axis
+    /// gives the identity.
+    pub fn from_axis_angle(axis: [B; 3], radians: B) -> Self {
+        let length = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+        if length <= B::zero() {
+            return Self::identity();
+        }
+
+        let half = radians * B::from_f32(0.5);
+        let s = half.sin() / length;
+
+        Self {
+            x: axis[0] * s,
+            y: axis[1] * s,
+            z: axis[2] * s,
+            w: half.cos()
+        }
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w
+        }
+    }
+
+    /// Scales back to unit length, or to the identity if there is no length left.
+    pub fn normalize(&self) -> Self {
+        let length = (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt();
+        if !length.is_finite() || length <= B::zero() {
+            return Self::identity();
+        }
+
+        Self {
+            x: self.x / length,
+            y: self.y / length,
+            z: self.z / length,
+            w: self.w / length
+        }
+    }
+
+    /// The rotation as a row-major matrix.
+    pub fn matrix(&self) -> [[B; 3]; 3] {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        let (one, two) = (B::one(), B::from_f32(2.0));
+
+        [
+            [one - two * (y * y + z * z), two * (x * y - z * w), two * (x * z + y * w)],
+            [two * (x * y + z * w), one - two * (x * x + z * z), two * (y * z - x * w)],
+            [two * (x * z - y * w), two * (y * z + x * w), one - two * (x * x + y * y)]
+        ]
+    }
+
+    pub fn rotate<U>(&self, v: &Quantity<3, B, U>) -> Quantity<3, B, U> {
+        let m = self.matrix();
+        let v = v.values();
+        Quantity::from_components(&m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2]))
+    }
+}
+
+impl<B: Real> Mul for Rotation3<B> {
+    type Output = Self;
+
+    /// Rotates by `other`, then by `self`.
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z
+        }
+    }
+}
+
+impl<B: Real, U> Orientation<U> for Rotation3<B>
+    where U: Universe<
+        AngularVelocity = AngularVelocity<3, B>,
+        AngularAcceleration = AngularAcceleration<3, B>,
+        Torque = Torque<3, B>,
+        InverseInertia = InverseInertiaTensor<B>,
+        Time = Seconds<B>
+    > + ?Sized
+{
+    fn identity() -> Self {
+        Rotation3::identity()
+    }
+
+    /// Turns about the angular velocity's axis by its whole angle, which is exact while the
+    /// angular velocity holds steady through the step.
+    fn turned(&self, angular_velocity: &U::AngularVelocity, dt: &U::Time) -> Self {
+        let angle = angular_velocity.magnitude().value() * dt.value();
+        (Self::from_axis_angle(angular_velocity.values(), angle) * *self).normalize()
+    }
+
+    fn world_inverse_inertia(&self, inverse_inertia: &U::InverseInertia) -> U::InverseInertia {
+        inverse_inertia.rotated(self)
+    }
+
+    /// Euler's equation, `I dω/dt = τ - ω × (I ω)`, in world space. The gyroscopic term is
+    /// what makes a spinning top precess and a tumbling box wobble about its middle axis.
+    fn angular_acceleration(&self, inverse_inertia: &U::InverseInertia, angular_velocity: &U::AngularVelocity, torque: &U::Torque) -> U::AngularAcceleration {
+        let world_inverse = inverse_inertia.rotated(self);
+        let world_inertia = inverse_inertia.inverse().rotated(self);
+
+        let momentum = world_inertia * *angular_velocity;
+        let gyroscopic: Torque<3, B> = angular_velocity.cross(&momentum);
+
+        world_inverse * (*torque - gyroscopic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::FRAC_PI_2;
+    use crate::units::space::Meters;
+    use super::*;
+
+    fn at(x: f64, y: f64, z: f64) -> Meters<3, f64> {
+        Meters::from_components(&[x, y, z])
+    }
+
+    fn close(a: Meters<3, f64>, b: Meters<3, f64>) -> bool {
+        (a - b).magnitude().value() < 1e-12
+    }
+
+    #[test]
+    fn rotates_counter_clockwise_about_the_axis() {
+        let quarter = Rotation3::from_axis_angle([0.0, 0.0, 2.0], FRAC_PI_2);
+        assert!(close(quarter.rotate(&at(1.0, 0.0, 0.0)), at(0.0, 1.0, 0.0)));
+        assert!(close(quarter.rotate(&at(0.0, 0.0, 1.0)), at(0.0, 0.0, 1.0)));
+
+        let diagonal = Rotation3::from_axis_angle([1.0, 1.0, 1.0], 2.0 * core::f64::consts::FRAC_PI_3);
+        assert!(close(diagonal.rotate(&at(1.0, 0.0, 0.0)), at(0.0, 1.0, 0.0)));
+        assert_eq!(Rotation3::from_axis_angle([0.0; 3], 1.0), Rotation3::identity());
+    }
+
+    #[test]
+    fn composing_applies_the_right_rotation_first() {
+        let about_z = Rotation3::from_axis_angle([0.0, 0.0, 1.0], FRAC_PI_2);
+        let about_x = Rotation3::from_axis_angle([1.0, 0.0, 0.0], FRAC_PI_2);
+        let y = at(0.0, 1.0, 0.0);
+
+        assert!(close((about_z * about_x).rotate(&y), about_z.rotate(&about_x.rotate(&y))));
+        assert!(close((about_z * about_x).rotate(&y), at(0.0, 0.0, 1.0)));
+        assert!(close((about_x * about_z).rotate(&y), at(-1.0, 0.0, 0.0)));
+        assert!(close((about_z * about_z).rotate(&y), at(0.0, -1.0, 0.0)));
+
+        let turned = about_z * about_x;
+        assert!(close((turned.inverse() * turned).rotate(&y), y));
+    }
+
+    #[test]
+    fn normalize_recovers_unit_length() {
+        let rotation = Rotation3::from_axis_angle([1.0, -2.0, 0.5], 0.7);
+        let stretched = Rotation3 { x: rotation.x * 3.0, y: rotation.y * 3.0, z: rotation.z * 3.0, w: rotation.w * 3.0 };
+        let normalized = stretched.normalize();
+        assert!([normalized.x - rotation.x, normalized.y - rotation.y, normalized.z - rotation.z, normalized.w - rotation.w].iter().all(|d| d.abs() < 1e-12));
+
+        assert_eq!(Rotation3 { x: 0.0, y: 0.0, z: 0.0, w: 0.0 }.normalize(), Rotation3::identity());
+        assert_eq!(Rotation3 { x: f64::NAN, ..rotation }.normalize(), Rotation3::identity());
+    }
+}
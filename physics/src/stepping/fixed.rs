@@ -0,0 +1,139 @@
+use crate::{Scale, Universe, Zero};
+use super::{fraction, Simulation, StepHooks};
+
+/// Runs a universe in steps of one fixed length, however long each frame takes.
+pub struct FixedTimestep<U: Universe> {
+    step: U::Time,
+    accumulator: U::Time,
+    max_steps: usize
+}
+
+impl<U: Universe> FixedTimestep<U> {
+    /// `None` unless `step` is longer than zero, as no number of empty steps would ever catch
+    /// up with a frame.
+    pub fn new(step: U::Time) -> Option<Self> {
+        let zero = step.scale(U::Scalar::zero());
+        if step > zero {
+            Some(Self {
+                step,
+                accumulator: zero,
+                max_steps: 8
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The most steps to run for one frame. Time beyond them is dropped, so that a slow frame
+    /// doesn't demand ever more steps from the frames after it.
+    pub fn max_steps(self, max_steps: usize) -> Self {
+        Self {
+            max_steps,
+            ..self
+        }
+    }
+
+    pub fn step(&self) -> U::Time {
+        self.step
+    }
+
+    /// Adds a frame's worth of time and runs every whole step it completes, returning how many ran.
+    pub fn advance<H: StepHooks<U>>(&mut self, universe: &mut U, frame_time: U::Time, hooks: &mut H) -> usize
+        where U: Simulation
+    {
+        self.accumulator = self.accumulator + frame_time;
+
+        let mut steps = 0;
+        while self.accumulator >= self.step && steps < self.max_steps {
+            universe.step_with(self.step, hooks);
+            self.accumulator = self.accumulator - self.step;
+            steps += 1;
+        }
+
+        if self.accumulator >= self.step {
+            self.accumulator = self.step.scale(U::Scalar::zero());
+        }
+
+        steps
+    }
+
+    /// How far the leftover time reaches into the next step, from `0` to `1`. Rendering the
+    /// previous and current states interpolated by this keeps motion smooth between steps.
+    pub fn alpha(&self) -> U::Scalar {
+        fraction(self.accumulator, self.step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec::Vec;
+    use crate::testing::Plane;
+    use crate::units::time::Seconds;
+    use super::*;
+
+    impl Simulation for Plane {
+        fn step(&mut self, dt: Seconds<f64>) {
+            self.time += dt;
+        }
+    }
+
+    fn plane() -> Plane {
+        Plane { time: Seconds::new(0.0) }
+    }
+
+    #[test]
+    fn runs_whole_steps_and_keeps_the_rest() {
+        let (mut plane, mut stepper) = (plane(), FixedTimestep::new(Seconds::new(0.25)).unwrap());
+
+        assert_eq!(stepper.advance(&mut plane, Seconds::new(0.625), &mut ()), 2);
+        assert_eq!((plane.time.value(), stepper.alpha()), (0.5, 0.5));
+
+        assert_eq!(stepper.advance(&mut plane, Seconds::new(0.0625), &mut ()), 0);
+        assert_eq!(stepper.alpha(), 0.75);
+
+        assert_eq!(stepper.advance(&mut plane, Seconds::new(0.0625), &mut ()), 1);
+        assert_eq!((plane.time.value(), stepper.alpha()), (0.75, 0.0));
+    }
+
+    #[test]
+    fn slow_frames_drop_what_they_cannot_run() {
+        let (mut plane, mut stepper) = (plane(), FixedTimestep::new(Seconds::new(0.25)).unwrap().max_steps(3));
+
+        assert_eq!(stepper.advance(&mut plane, Seconds::new(2.125), &mut ()), 3);
+        assert_eq!((plane.time.value(), stepper.alpha()), (0.75, 0.0));
+
+        // Only the frame's own time is left to run afterwards
+        assert_eq!(stepper.advance(&mut plane, Seconds::new(0.375), &mut ()), 1);
+        assert_eq!(stepper.alpha(), 0.5);
+    }
+
+    /// Writes down the hooks as they're called, with the time of the universe then.
+    struct Recorder(Vec<(&'static str, f64)>);
+
+    impl StepHooks<Plane> for Recorder {
+        fn before_step(&mut self, universe: &mut Plane, _dt: Seconds<f64>) {
+            self.0.push(("before", universe.time.value()));
+        }
+
+        fn after_step(&mut self, universe: &mut Plane, _dt: Seconds<f64>) {
+            self.0.push(("after", universe.time.value()));
+        }
+    }
+
+    #[test]
+    fn hooks_surround_every_step() {
+        let (mut plane, mut stepper) = (plane(), FixedTimestep::new(Seconds::new(0.25)).unwrap());
+        let mut recorder = Recorder(Vec::new());
+
+        stepper.advance(&mut plane, Seconds::new(0.5), &mut recorder);
+        assert_eq!(recorder.0, [("before", 0.0), ("after", 0.25), ("before", 0.25), ("after", 0.5)]);
+    }
+
+    #[test]
+    fn steps_must_take_time() {
+        assert!(FixedTimestep::<Plane>::new(Seconds::new(0.0)).is_none());
+        assert!(FixedTimestep::<Plane>::new(Seconds::new(-0.25)).is_none());
+        assert!(FixedTimestep::<Plane>::new(Seconds::new(f64::NAN)).is_none());
+    }
+}
@@ -0,0 +1,41 @@
+//! Moving a universe forward in time.
+//!
+//! Games render at whatever rate the display allows, but simulations are only stable and
+//! repeatable with a fixed step. `FixedTimestep` collects frame times and runs whole steps,
+//! leaving an interpolation `alpha` to draw between the last two states.
+use crate::{Additive, Scale, Time, Universe};
+
+mod fixed;
+pub use fixed::FixedTimestep;
+
+/// A universe that can advance itself.
+pub trait Simulation: Universe + Sized {
+    /// Moves everything in the universe forward by `dt`, including its clock.
+    fn step(&mut self, dt: Self::Time);
+
+    /// Steps once, calling `hooks` on either side.
+    fn step_with<H: StepHooks<Self>>(&mut self, dt: Self::Time, hooks: &mut H) {
+        hooks.before_step(self, dt);
+        self.step(dt);
+        hooks.after_step(self, dt);
+    }
+}
+
+/// Game code that runs around every step, such as applying player input before it and
+/// reacting to the results after it. Both do nothing unless overridden.
+pub trait StepHooks<U: Universe> {
+    fn before_step(&mut self, _universe: &mut U, _dt: U::Time) {}
+    fn after_step(&mut self, _universe: &mut U, _dt: U::Time) {}
+}
+
+impl<U: Universe> StepHooks<U> for () {}
+
+/// The value `alpha` of the way from `previous` to `current`, for drawing between two steps.
+pub fn interpolate<S: Additive + Scale<F>, F>(previous: S, current: S, alpha: F) -> S {
+    previous + (current - previous).scale(alpha)
+}
+
+/// The fraction of `step` that `elapsed` covers.
+pub(crate) fn fraction<T: Time>(elapsed: T, step: T) -> T::Base {
+    elapsed.seconds() / step.seconds()
+}
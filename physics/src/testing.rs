@@ -0,0 +1,60 @@
+//! A plain two-dimensional universe in `f64` for the crate's own tests.
+use crate::{Force, Subject, Universe};
+use crate::integration::{Integrator, RungeKutta4, State};
+use crate::rotation::Rotation2;
+use crate::units::{conversion, force, mass, motion, space, time};
+use crate::units::mass::Kilograms;
+use crate::units::motion::Velocity;
+use crate::units::space::Meters;
+use crate::units::time::Seconds;
+
+pub(crate) struct Plane {
+    pub time: time::Seconds<f64>
+}
+
+impl Universe for Plane {
+    type Scalar = f64;
+
+    type Space = space::Meters<2, f64>;
+    type Time = time::Seconds<f64>;
+    type Mass = mass::Kilograms<f64>;
+
+    type Velocity = motion::Velocity<2, f64>;
+    type Acceleration = motion::Acceleration<2, f64>;
+    type Force = force::Newtons<2, f64>;
+
+    type InverseMass = mass::InverseKilograms<f64>;
+
+    type Orientation = Rotation2<f64>;
+    type AngularVelocity = motion::AngularVelocity<1, f64>;
+    type AngularAcceleration = motion::AngularAcceleration<1, f64>;
+    type Torque = force::Torque<1, f64>;
+    type InverseInertia = force::InverseMomentOfInertia<f64>;
+
+    type Units = conversion::SI;
+
+    fn time(&self) -> &Self::Time {
+        &self.time
+    }
+}
+
+/// A body of `mass` at `position` moving at `velocity`.
+pub(crate) fn subject(position: [f64; 2], velocity: [f64; 2], mass: f64) -> Subject<Plane> {
+    Subject::new(Meters::from_components(&position), Velocity::from_components(&velocity), Kilograms::new(mass))
+}
+
+/// Moves `body` under `force` for `steps` steps of `dt` with RK4, calling `visit` with the time
+/// and the body after every step.
+pub(crate) fn simulate<F: Force<Plane>>(force: &F, body: Subject<Plane>, dt: f64, steps: usize, mut visit: impl FnMut(f64, &Subject<Plane>)) -> Subject<Plane> {
+    let mut state = State::<Plane>::new(body.position, body.velocity);
+    let mut time = Seconds::new(0.0);
+    for _ in 0..steps {
+        state = RungeKutta4.integrate(&state, time, Seconds::new(dt), |s, _| {
+            force.applied(&Subject::new(s.position, s.velocity, body.mass), &Seconds::new(dt)) / body.mass
+        });
+        time += Seconds::new(dt);
+        visit(time.value(), &Subject::new(state.position, state.velocity, body.mass));
+    }
+
+    Subject::new(state.position, state.velocity, body.mass)
+}
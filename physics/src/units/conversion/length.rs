@@ -0,0 +1,21 @@
+use crate::units::dimension::Length;
+use super::Unit;
+
+crate::unit!(pub Meter: Length = 1 / 1, "m");
+crate::unit!(pub Centimeter: Length = 1 / 100, "cm");
+crate::unit!(pub Millimeter: Length = 1 / 1000, "mm");
+crate::unit!(
+    /// The international foot, exactly 0.3048 m
+    pub Foot: Length = 381 / 1250, "ft"
+);
+
+/// A game's grid square, `NUMERATOR / DENOMINATOR` metres across.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Tile<const NUMERATOR: i64, const DENOMINATOR: i64>;
+
+impl<const NUMERATOR: i64, const DENOMINATOR: i64> Unit for Tile<NUMERATOR, DENOMINATOR> {
+    type Dimension = Length;
+    const NUMERATOR: i64 = NUMERATOR;
+    const DENOMINATOR: i64 = DENOMINATOR;
+    const SYMBOL: &'static str = "tile";
+}
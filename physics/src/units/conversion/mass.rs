@@ -0,0 +1,4 @@
+use crate::units::dimension::Mass;
+
+crate::unit!(pub Kilogram: Mass = 1 / 1, "kg");
+crate::unit!(pub Gram: Mass = 1 / 1000, "g");
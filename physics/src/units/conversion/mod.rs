@@ -0,0 +1,131 @@
+//! Units other than SI, and conversions between them.
+//!
+//! Every `Quantity` is held in SI units. Other units describe how numbers read from or written
+//! to the outside world relate to those, as an exact ratio. A conversion either lands on an
+//! exact value or reports why it couldn't; the `_lossy` variants round instead.
+use crate::space::BaseUnit;
+use super::Quantity;
+
+mod scalable;
+pub use scalable::Scalable;
+
+mod system;
+pub use system::{UnitSystem, SI};
+pub(crate) use system::system_ratio;
+
+mod length;
+pub use length::{Centimeter, Foot, Meter, Millimeter, Tile};
+
+mod time;
+pub use time::{Millisecond, Second};
+
+mod mass;
+pub use mass::{Gram, Kilogram};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The value has no exact representation in the target unit.
+    Inexact,
+    /// The value is too large for its base type once converted.
+    Overflow
+}
+
+impl core::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConversionError::Inexact => write!(f, "value is not exact in the target unit"),
+            ConversionError::Overflow => write!(f, "value is out of range for its type")
+        }
+    }
+}
+
+/// A unit of measure for `Dimension`, defined by how many SI units one of it is worth.
+///
+/// Use the `unit!` macro to declare new ones.
+pub trait Unit {
+    type Dimension;
+
+    /// One of this unit is `NUMERATOR / DENOMINATOR` of the SI unit.
+    const NUMERATOR: i64;
+    const DENOMINATOR: i64;
+    const SYMBOL: &'static str;
+}
+
+/// Declares a unit as an exact fraction of the SI unit of its dimension.
+///
+/// ```
+/// physics::unit!(
+///     /// A sixteenth of a metre
+///     pub Pixel: physics::units::dimension::Length = 1 / 16, "px"
+/// );
+/// ```
+#[macro_export]
+macro_rules! unit {
+    ($(#[$meta:meta])* $vis:vis $name:ident: $dimension:ty = $numerator:literal / $denominator:literal, $symbol:literal) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, Default)]
+        $vis struct $name;
+
+        impl $crate::units::conversion::Unit for $name {
+            type Dimension = $dimension;
+            const NUMERATOR: i64 = $numerator;
+            const DENOMINATOR: i64 = $denominator;
+            const SYMBOL: &'static str = $symbol;
+        }
+    };
+}
+
+pub(super) fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+/// The ratio that carries a value in `From` to the same value in `To`, in lowest terms.
+fn ratio<From: Unit, To: Unit>() -> (i128, i128) {
+    let numerator = From::NUMERATOR as i128 * To::DENOMINATOR as i128;
+    let denominator = From::DENOMINATOR as i128 * To::NUMERATOR as i128;
+    let divisor = gcd(numerator, denominator);
+
+    (numerator / divisor, denominator / divisor)
+}
+
+/// Converts a value between two units of the same dimension, failing unless it is exact.
+pub fn convert<From: Unit, To: Unit<Dimension = From::Dimension>, B: Scalable>(value: B) -> Result<B, ConversionError> {
+    let (numerator, denominator) = ratio::<From, To>();
+    value.scale(numerator, denominator)
+}
+
+/// Converts a value between two units of the same dimension, rounding if needed.
+pub fn convert_lossy<From: Unit, To: Unit<Dimension = From::Dimension>, B: Scalable>(value: B) -> B {
+    let (numerator, denominator) = ratio::<From, To>();
+    value.scale_lossy(numerator, denominator)
+}
+
+impl<const N: usize, B: BaseUnit + Scalable, U> Quantity<N, B, U> {
+    /// Reads a quantity given in `Un`, failing unless every component converts exactly.
+    pub fn from_unit<Un: Unit<Dimension = U>>(values: &[B; N]) -> Result<Self, ConversionError> {
+        let mut si = [B::zero(); N];
+        for (out, v) in si.iter_mut().zip(values) {
+            *out = v.scale(Un::NUMERATOR as i128, Un::DENOMINATOR as i128)?;
+        }
+
+        Ok(Self::from_components(&si))
+    }
+
+    pub fn from_unit_lossy<Un: Unit<Dimension = U>>(values: &[B; N]) -> Self {
+        Self::from_components(&values.map(|v| v.scale_lossy(Un::NUMERATOR as i128, Un::DENOMINATOR as i128)))
+    }
+
+    /// The components of this quantity measured in `Un`, failing unless every one is exact.
+    pub fn to_unit<Un: Unit<Dimension = U>>(&self) -> Result<[B; N], ConversionError> {
+        let mut values = self.values();
+        for v in values.iter_mut() {
+            *v = v.scale(Un::DENOMINATOR as i128, Un::NUMERATOR as i128)?;
+        }
+
+        Ok(values)
+    }
+
+    pub fn to_unit_lossy<Un: Unit<Dimension = U>>(&self) -> [B; N] {
+        self.values().map(|v| v.scale_lossy(Un::DENOMINATOR as i128, Un::NUMERATOR as i128))
+    }
+}
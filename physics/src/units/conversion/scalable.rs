@@ -0,0 +1,60 @@
+use super::ConversionError;
+
+/// A number that can be multiplied by an exact ratio.
+pub trait Scalable: Sized {
+    /// `self * numerator / denominator`, or an error if that isn't exactly representable.
+    fn scale(self, numerator: i128, denominator: i128) -> Result<Self, ConversionError>;
+
+    /// `self * numerator / denominator`, rounded towards zero and saturated at the type's limits.
+    fn scale_lossy(self, numerator: i128, denominator: i128) -> Self;
+}
+
+macro_rules! scalable_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Scalable for $t {
+                fn scale(self, numerator: i128, denominator: i128) -> Result<Self, ConversionError> {
+                    let scaled = (self as i128).checked_mul(numerator).ok_or(ConversionError::Overflow)?;
+                    if scaled % denominator != 0 {
+                        return Err(ConversionError::Inexact);
+                    }
+
+                    <$t>::try_from(scaled / denominator).map_err(|_| ConversionError::Overflow)
+                }
+
+                fn scale_lossy(self, numerator: i128, denominator: i128) -> Self {
+                    let scaled = (self as i128).saturating_mul(numerator) / denominator;
+                    scaled.clamp(<$t>::MIN as i128, <$t>::MAX as i128) as $t
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! scalable_float {
+    ($($t:ty),*) => {
+        $(
+            impl Scalable for $t {
+                /// Exact when converting back recovers the original value bit for bit.
+                fn scale(self, numerator: i128, denominator: i128) -> Result<Self, ConversionError> {
+                    let scaled = self.scale_lossy(numerator, denominator);
+                    if !scaled.is_finite() && self.is_finite() {
+                        return Err(ConversionError::Overflow);
+                    }
+                    if scaled.scale_lossy(denominator, numerator) != self {
+                        return Err(ConversionError::Inexact);
+                    }
+
+                    Ok(scaled)
+                }
+
+                fn scale_lossy(self, numerator: i128, denominator: i128) -> Self {
+                    self * (numerator as $t) / (denominator as $t)
+                }
+            }
+        )*
+    };
+}
+
+scalable_integer!(i32, i64);
+scalable_float!(f32, f64);
@@ -0,0 +1,141 @@
+use crate::space::BaseUnit;
+use crate::units::{dimension, Quantity};
+use crate::units::dimension::Dimensioned;
+use super::{gcd, ConversionError, Kilogram, Meter, Scalable, Second, Unit};
+
+/// The units a `Universe` reads and writes its quantities in, such as level files measured in
+/// tiles. Quantities themselves are always held in SI.
+pub trait UnitSystem {
+    type Length: Unit<Dimension = dimension::Length>;
+    type Time: Unit<Dimension = dimension::Time>;
+    type Mass: Unit<Dimension = dimension::Mass>;
+}
+
+/// Metres, seconds and kilograms.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SI;
+
+impl UnitSystem for SI {
+    type Length = Meter;
+    type Time = Second;
+    type Mass = Kilogram;
+}
+
+/// `numerator / denominator` raised to `exponent`, as a ratio in lowest terms.
+fn power(numerator: i64, denominator: i64, exponent: i32) -> Result<(i128, i128), ConversionError> {
+    let (numerator, denominator) = if exponent < 0 { (denominator, numerator) } else { (numerator, denominator) };
+    let n = exponent.unsigned_abs();
+
+    Ok((
+        (numerator as i128).checked_pow(n).ok_or(ConversionError::Overflow)?,
+        (denominator as i128).checked_pow(n).ok_or(ConversionError::Overflow)?
+    ))
+}
+
+/// How many SI units one unit of `S` is worth, for a quantity of dimension `U`.
+pub(crate) fn system_ratio<S: UnitSystem, U: Dimensioned>() -> Result<(i128, i128), ConversionError> {
+    let factors = [
+        power(S::Length::NUMERATOR, S::Length::DENOMINATOR, U::LENGTH)?,
+        power(S::Mass::NUMERATOR, S::Mass::DENOMINATOR, U::MASS)?,
+        power(S::Time::NUMERATOR, S::Time::DENOMINATOR, U::TIME)?
+    ];
+
+    factors.iter().try_fold((1, 1), |(numerator, denominator): (i128, i128), (n, d)| {
+        let numerator = numerator.checked_mul(*n).ok_or(ConversionError::Overflow)?;
+        let denominator = denominator.checked_mul(*d).ok_or(ConversionError::Overflow)?;
+        let divisor = gcd(numerator, denominator);
+
+        Ok((numerator / divisor, denominator / divisor))
+    })
+}
+
+impl<const N: usize, B: BaseUnit + Scalable, U: Dimensioned> Quantity<N, B, U> {
+    /// Reads a quantity given in the units of `S`, failing unless every component converts
+    /// exactly. Derived quantities combine the system's units, so a velocity in a system of
+    /// tiles and milliseconds is read in tiles per millisecond.
+    pub fn from_system<S: UnitSystem>(values: &[B; N]) -> Result<Self, ConversionError> {
+        let (numerator, denominator) = system_ratio::<S, U>()?;
+        let mut si = [B::zero(); N];
+        for (out, v) in si.iter_mut().zip(values) {
+            *out = v.scale(numerator, denominator)?;
+        }
+
+        Ok(Self::from_components(&si))
+    }
+
+    /// The components of this quantity measured in the units of `S`, failing unless every one
+    /// is exact.
+    pub fn to_system<S: UnitSystem>(&self) -> Result<[B; N], ConversionError> {
+        let (numerator, denominator) = system_ratio::<S, U>()?;
+        let mut values = self.values();
+        for v in values.iter_mut() {
+            *v = v.scale(denominator, numerator)?;
+        }
+
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+    use crate::units::force::Newtons;
+    use crate::units::motion::Velocity;
+    use crate::units::space::Meters;
+    use crate::units::time::Seconds;
+    use crate::units::ParseError;
+    use super::super::{Gram, Millisecond, Tile};
+    use super::*;
+
+    /// Half-metre tiles, milliseconds and grams.
+    struct Level;
+
+    impl UnitSystem for Level {
+        type Length = Tile<1, 2>;
+        type Time = Millisecond;
+        type Mass = Gram;
+    }
+
+    #[test]
+    fn derived_units_combine() {
+        assert_eq!(system_ratio::<Level, dimension::Length>(), Ok((1, 2)));
+        assert_eq!(system_ratio::<Level, dimension::Velocity>(), Ok((500, 1)));
+        assert_eq!(system_ratio::<Level, dimension::Force>(), Ok((500, 1)));
+        assert_eq!(system_ratio::<SI, dimension::Force>(), Ok((1, 1)));
+    }
+
+    #[test]
+    fn converts_through_a_system() {
+        let v = Velocity::<2, f64>::from_system::<Level>(&[2.0, -1.0]).unwrap();
+        assert_eq!(v.values(), [1000.0, -500.0]);
+        assert_eq!(v.to_system::<Level>(), Ok([2.0, -1.0]));
+
+        assert_eq!(Meters::<1, i64>::from_system::<Level>(&[3]), Err(ConversionError::Inexact));
+        assert_eq!(Meters::<1, i64>::new(3).to_system::<Level>(), Ok([6]));
+    }
+
+    #[test]
+    fn parses_bare_numbers_in_a_system() {
+        let tiles: Meters<2, f64> = Meters::parse_in::<Level>("(4, 1)").unwrap();
+        assert_eq!(tiles.values(), [2.0, 0.5]);
+
+        let metres: Meters<2, f64> = Meters::parse_in::<Level>("(4, 1) m").unwrap();
+        assert_eq!(metres.values(), [4.0, 1.0]);
+
+        let time: Seconds<f64> = Seconds::parse_in::<Level>("250").unwrap();
+        assert_eq!(time.value(), 0.25);
+
+        assert_eq!(Seconds::<f64>::parse_in::<SI>("250").map(|s| s.value()), Ok(250.0));
+        assert_eq!("250".parse::<Seconds<f64>>().map(|s| s.value()), Err(ParseError::WrongDimension));
+    }
+
+    #[test]
+    fn displays_in_a_system() {
+        assert_eq!(format!("{}", Meters::<2, f64>::from_components(&[1.0, 0.5]).display_in::<Level>()), "(2, 1) tile");
+        assert_eq!(format!("{}", Velocity::<1, f64>::new(1000.0).display_in::<Level>()), "2 tile/ms");
+        assert_eq!(format!("{}", Newtons::<1, f64>::new(500.0).display_in::<Level>()), "1 g·tile/ms²");
+        assert_eq!(format!("{}", Newtons::<1, f64>::new(3.0).display_in::<SI>()), "3 N");
+        assert_eq!(format!("{:.1}", Seconds::<f64>::new(0.25).display_in::<Level>()), "250.0 ms");
+    }
+}
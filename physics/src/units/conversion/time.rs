@@ -0,0 +1,4 @@
+use crate::units::dimension::Time;
+
+crate::unit!(pub Second: Time = 1 / 1, "s");
+crate::unit!(pub Millisecond: Time = 1 / 1000, "ms");
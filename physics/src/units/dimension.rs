@@ -0,0 +1,200 @@
+//! Physical dimensions tracked in the type system.
+//!
+//! A `Dimension<L, M, T>` records the exponents of length, mass and time as type-level
+//! integers, so `Length / Time` works out to `Velocity` while the compiler is still checking
+//! types, and adding a `Velocity` to a `Length` fails to compile.
+//!
+//! ```
+//! use physics::units::motion::Velocity;
+//! use physics::units::space::Meters;
+//! use physics::units::time::Seconds;
+//!
+//! let speed: Velocity<1, f64> = Meters::new(6.0) / Seconds::new(2.0);
+//! assert_eq!(speed.value(), 3.0);
+//! ```
+//!
+//! ```compile_fail
+//! use physics::units::space::Meters;
+//! use physics::units::time::Seconds;
+//!
+//! let _ = Meters::<1, f64>::new(6.0) + Seconds::new(2.0);
+//! ```
+use core::marker::PhantomData;
+
+/// An integer known at compile time, used as an exponent.
+pub trait Integer {
+    const VALUE: i32;
+}
+
+/// The integer one above `Self`.
+pub trait Increment {
+    type Output;
+}
+
+/// The integer one below `Self`.
+pub trait Decrement {
+    type Output;
+}
+
+/// `Self` with its sign flipped.
+pub trait Negate {
+    type Output;
+}
+
+/// `Self + Rhs`, for type-level integers.
+pub trait Sum<Rhs> {
+    type Output;
+}
+
+/// `Self - Rhs`, for type-level integers.
+pub trait Difference<Rhs> {
+    type Output;
+}
+
+/// Exponents this far from zero have no type, so any arithmetic producing one fails to compile.
+pub struct OutOfRange;
+
+macro_rules! integers {
+    ($($name:ident = $value:expr, $pred:ident < > $succ:ident, - $neg:ident;)*) => {
+        $(
+            #[derive(Copy, Clone, Debug, Default)]
+            pub struct $name;
+            impl Integer for $name { const VALUE: i32 = $value; }
+            impl Increment for $name { type Output = $succ; }
+            impl Decrement for $name { type Output = $pred; }
+            impl Negate for $name { type Output = $neg; }
+        )*
+    };
+}
+
+integers! {
+    N6 = -6, OutOfRange < > N5, -P6;
+    N5 = -5, N6 < > N4, -P5;
+    N4 = -4, N5 < > N3, -P4;
+    N3 = -3, N4 < > N2, -P3;
+    N2 = -2, N3 < > N1, -P2;
+    N1 = -1, N2 < > Z0, -P1;
+    Z0 = 0, N1 < > P1, -Z0;
+    P1 = 1, Z0 < > P2, -N1;
+    P2 = 2, P1 < > P3, -N2;
+    P3 = 3, P2 < > P4, -N3;
+    P4 = 4, P3 < > P5, -N4;
+    P5 = 5, P4 < > P6, -N5;
+    P6 = 6, P5 < > OutOfRange, -N6;
+}
+
+impl<A: Integer> Sum<Z0> for A {
+    type Output = A;
+}
+
+// Adding `n` is adding `n - 1` and then stepping once more towards `n`'s sign.
+macro_rules! sums {
+    ($step:ident: $($rhs:ident => $prev:ident),*) => {
+        $(
+            impl<A: Sum<$prev>> Sum<$rhs> for A
+                where <A as Sum<$prev>>::Output: $step
+            {
+                type Output = <<A as Sum<$prev>>::Output as $step>::Output;
+            }
+        )*
+    };
+}
+
+sums!(Increment: P1 => Z0, P2 => P1, P3 => P2, P4 => P3, P5 => P4, P6 => P5);
+sums!(Decrement: N1 => Z0, N2 => N1, N3 => N2, N4 => N3, N5 => N4, N6 => N5);
+
+impl<A, B: Negate> Difference<B> for A
+    where A: Sum<<B as Negate>::Output>
+{
+    type Output = <A as Sum<<B as Negate>::Output>>::Output;
+}
+
+/// Exponents of length, mass and time.
+pub struct Dimension<L, M, T>(PhantomData<(L, M, T)>);
+
+/// Reads the exponents back out of a `Dimension`.
+pub trait Dimensioned {
+    const LENGTH: i32;
+    const MASS: i32;
+    const TIME: i32;
+}
+
+impl<L: Integer, M: Integer, T: Integer> Dimensioned for Dimension<L, M, T> {
+    const LENGTH: i32 = L::VALUE;
+    const MASS: i32 = M::VALUE;
+    const TIME: i32 = T::VALUE;
+}
+
+/// The dimension of `Self * Rhs`.
+pub trait DimensionProduct<Rhs> {
+    type Output;
+}
+
+/// The dimension of `Self / Rhs`.
+pub trait DimensionQuotient<Rhs> {
+    type Output;
+}
+
+impl<L1, M1, T1, L2, M2, T2> DimensionProduct<Dimension<L2, M2, T2>> for Dimension<L1, M1, T1>
+    where L1: Sum<L2>, M1: Sum<M2>, T1: Sum<T2>
+{
+    type Output = Dimension<<L1 as Sum<L2>>::Output, <M1 as Sum<M2>>::Output, <T1 as Sum<T2>>::Output>;
+}
+
+impl<L1, M1, T1, L2, M2, T2> DimensionQuotient<Dimension<L2, M2, T2>> for Dimension<L1, M1, T1>
+    where L1: Difference<L2>, M1: Difference<M2>, T1: Difference<T2>
+{
+    type Output = Dimension<<L1 as Difference<L2>>::Output, <M1 as Difference<M2>>::Output, <T1 as Difference<T2>>::Output>;
+}
+
+pub type Product<A, B> = <A as DimensionProduct<B>>::Output;
+pub type Quotient<A, B> = <A as DimensionQuotient<B>>::Output;
+
+pub type Dimensionless = Dimension<Z0, Z0, Z0>;
+pub type Length = Dimension<P1, Z0, Z0>;
+pub type Mass = Dimension<Z0, P1, Z0>;
+pub type Time = Dimension<Z0, Z0, P1>;
+
+pub type Area = Product<Length, Length>;
+pub type Volume = Product<Area, Length>;
+pub type Velocity = Quotient<Length, Time>;
+pub type Acceleration = Quotient<Velocity, Time>;
+pub type Force = Product<Mass, Acceleration>;
+pub type Momentum = Product<Mass, Velocity>;
+pub type Impulse = Product<Force, Time>;
+pub type Energy = Product<Force, Length>;
+pub type Power = Quotient<Energy, Time>;
+
+/// Angles are ratios of lengths, so angular quantities carry no length of their own.
+pub type AngularVelocity = Quotient<Dimensionless, Time>;
+pub type AngularAcceleration = Quotient<AngularVelocity, Time>;
+pub type Torque = Product<Length, Force>;
+pub type MomentOfInertia = Product<Mass, Area>;
+
+pub type InverseMass = Quotient<Dimensionless, Mass>;
+pub type InverseMomentOfInertia = Quotient<Dimensionless, MomentOfInertia>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The exponents of length, mass and time in `D`.
+    fn exponents<D: Dimensioned>() -> [i32; 3] {
+        [D::LENGTH, D::MASS, D::TIME]
+    }
+
+    #[test]
+    fn exponents_add_and_subtract() {
+        assert_eq!(exponents::<Dimension<<P2 as Sum<N3>>::Output, <N4 as Sum<P3>>::Output, <Z0 as Sum<N1>>::Output>>(), [-1, -1, -1]);
+        assert_eq!(exponents::<Dimension<<P4 as Difference<P6>>::Output, <N2 as Difference<N2>>::Output, <Z0 as Difference<P6>>::Output>>(), [-2, 0, -6]);
+    }
+
+    #[test]
+    fn derived_dimensions() {
+        assert_eq!(exponents::<Velocity>(), [1, 0, -1]);
+        assert_eq!(exponents::<Acceleration>(), [1, 0, -2]);
+        assert_eq!(exponents::<Force>(), [1, 1, -2]);
+        assert_eq!(exponents::<Quotient<Velocity, Velocity>>(), [0, 0, 0]);
+        assert_eq!(exponents::<Product<Area, Area>>(), [4, 0, 0]);
+    }
+}
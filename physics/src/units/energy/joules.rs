@@ -0,0 +1,5 @@
+use crate::units::dimension;
+use crate::units::Quantity;
+
+/// Newton metres of work. Energy has no direction, so like `Seconds` it has one component.
+pub type Joules<B> = Quantity<1, B, dimension::Energy>;
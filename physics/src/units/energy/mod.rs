@@ -0,0 +1,5 @@
+mod joules;
+pub use joules::Joules;
+
+mod watts;
+pub use watts::Watts;
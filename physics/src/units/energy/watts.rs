@@ -0,0 +1,5 @@
+use crate::units::dimension;
+use crate::units::Quantity;
+
+/// Joules per second.
+pub type Watts<B> = Quantity<1, B, dimension::Power>;
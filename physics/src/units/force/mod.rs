@@ -0,0 +1,8 @@
+mod newtons;
+pub use newtons::Newtons;
+
+mod momentum;
+pub use momentum::{Impulse, Momentum};
+
+mod torque;
+pub use torque::{InertiaTensor, InverseInertiaTensor, InverseMomentOfInertia, MomentOfInertia, Torque};
@@ -0,0 +1,9 @@
+use crate::units::dimension;
+use crate::units::Quantity;
+
+/// Kilogram metres per second.
+pub type Momentum<const DIMENSIONS: usize, B> = Quantity<DIMENSIONS, B, dimension::Momentum>;
+
+/// Newton seconds, the change in `Momentum` a force causes over time. The two share a
+/// dimension, so an impulse adds straight onto a momentum.
+pub type Impulse<const DIMENSIONS: usize, B> = Quantity<DIMENSIONS, B, dimension::Impulse>;
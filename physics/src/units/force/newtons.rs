@@ -0,0 +1,5 @@
+use crate::units::dimension;
+use crate::units::Quantity;
+
+/// Kilogram metres per second squared.
+pub type Newtons<const DIMENSIONS: usize, B> = Quantity<DIMENSIONS, B, dimension::Force>;
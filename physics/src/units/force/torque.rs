@@ -0,0 +1,14 @@
+use crate::units::dimension;
+use crate::units::{Quantity, Tensor};
+
+/// Newton metres: one component about the out-of-plane axis in 2D, three in 3D.
+pub type Torque<const DIMENSIONS: usize, B> = Quantity<DIMENSIONS, B, dimension::Torque>;
+
+/// Kilogram square metres, resisting changes in `AngularVelocity`.
+pub type MomentOfInertia<B> = Quantity<1, B, dimension::MomentOfInertia>;
+
+pub type InverseMomentOfInertia<B> = Quantity<1, B, dimension::InverseMomentOfInertia>;
+
+/// The moments of inertia about every axis of a body in 3D, and how they couple.
+pub type InertiaTensor<B> = Tensor<B, dimension::MomentOfInertia>;
+pub type InverseInertiaTensor<B> = Tensor<B, dimension::InverseMomentOfInertia>;
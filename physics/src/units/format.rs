@@ -0,0 +1,173 @@
+use core::fmt::{self, Debug, Display, Formatter};
+use core::marker::PhantomData;
+use crate::space::BaseUnit;
+use super::Quantity;
+use super::conversion::{system_ratio, Scalable, Unit, UnitSystem};
+use super::dimension::Dimensioned;
+
+/// Dimensions with a symbol of their own, as exponents of length, mass and time.
+pub(crate) const NAMED: [(&str, [i32; 3]); 3] = [
+    ("N", [1, 1, -2]),
+    ("J", [2, 1, -2]),
+    ("W", [2, 1, -3])
+];
+
+/// How those dimensions are shown. Torque has the dimension of energy, and the two are the
+/// same type, so that dimension shows as `N·m`, which suits both, rather than `J`.
+const SHOWN: [(&str, [i32; 3]); 3] = [
+    ("N", [1, 1, -2]),
+    ("N·m", [2, 1, -2]),
+    ("W", [2, 1, -3])
+];
+
+fn write_power(f: &mut Formatter<'_>, symbol: &str, exponent: i32) -> fmt::Result {
+    match exponent {
+        1 => write!(f, "{}", symbol),
+        2 => write!(f, "{}²", symbol),
+        3 => write!(f, "{}³", symbol),
+        e => write!(f, "{}^{}", symbol, e)
+    }
+}
+
+/// The symbols of metres, kilograms and seconds.
+const SI_SYMBOLS: [&str; 3] = ["m", "kg", "s"];
+
+/// Writes the symbol for a dimension, such as `m/s²` or `kg·m²`, preceded by a space, built
+/// from the symbols for length, mass and time. Every base unit with a negative exponent goes
+/// after a single `/`.
+fn write_symbol(f: &mut Formatter<'_>, exponents: [i32; 3], symbols: [&str; 3]) -> fmt::Result {
+    if exponents == [0, 0, 0] {
+        return Ok(());
+    }
+
+    if symbols == SI_SYMBOLS {
+        if let Some((symbol, _)) = SHOWN.iter().find(|(_, e)| *e == exponents) {
+            return write!(f, " {}", symbol);
+        }
+    }
+
+    let [length, mass, time] = exponents;
+    let bases = [(symbols[1], mass), (symbols[0], length), (symbols[2], time)];
+
+    f.write_str(" ")?;
+    let mut first = true;
+    for (symbol, exponent) in bases.iter().filter(|(_, e)| *e > 0) {
+        if !first {
+            f.write_str("·")?;
+        }
+        write_power(f, symbol, *exponent)?;
+        first = false;
+    }
+    if first {
+        f.write_str("1")?;
+    }
+
+    first = true;
+    for (symbol, exponent) in bases.iter().filter(|(_, e)| *e < 0) {
+        f.write_str(if first { "/" } else { "·" })?;
+        write_power(f, symbol, -exponent)?;
+        first = false;
+    }
+
+    Ok(())
+}
+
+/// Scalars print as `3.5 m`, vectors as `(1, 2) m`. Formatting options such as precision apply
+/// to every component.
+impl<const N: usize, B: BaseUnit + Display, U: Dimensioned> Display for Quantity<N, B, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_components(f, &self.values())?;
+        write_symbol(f, [U::LENGTH, U::MASS, U::TIME], SI_SYMBOLS)
+    }
+}
+
+fn write_components<B: Display>(f: &mut Formatter<'_>, values: &[B]) -> fmt::Result {
+    if let [value] = values {
+        return Display::fmt(value, f);
+    }
+
+    f.write_str("(")?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        Display::fmt(value, f)?;
+    }
+    f.write_str(")")
+}
+
+/// A quantity shown in the units of `S`, as returned by `Quantity::display_in`.
+pub struct InSystem<'a, S, const N: usize, B: BaseUnit, U> {
+    quantity: &'a Quantity<N, B, U>,
+    system: PhantomData<S>
+}
+
+impl<const N: usize, B: BaseUnit, U> Quantity<N, B, U> {
+    /// Shows this quantity in the units of `S`, such as a universe's `Units`, rounding where
+    /// it isn't exact. A velocity in a system of tiles and milliseconds prints as `2 tile/ms`.
+    pub fn display_in<S: UnitSystem>(&self) -> InSystem<'_, S, N, B, U> {
+        InSystem {
+            quantity: self,
+            system: PhantomData
+        }
+    }
+}
+
+impl<S, const N: usize, B, U> Display for InSystem<'_, S, N, B, U>
+    where S: UnitSystem, B: BaseUnit + Scalable + Display, U: Dimensioned
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (numerator, denominator) = system_ratio::<S, U>().map_err(|_| fmt::Error)?;
+        write_components(f, &self.quantity.values().map(|v| v.scale_lossy(denominator, numerator)))?;
+        write_symbol(f, [U::LENGTH, U::MASS, U::TIME], [S::Length::SYMBOL, S::Mass::SYMBOL, S::Time::SYMBOL])
+    }
+}
+
+impl<const N: usize, B: BaseUnit + Display, U: Dimensioned> Debug for Quantity<N, B, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+    use crate::units::energy::{Joules, Watts};
+    use crate::units::force::{Momentum, Newtons, Torque};
+    use crate::units::mass::Kilograms;
+    use crate::units::motion::{Acceleration, AngularVelocity};
+    use crate::units::space::Meters;
+    use crate::units::time::Seconds;
+
+    #[test]
+    fn prints_values_and_units() {
+        assert_eq!(format!("{}", Meters::<1, f64>::new(3.5)), "3.5 m");
+        assert_eq!(format!("{}", Meters::<2, f64>::from_components(&[1.0, 2.0])), "(1, 2) m");
+        assert_eq!(format!("{}", Kilograms::<i64>::new(12)), "12 kg");
+        assert_eq!(format!("{:?}", Seconds::<f32>::new(0.5)), "0.5 s");
+        assert_eq!(format!("{:.2}", Meters::<2, f64>::from_components(&[1.0, 1.0 / 3.0])), "(1.00, 0.33) m");
+    }
+
+    #[test]
+    fn prints_derived_units() {
+        assert_eq!(format!("{}", Acceleration::<1, f64>::new(9.81)), "9.81 m/s²");
+        assert_eq!(format!("{}", Momentum::<1, f64>::new(2.0)), "2 kg·m/s");
+        assert_eq!(format!("{}", AngularVelocity::<1, f64>::new(3.0)), "3 1/s");
+        assert_eq!(format!("{}", Newtons::<1, f64>::new(3.0)), "3 N");
+        assert_eq!(format!("{}", Watts::<f64>::new(3.0)), "3 W");
+
+        // Torque and energy are the same type, and neither prints as joules of torque
+        assert_eq!(format!("{}", Torque::<1, f64>::new(3.0)), "3 N·m");
+        assert_eq!(format!("{}", Joules::<f64>::new(3.0)), "3 N·m");
+    }
+
+    #[test]
+    fn printed_quantities_read_back() {
+        let torque = Torque::<1, f64>::new(-1.25);
+        assert_eq!(format!("{torque}").parse::<Torque<1, f64>>().map(|t| t.value()), Ok(-1.25));
+
+        let force = Newtons::<2, f64>::from_components(&[0.5, -3.0]);
+        assert_eq!(format!("{force}").parse::<Newtons<2, f64>>().map(|f| f.values()), Ok([0.5, -3.0]));
+    }
+}
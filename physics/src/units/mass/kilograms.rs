@@ -1,9 +1,11 @@
 use crate::Mass;
+use crate::space::BaseUnit;
+use crate::units::dimension;
+use crate::units::Quantity;
 
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
-pub struct Kilograms(i64);
-impl Kilograms {
-    pub fn new(m: i64) -> Self { Kilograms(m) }
-}
+pub type Kilograms<B> = Quantity<1, B, dimension::Mass>;
 
-impl Mass for Kilograms {}
\ No newline at end of file
+/// One over kilograms, how much a force accelerates a body.
+pub type InverseKilograms<B> = Quantity<1, B, dimension::InverseMass>;
+
+impl<B: BaseUnit> Mass for Kilograms<B> {}
@@ -1,2 +1,2 @@
 mod kilograms;
-pub use kilograms::Kilograms;
\ No newline at end of file
+pub use kilograms::{InverseKilograms, Kilograms};
\ No newline at end of file
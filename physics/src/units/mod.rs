@@ -1,3 +1,22 @@
+pub mod dimension;
+
+mod quantity;
+pub use quantity::Quantity;
+
+mod tensor;
+pub use tensor::Tensor;
+
+mod format;
+pub use format::InSystem;
+mod parse;
+pub use parse::ParseError;
+
+pub mod conversion;
+
 pub mod mass;
 pub mod space;
-pub mod time;
\ No newline at end of file
+pub mod time;
+
+pub mod motion;
+pub mod force;
+pub mod energy;
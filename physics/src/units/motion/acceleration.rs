@@ -0,0 +1,8 @@
+use crate::units::dimension;
+use crate::units::Quantity;
+
+/// Metres per second squared.
+pub type Acceleration<const DIMENSIONS: usize, B> = Quantity<DIMENSIONS, B, dimension::Acceleration>;
+
+/// Radians per second squared.
+pub type AngularAcceleration<const DIMENSIONS: usize, B> = Quantity<DIMENSIONS, B, dimension::AngularAcceleration>;
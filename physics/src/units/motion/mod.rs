@@ -0,0 +1,5 @@
+mod velocity;
+pub use velocity::{AngularVelocity, Velocity};
+
+mod acceleration;
+pub use acceleration::{Acceleration, AngularAcceleration};
@@ -0,0 +1,8 @@
+use crate::units::dimension;
+use crate::units::Quantity;
+
+/// Metres per second.
+pub type Velocity<const DIMENSIONS: usize, B> = Quantity<DIMENSIONS, B, dimension::Velocity>;
+
+/// Radians per second: one component about the out-of-plane axis in 2D, three in 3D.
+pub type AngularVelocity<const DIMENSIONS: usize, B> = Quantity<DIMENSIONS, B, dimension::AngularVelocity>;
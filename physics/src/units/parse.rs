@@ -0,0 +1,302 @@
+use core::fmt;
+use core::str::FromStr;
+use crate::space::BaseUnit;
+use super::Quantity;
+use super::conversion::{gcd, system_ratio, ConversionError, Scalable, UnitSystem};
+use super::dimension::Dimensioned;
+use super::format::NAMED;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A component isn't a number of the quantity's base type.
+    InvalidNumber,
+    /// The text has a different number of components than the quantity.
+    Components { expected: usize, found: usize },
+    /// A unit symbol, prefix or exponent wasn't recognised.
+    UnknownUnit,
+    /// The unit measures something else, such as seconds given for a length.
+    WrongDimension,
+    /// The value can't be held exactly in SI units of the base type.
+    Conversion(ConversionError)
+}
+
+impl From<ConversionError> for ParseError {
+    fn from(e: ConversionError) -> Self {
+        ParseError::Conversion(e)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidNumber => write!(f, "invalid number"),
+            ParseError::Components { expected, found } => write!(f, "expected {} components, found {}", expected, found),
+            ParseError::UnknownUnit => write!(f, "unknown unit"),
+            ParseError::WrongDimension => write!(f, "unit has the wrong dimension"),
+            ParseError::Conversion(e) => write!(f, "{}", e)
+        }
+    }
+}
+
+/// Symbols that take a prefix, with their dimension and how many SI units one of them is.
+const BASES: [(&str, [i32; 3], i128, i128); 6] = [
+    ("m", [1, 0, 0], 1, 1),
+    ("g", [0, 1, 0], 1, 1000),
+    ("s", [0, 0, 1], 1, 1),
+    (NAMED[0].0, NAMED[0].1, 1, 1),
+    (NAMED[1].0, NAMED[1].1, 1, 1),
+    (NAMED[2].0, NAMED[2].1, 1, 1)
+];
+
+const PREFIXES: [(&str, u32, bool); 13] = [
+    ("da", 1, true),
+    ("T", 12, true),
+    ("G", 9, true),
+    ("M", 6, true),
+    ("k", 3, true),
+    ("h", 2, true),
+    ("d", 1, false),
+    ("c", 2, false),
+    ("m", 3, false),
+    ("µ", 6, false),
+    ("u", 6, false),
+    ("n", 9, false),
+    ("p", 12, false)
+];
+
+/// A unit read from text: its dimension and an exact ratio to the SI unit.
+#[derive(Copy, Clone)]
+struct Measure {
+    exponents: [i32; 3],
+    numerator: i128,
+    denominator: i128
+}
+
+const ONE: Measure = Measure { exponents: [0, 0, 0], numerator: 1, denominator: 1 };
+
+impl Measure {
+    fn times(self, other: Measure) -> Result<Measure, ParseError> {
+        let numerator = self.numerator.checked_mul(other.numerator).ok_or(ConversionError::Overflow)?;
+        let denominator = self.denominator.checked_mul(other.denominator).ok_or(ConversionError::Overflow)?;
+        let divisor = gcd(numerator, denominator);
+
+        let mut exponents = self.exponents;
+        for (exponent, other) in exponents.iter_mut().zip(other.exponents) {
+            *exponent = exponent.checked_add(other).ok_or(ConversionError::Overflow)?;
+        }
+
+        Ok(Measure {
+            exponents,
+            numerator: numerator / divisor,
+            denominator: denominator / divisor
+        })
+    }
+
+    fn power(self, exponent: i32) -> Result<Measure, ParseError> {
+        let n = exponent.unsigned_abs();
+        let numerator = self.numerator.checked_pow(n).ok_or(ConversionError::Overflow)?;
+        let denominator = self.denominator.checked_pow(n).ok_or(ConversionError::Overflow)?;
+        let (numerator, denominator) = if exponent < 0 { (denominator, numerator) } else { (numerator, denominator) };
+
+        let mut exponents = self.exponents;
+        for e in &mut exponents {
+            *e = e.checked_mul(exponent).ok_or(ConversionError::Overflow)?;
+        }
+
+        Ok(Measure {
+            exponents,
+            numerator,
+            denominator
+        })
+    }
+}
+
+fn parse_symbol(symbol: &str) -> Option<Measure> {
+    let base = |s: &str| BASES.iter()
+        .find(|(b, ..)| *b == s)
+        .map(|(_, exponents, numerator, denominator)| Measure { exponents: *exponents, numerator: *numerator, denominator: *denominator });
+
+    if let Some(measure) = base(symbol) {
+        return Some(measure);
+    }
+
+    PREFIXES.iter().find_map(|(prefix, power, up)| {
+        let measure = base(symbol.strip_prefix(prefix)?)?;
+        let factor = Measure { exponents: [0, 0, 0], numerator: 10i128.pow(*power), denominator: 1 };
+        let factor = if *up { factor } else { factor.power(-1).ok()? };
+        measure.times(factor).ok()
+    })
+}
+
+/// One symbol with an optional prefix and exponent, such as `km`, `s²` or `m^-1`.
+fn parse_term(term: &str) -> Result<Measure, ParseError> {
+    let (symbol, exponent) = if let Some(s) = term.strip_suffix('²') {
+        (s, 2)
+    } else if let Some(s) = term.strip_suffix('³') {
+        (s, 3)
+    } else if let Some((s, e)) = term.split_once('^') {
+        (s, e.parse().map_err(|_| ParseError::UnknownUnit)?)
+    } else {
+        (term, 1)
+    };
+
+    parse_symbol(symbol).ok_or(ParseError::UnknownUnit)?.power(exponent)
+}
+
+fn parse_product(text: &str, sign: i32) -> Result<Measure, ParseError> {
+    let text = text.trim();
+    if text == "1" {
+        return Ok(ONE);
+    }
+
+    text.split(['·', '*'])
+        .try_fold(ONE, |measure, term| measure.times(parse_term(term.trim())?.power(sign)?))
+}
+
+/// A whole unit such as `kg·m/s²`, where every symbol after the `/` divides.
+fn parse_unit(text: &str) -> Result<Measure, ParseError> {
+    match text.split_once('/') {
+        Some((_, denominator)) if denominator.contains('/') => Err(ParseError::UnknownUnit),
+        Some((numerator, denominator)) => parse_product(numerator, 1)?.times(parse_product(denominator, -1)?),
+        None => parse_product(text, 1)
+    }
+}
+
+/// Splits a leading number from the unit after it, with or without a space between them.
+fn split_number(text: &str) -> (&str, &str) {
+    let bytes = text.as_bytes();
+    let end = text.char_indices()
+        .find(|(i, c)| match c {
+            '0'..='9' | '.' | '+' | '-' => false,
+            'e' | 'E' => !matches!(bytes.get(i + 1), Some(b'0'..=b'9' | b'+' | b'-')),
+            _ => true
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(text.len());
+
+    text.split_at(end)
+}
+
+/// Reads quantities as they are displayed, such as `3.5 m` or `(1, 2) m/s`, with any SI prefix
+/// on `m`, `g`, `s`, `N`, `J` and `W`. The value is converted to SI units, so `250 ms` reads as
+/// a quarter of a second and fails for integer seconds.
+impl<const N: usize, B, U> FromStr for Quantity<N, B, U>
+    where B: BaseUnit + Scalable + FromStr, U: Dimensioned
+{
+    type Err = ParseError;
+
+    /// A number without a unit only reads as a dimensionless quantity.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s, ONE)
+    }
+}
+
+impl<const N: usize, B, U> Quantity<N, B, U>
+    where B: BaseUnit + Scalable + FromStr, U: Dimensioned
+{
+    /// Reads a quantity as `from_str` does, except that a bare number is taken to be in the
+    /// units of `S`, such as a universe's `Units`. So `3` reads as three tiles in a universe
+    /// measured in tiles, while `3 m` is still three metres.
+    pub fn parse_in<S: UnitSystem>(s: &str) -> Result<Self, ParseError> {
+        let (numerator, denominator) = system_ratio::<S, U>()?;
+        parse(s, Measure {
+            exponents: [U::LENGTH, U::MASS, U::TIME],
+            numerator,
+            denominator
+        })
+    }
+}
+
+/// Reads a quantity, taking a value written without a unit to be in `bare`.
+fn parse<const N: usize, B, U>(s: &str, bare: Measure) -> Result<Quantity<N, B, U>, ParseError>
+    where B: BaseUnit + Scalable + FromStr, U: Dimensioned
+{
+    let s = s.trim();
+    let (values, unit) = match s.strip_prefix('(') {
+        Some(rest) => rest.split_once(')').ok_or(ParseError::InvalidNumber)?,
+        None => split_number(s)
+    };
+
+    let measure = match unit.trim() {
+        "" => bare,
+        unit => parse_unit(unit)?
+    };
+    if measure.exponents != [U::LENGTH, U::MASS, U::TIME] {
+        return Err(ParseError::WrongDimension);
+    }
+
+    let mut components = [B::zero(); N];
+    let mut found = 0;
+    for text in values.split(',') {
+        if found < N {
+            let value: B = text.trim().parse().map_err(|_| ParseError::InvalidNumber)?;
+            components[found] = value.scale(measure.numerator, measure.denominator)?;
+        }
+        found += 1;
+    }
+
+    if found != N {
+        return Err(ParseError::Components { expected: N, found });
+    }
+
+    Ok(Quantity::from_components(&components))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::units::energy::Joules;
+    use crate::units::force::Newtons;
+    use crate::units::mass::Kilograms;
+    use crate::units::motion::{Acceleration, Velocity};
+    use crate::units::space::Meters;
+    use crate::units::time::Seconds;
+    use super::*;
+
+    #[test]
+    fn reads_numbers_and_units() {
+        assert_eq!("3.5 m".parse::<Meters<1, f64>>().map(|m| m.value()), Ok(3.5));
+        assert_eq!("(1, 2) m".parse::<Meters<2, f64>>().map(|m| m.values()), Ok([1.0, 2.0]));
+        assert_eq!("12 kg".parse::<Kilograms<i64>>().map(|m| m.value()), Ok(12));
+        assert_eq!("  -4e2m ".parse::<Meters<1, f64>>().map(|m| m.value()), Ok(-400.0));
+        assert_eq!("(3, -4) m/s".parse::<Velocity<2, f64>>().map(|v| v.values()), Ok([3.0, -4.0]));
+        assert_eq!("9.81 m/s²".parse::<Acceleration<1, f64>>().map(|a| a.value()), Ok(9.81));
+        assert_eq!("2 kg·m/s^2".parse::<Newtons<1, f64>>().map(|f| f.value()), Ok(2.0));
+        assert_eq!("5 J".parse::<Joules<f64>>().map(|e| e.value()), Ok(5.0));
+        assert_eq!("5 N·m".parse::<Joules<f64>>().map(|e| e.value()), Ok(5.0));
+    }
+
+    #[test]
+    fn reads_prefixes() {
+        assert_eq!("1.5 km".parse::<Meters<1, f64>>().map(|m| m.value()), Ok(1500.0));
+        assert_eq!("250 ms".parse::<Seconds<f64>>().map(|s| s.value()), Ok(0.25));
+        assert_eq!("500 g".parse::<Kilograms<f64>>().map(|m| m.value()), Ok(0.5));
+        assert_eq!("3 Mg".parse::<Kilograms<i64>>().map(|m| m.value()), Ok(3000));
+        assert_eq!("2 dam".parse::<Meters<1, i64>>().map(|m| m.value()), Ok(20));
+        assert_eq!("7 µs".parse::<Seconds<f64>>().map(|s| s.value()), Ok(7e-6));
+        assert_eq!("1 cm²·kN".parse::<Joules<f64>>().map(|e| e.value()), Err(ParseError::WrongDimension));
+        assert_eq!("(1, 0) km/ms".parse::<Velocity<2, i64>>().map(|v| v.values()), Ok([1_000_000, 0]));
+    }
+
+    #[test]
+    fn every_error() {
+        assert_eq!("1.2.3 m".parse::<Meters<1, f64>>(), Err(ParseError::InvalidNumber));
+        assert_eq!("(1, 2 m".parse::<Meters<2, f64>>(), Err(ParseError::InvalidNumber));
+        assert_eq!("(1, 2, 3) m".parse::<Meters<2, f64>>(), Err(ParseError::Components { expected: 2, found: 3 }));
+        assert_eq!("(1) m".parse::<Meters<2, f64>>(), Err(ParseError::Components { expected: 2, found: 1 }));
+        assert_eq!("3 ft".parse::<Meters<1, f64>>(), Err(ParseError::UnknownUnit));
+        assert_eq!("3 m/s/s".parse::<Acceleration<1, f64>>(), Err(ParseError::UnknownUnit));
+        assert_eq!("3 m^x".parse::<Meters<1, f64>>(), Err(ParseError::UnknownUnit));
+        assert_eq!("3 s".parse::<Meters<1, f64>>(), Err(ParseError::WrongDimension));
+        assert_eq!("3".parse::<Meters<1, f64>>(), Err(ParseError::WrongDimension));
+        assert_eq!("250 ms".parse::<Seconds<i64>>(), Err(ParseError::Conversion(ConversionError::Inexact)));
+        assert_eq!("10000000 Tm".parse::<Meters<1, i64>>(), Err(ParseError::Conversion(ConversionError::Overflow)));
+    }
+
+    #[test]
+    fn huge_exponents_overflow() {
+        let overflow = Some(ParseError::Conversion(ConversionError::Overflow));
+        assert_eq!("1 m^2147483647·m".parse::<Meters<1, f64>>().err(), overflow);
+        assert_eq!("1 m^-2147483648/m".parse::<Meters<1, f64>>().err(), overflow);
+        assert_eq!("1 s^1073741824·s^1073741824".parse::<Seconds<f64>>().err(), overflow);
+    }
+}
@@ -0,0 +1,306 @@
+use core::marker::PhantomData;
+use linalg::Vector;
+use crate::{Cross, Magnitude, Real, Scale};
+use crate::space::BaseUnit;
+use super::dimension::{DimensionProduct, DimensionQuotient, Dimensionless, Product, Quotient};
+
+/// `N` components of `B`, measured in the SI unit of dimension `U`.
+///
+/// Multiplying or dividing by a one-component quantity combines the dimensions, so
+/// `Meters<2, B> / Seconds<B>` is a two-dimensional velocity. The one-component quantity
+/// always goes on the right. Quantities only add to and subtract from quantities of the same
+/// dimension.
+pub struct Quantity<const N: usize, B: BaseUnit, U>(pub(crate) Vector<B, N>, PhantomData<U>);
+
+impl<const N: usize, B: BaseUnit, U> Quantity<N, B, U> {
+    pub fn from_components(p: &[B; N]) -> Self {
+        Self::from_vector(Vector::vector(*p))
+    }
+
+    pub(crate) fn from_vector(v: Vector<B, N>) -> Self {
+        Self(v, PhantomData)
+    }
+
+    pub fn zero() -> Self {
+        Self::from_components(&[B::zero(); N])
+    }
+
+    pub fn value_at(&self, i: usize) -> B {
+        *self.0.value_at(i, 0)
+    }
+
+    pub fn values(&self) -> [B; N] {
+        let mut values = [B::zero(); N];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = self.value_at(i);
+        }
+
+        values
+    }
+
+    /// Multiplies every component by a plain number.
+    pub fn scale(&self, factor: B) -> Self {
+        Self::from_vector(self.0 * factor)
+    }
+
+    /// Multiplies matching components together, such as the sides of a box into its area.
+    pub fn component_mul<U2>(&self, other: &Quantity<N, B, U2>) -> Quantity<N, B, Product<U, U2>>
+        where U: DimensionProduct<U2>
+    {
+        Quantity::from_vector(self.0.vec_multiply(other.0))
+    }
+
+    pub fn dot<U2>(&self, other: &Quantity<N, B, U2>) -> Quantity<1, B, Product<U, U2>>
+        where U: DimensionProduct<U2>
+    {
+        Quantity::new(self.0.dot(&other.0))
+    }
+
+    /// The squared length of the vector, which needs no square root to compare.
+    pub fn magnitude_squared(&self) -> Quantity<1, B, Product<U, U>>
+        where U: DimensionProduct<U>
+    {
+        self.dot(self)
+    }
+}
+
+impl<const N: usize, B: Real, U> Quantity<N, B, U> {
+    /// The length of the vector, in the same unit.
+    pub fn magnitude(&self) -> Quantity<1, B, U> {
+        Quantity::new(self.0.dot(&self.0).sqrt())
+    }
+
+    /// The vector scaled to length one, or zero if it has no length to scale.
+    pub fn direction(&self) -> Quantity<N, B, Dimensionless> {
+        let length = self.magnitude().value();
+        if length > B::zero() {
+            Quantity::from_vector(self.0 / length)
+        } else {
+            Quantity::zero()
+        }
+    }
+}
+
+impl<B: Real, U> Quantity<1, B, U> {
+    /// One over this quantity, or zero for zero, as used for the inverse mass of a body that
+    /// nothing can move.
+    pub fn inverse(&self) -> Quantity<1, B, Quotient<Dimensionless, U>>
+        where Dimensionless: DimensionQuotient<U>
+    {
+        let value = self.value();
+        Quantity::new(if value == B::zero() { B::zero() } else { B::one() / value })
+    }
+
+    /// The smaller of the two, preferring `self` when they can't be compared.
+    pub fn min(self, other: Self) -> Self {
+        Quantity::new(self.value().min(other.value()))
+    }
+
+    /// The larger of the two, preferring `self` when they can't be compared.
+    pub fn max(self, other: Self) -> Self {
+        Quantity::new(self.value().max(other.value()))
+    }
+}
+
+impl<B: BaseUnit, U> Quantity<2, B, U> {
+    /// The out-of-plane component of the cross product, such as the torque of a force in 2D.
+    pub fn cross<U2>(&self, other: &Quantity<2, B, U2>) -> Quantity<1, B, Product<U, U2>>
+        where U: DimensionProduct<U2>
+    {
+        Quantity::new((self.value_at(0) * other.value_at(1)) - (self.value_at(1) * other.value_at(0)))
+    }
+}
+
+impl<B: BaseUnit, U> Quantity<3, B, U> {
+    pub fn cross<U2>(&self, other: &Quantity<3, B, U2>) -> Quantity<3, B, Product<U, U2>>
+        where U: DimensionProduct<U2>
+    {
+        Quantity::from_vector(self.0.cross(&other.0))
+    }
+}
+
+impl<B: BaseUnit, U, U2> Cross<Quantity<2, B, U2>> for Quantity<2, B, U>
+    where U: DimensionProduct<U2>
+{
+    type Output = Quantity<1, B, Product<U, U2>>;
+    fn cross(&self, other: &Quantity<2, B, U2>) -> Self::Output { Quantity::<2, B, U>::cross(self, other) }
+}
+
+impl<B: BaseUnit, U, U2> Cross<Quantity<3, B, U2>> for Quantity<3, B, U>
+    where U: DimensionProduct<U2>
+{
+    type Output = Quantity<3, B, Product<U, U2>>;
+    fn cross(&self, other: &Quantity<3, B, U2>) -> Self::Output { Quantity::<3, B, U>::cross(self, other) }
+}
+
+impl<B: BaseUnit, U> Quantity<1, B, U> {
+    pub fn new(value: B) -> Self {
+        Self::from_components(&[value])
+    }
+
+    pub fn value(&self) -> B {
+        self.value_at(0)
+    }
+}
+
+impl<const N: usize, B: BaseUnit, U> linalg::Zero for Quantity<N, B, U> {
+    fn zero() -> Self {
+        Quantity::zero()
+    }
+}
+
+impl<const N: usize, B: BaseUnit, U> Clone for Quantity<N, B, U> {
+    fn clone(&self) -> Self { *self }
+}
+impl<const N: usize, B: BaseUnit, U> Copy for Quantity<N, B, U> {}
+
+/// Only scalars are ordered. Float quantities are only partially ordered, as `NaN` compares
+/// with nothing.
+impl<B: BaseUnit, U> core::cmp::PartialOrd for Quantity<1, B, U> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.value().partial_cmp(&other.value())
+    }
+}
+
+impl<B: BaseUnit + Ord, U> core::cmp::Ord for Quantity<1, B, U> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl<const N: usize, B: BaseUnit, U> Scale<B> for Quantity<N, B, U> {
+    fn scale(&self, factor: B) -> Self {
+        Quantity::scale(self, factor)
+    }
+}
+
+/// Vectors compare by their squared magnitude, and scalars by their absolute value.
+impl<const N: usize, B: BaseUnit, U> Magnitude for Quantity<N, B, U> {
+    fn magnitude_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.0.dot(&self.0).partial_cmp(&other.0.dot(&other.0))
+    }
+}
+
+impl<const N: usize, B: BaseUnit + Eq, U> core::cmp::Eq for Quantity<N, B, U> {}
+impl<const N: usize, B: BaseUnit, U> core::cmp::PartialEq for Quantity<N, B, U> {
+    fn eq(&self, other: &Self) -> bool {
+        (0..N).all(|i| self.value_at(i) == other.value_at(i))
+    }
+}
+
+impl<const N: usize, B: BaseUnit, U> core::ops::Add for Quantity<N, B, U> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output { Self::from_vector(self.0 + other.0) }
+}
+
+impl<const N: usize, B: BaseUnit, U> core::ops::Sub for Quantity<N, B, U> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output { Self::from_vector(self.0 - other.0) }
+}
+
+impl<const N: usize, B: BaseUnit, U> core::ops::AddAssign for Quantity<N, B, U> {
+    fn add_assign(&mut self, other: Self) { *self = *self + other; }
+}
+
+impl<const N: usize, B: BaseUnit, U> core::ops::SubAssign for Quantity<N, B, U> {
+    fn sub_assign(&mut self, other: Self) { *self = *self - other; }
+}
+
+impl<const N: usize, B: BaseUnit, U> core::ops::Neg for Quantity<N, B, U> {
+    type Output = Self;
+    fn neg(self) -> Self::Output { Self::zero() - self }
+}
+
+impl<const N: usize, B: BaseUnit, U, U2> core::ops::Mul<Quantity<1, B, U2>> for Quantity<N, B, U>
+    where U: DimensionProduct<U2>
+{
+    type Output = Quantity<N, B, Product<U, U2>>;
+    fn mul(self, other: Quantity<1, B, U2>) -> Self::Output { Quantity::from_vector(self.0 * other.value()) }
+}
+
+impl<const N: usize, B: BaseUnit, U, U2> core::ops::Div<Quantity<1, B, U2>> for Quantity<N, B, U>
+    where U: DimensionQuotient<U2>
+{
+    type Output = Quantity<N, B, Quotient<U, U2>>;
+    fn div(self, other: Quantity<1, B, U2>) -> Self::Output { Quantity::from_vector(self.0 / other.value()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::units::energy::{Joules, Watts};
+    use crate::units::force::{Impulse, Momentum, Newtons, Torque};
+    use crate::units::mass::Kilograms;
+    use crate::units::motion::{Acceleration, AngularVelocity, Velocity};
+    use crate::units::space::Meters;
+    use crate::units::time::Seconds;
+
+    #[test]
+    fn dividing_lengths_by_times_gives_velocities() {
+        let velocity: Velocity<2, f64> = Meters::from_components(&[6.0, -3.0]) / Seconds::new(3.0);
+        assert_eq!(velocity.values(), [2.0, -1.0]);
+
+        let acceleration: Acceleration<2, f64> = velocity / Seconds::new(0.5);
+        assert_eq!(acceleration.values(), [4.0, -2.0]);
+
+        let travelled: Meters<2, f64> = velocity * Seconds::new(2.0);
+        assert_eq!(travelled.values(), [4.0, -2.0]);
+    }
+
+    #[test]
+    fn derived_quantities_combine() {
+        let mass = Kilograms::<f64>::new(2.0);
+        let force: Newtons<2, f64> = Acceleration::from_components(&[3.0, 0.5]) * mass;
+        assert_eq!(force.values(), [6.0, 1.0]);
+
+        let momentum: Momentum<2, f64> = Velocity::from_components(&[1.0, -2.0]) * mass;
+        let impulse: Impulse<2, f64> = force * Seconds::new(0.5);
+        assert_eq!((momentum + impulse).values(), [5.0, -3.5]);
+
+        let work: Joules<f64> = force.dot(&Meters::from_components(&[2.0, 4.0]));
+        assert_eq!(work.value(), 16.0);
+        let power: Watts<f64> = work / Seconds::new(4.0);
+        assert_eq!(power.value(), 4.0);
+
+        let torque: Torque<1, f64> = Meters::from_components(&[0.0, 1.0]).cross(&force);
+        assert_eq!(torque.value(), -6.0);
+
+        let spin: AngularVelocity<1, f64> = AngularVelocity::new(3.0);
+        assert_eq!((spin * Seconds::new(2.0)).value(), 6.0);
+    }
+
+    #[test]
+    fn float_masses_add_scale_and_order() {
+        fn total<M: crate::Mass>(masses: &[M]) -> M {
+            masses.iter().fold(M::zero(), |sum, &m| sum + m)
+        }
+
+        let (light, heavy) = (Kilograms::<f32>::new(0.25), Kilograms::<f32>::new(1.5));
+        assert_eq!(total(&[light, heavy, light]).value(), 2.0);
+        assert_eq!((heavy - light).value(), 1.25);
+        assert_eq!(light.scale(4.0).value(), 1.0);
+        assert_eq!((heavy / light).value(), 6.0);
+        assert_eq!(light.inverse().value(), 4.0);
+        assert_eq!(Kilograms::<f32>::zero().inverse().value(), 0.0);
+
+        assert!(light < heavy && heavy >= Kilograms::new(1.5));
+        assert_eq!(light.max(heavy), heavy);
+        assert_eq!(light.partial_cmp(&Kilograms::new(f32::NAN)), None);
+    }
+
+    #[test]
+    fn equal_magnitudes_are_not_equal_points() {
+        use core::cmp::Ordering;
+        use crate::Magnitude;
+
+        let (a, b) = (Meters::<2, f32>::from_components(&[3.0, 4.0]), Meters::<2, f32>::from_components(&[-5.0, 0.0]));
+        assert_ne!(a, b);
+        assert_eq!(a.magnitude_cmp(&b), Some(Ordering::Equal));
+        assert_eq!(a.magnitude_cmp(&a.scale(0.5)), Some(Ordering::Greater));
+        assert_eq!(a.magnitude_cmp(&Meters::from_components(&[f32::NAN, 0.0])), None);
+
+        // Scalars compare by size, so a negative time is smaller but may be longer
+        let (before, after) = (Seconds::<f32>::new(-2.0), Seconds::<f32>::new(1.0));
+        assert!(before < after);
+        assert_eq!(before.magnitude_cmp(&after), Some(Ordering::Greater));
+    }
+}
@@ -1,9 +1,11 @@
-use linalg::Vector;
 use crate::Space;
 use crate::space::{BaseUnit, ObservableSpace};
+use crate::units::dimension::{Area, Length, Volume};
+use crate::units::Quantity;
 
-#[derive(Copy, Clone)]
-pub struct Meters<const DIMENSIONS: usize, B: BaseUnit>(Vector<B, DIMENSIONS>);
+pub type Meters<const DIMENSIONS: usize, B> = Quantity<DIMENSIONS, B, Length>;
+pub type SquareMeters<B> = Quantity<1, B, Area>;
+pub type CubicMeters<B> = Quantity<1, B, Volume>;
 
 impl<const D: usize, B: BaseUnit> Space for Meters<D, B> {
     const DIMENSIONS: usize = D;
@@ -12,65 +14,15 @@ impl<const D: usize, B: BaseUnit> Space for Meters<D, B> {
 
 impl<const D: usize, B: BaseUnit> ObservableSpace<D> for Meters<D, B> {
     fn new(p: &[B; D]) -> Self {
-        Self(Vector::vector(*p))
+        Self::from_components(p)
     }
 
     fn components(&self) -> [Self; D] {
-        let mut comps: [Self; D] = [Self::new(&[B::zero(); D]); D];
-        for i in 0..D {
-            comps[i].0.set(0, i, *self.0.value_at(0, i))
+        let mut comps: [Self; D] = [Self::zero(); D];
+        for (i, comp) in comps.iter_mut().enumerate() {
+            comp.0.set(i, 0, self.value_at(i))
         }
 
         comps
     }
 }
-
-impl<const D: usize, B: BaseUnit> core::cmp::PartialOrd for Meters<D, B> {
-    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-impl<const D: usize, B: BaseUnit> core::cmp::Ord for Meters<D, B> {
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        let mut magnitude_a = B::zero();
-        let mut magnitude_b = B::zero();
-        for i in 0..D {
-            magnitude_a = magnitude_a + (*self.0.value_at(0, i) * *self.0.value_at(0, i));
-            magnitude_b = magnitude_b + (*other.0.value_at(0, i) * *other.0.value_at(0, i));
-        }
-
-        magnitude_a.cmp(&magnitude_b)
-    }
-}
-impl<const D: usize, B: BaseUnit> core::cmp::Eq for Meters<D, B> {}
-impl<const D: usize, B: BaseUnit> core::cmp::PartialEq for Meters<D, B> {
-    fn eq(&self, other: &Self) -> bool {
-        for i in 0..D {
-            if *self.0.value_at(0, i) != *other.0.value_at(0, i) {
-                return false
-            }
-        }
-
-        true
-    }
-}
-
-impl<const D: usize, B: BaseUnit> core::ops::Add for Meters<D, B> {
-    type Output = Self;
-    fn add(self, other: Self) -> Self::Output { Self(self.0 + other.0) }
-}
-
-impl<const D: usize, B: BaseUnit> core::ops::Sub for Meters<D, B> {
-    type Output = Self;
-    fn sub(self, other: Self) -> Self::Output { Self(self.0 - other.0) }
-}
-
-impl<const D: usize, B: BaseUnit> core::ops::Div for Meters<D, B> {
-    type Output = Self;
-    fn div(self, other: Self) -> Self::Output { Self(self.0 / other.0) }
-}
-
-impl<const D: usize, B: BaseUnit> core::ops::Mul for Meters<D, B> {
-    type Output = Self;
-    fn mul(self, other: Self) -> Self::Output { Self(self.0.vec_multiply(other.0)) }
-}
\ No newline at end of file
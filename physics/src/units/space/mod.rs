@@ -1,2 +1,2 @@
 pub mod meters;
-pub use meters::Meters;
\ No newline at end of file
+pub use meters::{CubicMeters, Meters, SquareMeters};
@@ -0,0 +1,130 @@
+use core::marker::PhantomData;
+use crate::Real;
+use crate::rotation::Rotation3;
+use crate::space::BaseUnit;
+use super::Quantity;
+use super::dimension::{DimensionProduct, DimensionQuotient, Dimensionless, Product, Quotient};
+
+/// A 3×3 matrix of `B` in the SI unit of dimension `U`, which maps 3D quantities to 3D
+/// quantities, such as angular velocity to angular momentum.
+pub struct Tensor<B: BaseUnit, U> {
+    rows: [[B; 3]; 3],
+    unit: PhantomData<U>
+}
+
+impl<B: BaseUnit, U> Tensor<B, U> {
+    pub fn new(rows: [[B; 3]; 3]) -> Self {
+        Self {
+            rows,
+            unit: PhantomData
+        }
+    }
+
+    pub fn diagonal(d: [B; 3]) -> Self {
+        let z = B::zero();
+        Self::new([[d[0], z, z], [z, d[1], z], [z, z, d[2]]])
+    }
+
+    pub fn rows(&self) -> [[B; 3]; 3] {
+        self.rows
+    }
+
+    pub fn transpose(&self) -> Self {
+        let r = self.rows;
+        Self::new([0, 1, 2].map(|i| [r[0][i], r[1][i], r[2][i]]))
+    }
+
+    fn product(a: &[[B; 3]; 3], b: &[[B; 3]; 3]) -> [[B; 3]; 3] {
+        [0, 1, 2].map(|i| [0, 1, 2].map(|j| a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j]))
+    }
+}
+
+impl<B: Real, U> Tensor<B, U> {
+    /// The same tensor expressed in a frame turned by `rotation`: `R T Rᵀ`.
+    pub fn rotated(&self, rotation: &Rotation3<B>) -> Self {
+        let r = rotation.matrix();
+        let rt = [0, 1, 2].map(|i| [r[0][i], r[1][i], r[2][i]]);
+        Self::new(Self::product(&Self::product(&r, &self.rows), &rt))
+    }
+
+    /// The inverse tensor, or zero if this one has no inverse, as for a body that can't turn.
+    pub fn inverse(&self) -> Tensor<B, Quotient<Dimensionless, U>>
+        where Dimensionless: DimensionQuotient<U>
+    {
+        let m = self.rows;
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+
+        let adjugate = [
+            [cofactor(1, 2, 1, 2), -cofactor(0, 2, 1, 2), cofactor(0, 1, 1, 2)],
+            [-cofactor(1, 2, 0, 2), cofactor(0, 2, 0, 2), -cofactor(0, 1, 0, 2)],
+            [cofactor(1, 2, 0, 1), -cofactor(0, 2, 0, 1), cofactor(0, 1, 0, 1)]
+        ];
+
+        let determinant = m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+        if determinant == B::zero() || !determinant.is_finite() {
+            return Tensor::new([[B::zero(); 3]; 3]);
+        }
+
+        Tensor::new(adjugate.map(|row| row.map(|v| v / determinant)))
+    }
+}
+
+impl<B: BaseUnit, U> linalg::Zero for Tensor<B, U> {
+    fn zero() -> Self {
+        Self::new([[B::zero(); 3]; 3])
+    }
+}
+
+impl<B: BaseUnit, U> Clone for Tensor<B, U> {
+    fn clone(&self) -> Self { *self }
+}
+impl<B: BaseUnit, U> Copy for Tensor<B, U> {}
+
+impl<B: BaseUnit, U, U2> core::ops::Mul<Quantity<3, B, U2>> for Tensor<B, U>
+    where U: DimensionProduct<U2>
+{
+    type Output = Quantity<3, B, Product<U, U2>>;
+
+    fn mul(self, v: Quantity<3, B, U2>) -> Self::Output {
+        let v = v.values();
+        Quantity::from_components(&self.rows.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::units::force::InertiaTensor;
+    use super::*;
+
+    fn close(a: [[f64; 3]; 3], b: [[f64; 3]; 3]) -> bool {
+        (0..3).all(|i| (0..3).all(|j| (a[i][j] - b[i][j]).abs() < 1e-12))
+    }
+
+    const IDENTITY: [[f64; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+    #[test]
+    fn inverse_undoes_the_tensor() {
+        let tensor = InertiaTensor::new([[2.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]]);
+        let inverse = tensor.inverse();
+        assert!(close(Tensor::<f64, ()>::product(&inverse.rows(), &tensor.rows()), IDENTITY));
+        assert!(close(Tensor::<f64, ()>::product(&tensor.rows(), &inverse.rows()), IDENTITY));
+    }
+
+    #[test]
+    fn singular_tensors_invert_to_zero() {
+        let flat = InertiaTensor::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 0.0, 1.0]]);
+        assert_eq!(flat.inverse().rows(), [[0.0; 3]; 3]);
+        assert_eq!(InertiaTensor::<f64>::diagonal([1.0, 0.0, 2.0]).inverse().rows(), [[0.0; 3]; 3]);
+    }
+
+    #[test]
+    fn rotating_turns_the_axes() {
+        let tensor = InertiaTensor::diagonal([1.0, 2.0, 3.0]);
+        let quarter = Rotation3::from_axis_angle([0.0, 0.0, 1.0], core::f64::consts::FRAC_PI_2);
+        assert!(close(tensor.rotated(&quarter).rows(), [[2.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 3.0]]));
+
+        // Turning and inverting can go in either order
+        let turned = Rotation3::from_axis_angle([1.0, 2.0, -1.0], 0.4);
+        assert!(close(tensor.rotated(&turned).inverse().rows(), tensor.inverse().rotated(&turned).rows()));
+    }
+}
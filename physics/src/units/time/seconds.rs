@@ -1,10 +1,16 @@
 use crate::{Space, Time};
 use crate::space::{BaseUnit, ObservableSpace};
+use crate::units::dimension;
+use crate::units::Quantity;
 
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
-pub struct Seconds<B: BaseUnit>(B);
+pub type Seconds<B> = Quantity<1, B, dimension::Time>;
+
+impl<B: BaseUnit> Time for Seconds<B> {
+    fn seconds(&self) -> B {
+        self.value()
+    }
+}
 
-impl<B: BaseUnit> Time for Seconds<B> {}
 impl<B: BaseUnit> Space for Seconds<B> {
     const DIMENSIONS: usize = 1;
     type Base = B;
@@ -12,30 +18,10 @@ impl<B: BaseUnit> Space for Seconds<B> {
 
 impl<B: BaseUnit> ObservableSpace<1> for Seconds<B> {
     fn new(p: &[B; 1]) -> Self {
-        Self(p[0])
+        Self::from_components(p)
     }
 
     fn components(&self) -> [Self; 1] {
-        return [*self]
+        [*self]
     }
 }
-
-impl<B: BaseUnit> core::ops::Add for Seconds<B> {
-    type Output = Self;
-    fn add(self, other: Self) -> Self::Output { Self(self.0 + other.0) }
-}
-
-impl<B: BaseUnit> core::ops::Sub for Seconds<B> {
-    type Output = Self;
-    fn sub(self, other: Self) -> Self::Output { Self(self.0 - other.0) }
-}
-
-impl<B: BaseUnit> core::ops::Div for Seconds<B> {
-    type Output = Self;
-    fn div(self, other: Self) -> Self::Output { Self(self.0 / other.0) }
-}
-
-impl<B: BaseUnit> core::ops::Mul for Seconds<B> {
-    type Output = Self;
-    fn mul(self, other: Self) -> Self::Output { Self(self.0 * other.0) }
-}
\ No newline at end of file
@@ -7,7 +7,7 @@ extern crate winit;
 use ash::vk;
 use std::{cell::RefCell, ffi::CString};
 use models::{Renderable, Vertex, Modelable};
-use world::ModelMatrix;
+use world::{JointPalette, ModelMatrix};
 
 #[macro_use]
 mod window;
@@ -129,15 +129,6 @@ pub struct Material {
     pub id: u32,
 }
 
-#[derive(Copy, Clone, Debug)]
-pub struct Animation {
-    pub start_transform: [[f32; 4]; 4],
-    pub end_transform: [[f32; 4]; 4],
-    pub start_time: u32,
-    pub end_time: u32,
-    pub copied: bool
-}
-
 #[macro_use]
 mod macros;
 
@@ -222,11 +213,23 @@ fn main() {
         .memory_flags(vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT)
         .load(&device_id.device().device, &models_ref.borrow().materials());
 
+    // None of the models are skinned yet, so every joint leaves its vertices in place
+    let palette_buffer: Buffer<JointPalette> = Buffer::new(&device_props)
+        .stages(vk::ShaderStageFlags::VERTEX)
+        .binding(2)
+        .usage(vk::BufferUsageFlags::UNIFORM_BUFFER)
+        .descriptor_type(vk::DescriptorType::UNIFORM_BUFFER)
+        .sharing_mode(vk::SharingMode::EXCLUSIVE)
+        .memory_flags(vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT)
+        .load(&device_id.device().device, &[JointPalette::new(&[])]);
+
     let vertex_input: Buffer<Vertex> = Buffer::new(&device_props)
         .usage(vk::BufferUsageFlags::VERTEX_BUFFER)
         .sharing_mode(vk::SharingMode::EXCLUSIVE)
         .memory_flags(vk::MemoryPropertyFlags::HOST_VISIBLE | vk::MemoryPropertyFlags::HOST_COHERENT)
         .vertex_input_attribute(0, 0, offset_of!(Vertex, pos) as u32, vk::Format::R32G32B32_SFLOAT)
+        .vertex_input_attribute(1, 0, offset_of!(Vertex, joints) as u32, vk::Format::R32G32B32A32_UINT)
+        .vertex_input_attribute(2, 0, offset_of!(Vertex, weights) as u32, vk::Format::R32G32B32A32_SFLOAT)
         .load(&device_id.device().device, models_ref.borrow().vertices().as_slice());
 
     let index_buffer: Buffer<u32> = Buffer::new(&device_props)
@@ -250,6 +253,7 @@ fn main() {
     let per_pass_buffers = BufferSet::new()
         .add(&transformation_buffer)
         .add(&material_buffer)
+        .add(&palette_buffer)
         .allocate(&device_id.device().device);
 
     //
@@ -258,7 +262,7 @@ fn main() {
     let shader_stages = shaders::Stages::new(&[
         shaders::Stage::new(
             vk::ShaderStageFlags::VERTEX, 
-            glsl_to_shader!(device_id, "./shaders/triangle.vert", shaderc::ShaderKind::Vertex)
+            glsl_to_shader!(device_id, "./shaders/skinned.vert", shaderc::ShaderKind::Vertex)
         ),
         
         shaders::Stage::new(
@@ -600,6 +604,7 @@ fn main() {
     engine.cleanup(&vertex_input);
     engine.cleanup(&transformation_buffer);
     engine.cleanup(&material_buffer);
+    engine.cleanup(&palette_buffer);
     engine.cleanup(&engine);
 
     println!("Cleaned up!!");
@@ -0,0 +1,66 @@
+#version 400
+#extension GL_ARB_separate_shader_objects : enable
+#extension GL_ARB_shading_language_420pack : enable
+
+// Must match world::skeleton::MAX_JOINTS
+#define MAX_JOINTS 64
+
+layout (location = 0) in vec3 pos;
+layout (location = 1) in uvec4 joints;
+layout (location = 2) in vec4 weights;
+
+layout(binding = 0) uniform Transformation {
+    mat4 matrix;
+} transformation;
+
+// world::JointPalette
+layout(binding = 2) uniform JointPalette {
+    mat4 matrices[MAX_JOINTS];
+} palette;
+
+layout (push_constant) uniform PushConstants {
+    uint time;
+} pcs;
+
+mat4 PerspectiveMatrix(float l, float r, float t, float b, float n, float f) {
+    return transpose(mat4(
+        (2.0*n)/(r-l), 0.0, (r+l)/(r-l), 0.0,
+        0.0, (2.0*n)/(b-t), (b+t)/(b-t), 0.0,
+        0.0, 0.0, n/(f-n), (n*f)/(f-n),
+        0.0, 0.0, -n, 1.0
+    ));
+}
+
+mat4 P = PerspectiveMatrix(-1, 1, -1, 1, 5, 0);
+
+mat4 V = transpose(mat4(
+    1.0, 0.0, 0.0, 0.0,
+    0.0, 1.0, 0.0, 0.0,
+    0.0, 0.0, 1.0, 0.0,
+    0.0, 0.0, 0.0, 1.0
+));
+
+// Joints past the palette leave the vertex in place, as unused slots do
+mat4 JointMatrix(uint joint) {
+    return joint < uint(MAX_JOINTS) ? palette.matrices[joint] : mat4(1.0);
+}
+
+// Same blend as models::skinning::skin, so CPU and GPU results agree
+mat4 SkinMatrix() {
+    float total = weights.x + weights.y + weights.z + weights.w;
+    if (total == 0.0) {
+        return mat4(1.0);
+    }
+
+    mat4 skin = weights.x * JointMatrix(joints.x)
+        + weights.y * JointMatrix(joints.y)
+        + weights.z * JointMatrix(joints.z)
+        + weights.w * JointMatrix(joints.w);
+
+    return skin / total;
+}
+
+void main() {
+    mat4 M = transformation.matrix;
+    gl_Position = P * V * M * SkinMatrix() * vec4(pos, 1.0);
+}
@@ -0,0 +1,168 @@
+use crate::Pose;
+use super::{Clip, LoopMode};
+
+/// A clip playing from a given start time, at a given speed.
+#[derive(Copy, Clone, Debug)]
+pub struct Playback<'a> {
+    pub clip: &'a Clip,
+    pub start: f32,
+    pub speed: f32,
+    pub mode: LoopMode
+}
+
+impl<'a> Playback<'a> {
+    pub fn new(clip: &'a Clip, start: f32, mode: LoopMode) -> Self {
+        Self {
+            clip,
+            start,
+            speed: 1.0,
+            mode
+        }
+    }
+
+    pub fn speed(self, speed: f32) -> Self {
+        Self {
+            speed,
+            ..self
+        }
+    }
+
+    /// Where in the clip playback has reached at absolute `time`.
+    pub fn local_time(&self, time: f32) -> f32 {
+        self.mode.local_time((time - self.start) * self.speed, self.clip.duration())
+    }
+
+    /// The pose at absolute `time`, starting from `base` for anything the clip doesn't drive.
+    pub fn sample(&self, time: f32, base: &Pose) -> Pose {
+        let mut pose = base.clone();
+        self.clip.sample(self.local_time(time), &mut pose);
+        pose
+    }
+}
+
+/// Fades from one playing clip to another over `duration`, starting at `start`.
+#[derive(Copy, Clone, Debug)]
+pub struct CrossFade<'a> {
+    pub from: Playback<'a>,
+    pub to: Playback<'a>,
+    pub start: f32,
+    pub duration: f32
+}
+
+impl<'a> CrossFade<'a> {
+    pub fn new(from: Playback<'a>, to: Playback<'a>, start: f32, duration: f32) -> Self {
+        Self {
+            from,
+            to,
+            start,
+            duration
+        }
+    }
+
+    /// How much of `to` is showing at `time`, from `0.0` before the fade to `1.0` after it.
+    pub fn weight(&self, time: f32) -> f32 {
+        if self.duration <= 0.0 {
+            return if time >= self.start { 1.0 } else { 0.0 };
+        }
+
+        ((time - self.start) / self.duration).clamp(0.0, 1.0)
+    }
+
+    pub fn sample(&self, time: f32, base: &Pose) -> Pose {
+        match self.weight(time) {
+            w if w <= 0.0 => self.from.sample(time, base),
+            w if w >= 1.0 => self.to.sample(time, base),
+            w => self.from.sample(time, base).blend(&self.to.sample(time, base), w)
+        }
+    }
+}
+
+impl Pose {
+    /// Interpolates every joint towards `other`, by `t` from `0.0` (this pose) to `1.0`.
+    pub fn blend(&self, other: &Pose, t: f32) -> Pose {
+        let locals = self.locals.iter()
+            .zip(&other.locals)
+            .map(|(a, b)| a.lerp(b, t))
+            .collect();
+
+        let targets = self.weights.len().max(other.weights.len());
+        let weights = (0..targets).map(|i| {
+            let a = self.weights.get(i).map(Vec::as_slice).unwrap_or(&[]);
+            let b = other.weights.get(i).map(Vec::as_slice).unwrap_or(&[]);
+            (0..a.len().max(b.len()))
+                .map(|k| {
+                    let (x, y) = (a.get(k).copied().unwrap_or(0.0), b.get(k).copied().unwrap_or(0.0));
+                    x + (y - x) * t
+                })
+                .collect()
+        }).collect();
+
+        Pose {
+            locals,
+            weights
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::{Channel, Interpolation, Property};
+    use crate::{Skeleton, Transform};
+
+    fn slide(name: &str, from: f32, to: f32) -> Clip {
+        Clip::new(name).channel(Channel::new(0, Property::Translation, Interpolation::Linear, vec![0.0, 1.0], vec![from, 0.0, 0.0, to, 0.0, 0.0]))
+    }
+
+    fn x(pose: &Pose) -> f32 {
+        *pose.locals[0].translation.value_at(0, 0)
+    }
+
+    #[test]
+    fn playback_offsets_and_scales_time() {
+        let clip = slide("slide", 0.0, 1.0);
+        let base = Skeleton::new().joint("root", None, Transform::default()).rest_pose();
+        let playback = Playback::new(&clip, 2.0, LoopMode::Loop).speed(0.5);
+
+        assert_eq!(playback.local_time(2.0), 0.0);
+        assert_eq!(playback.local_time(3.0), 0.5);
+        assert_eq!(playback.local_time(4.5), 0.25);
+        assert!((x(&playback.sample(3.0, &base)) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cross_fade_weights() {
+        let (a, b) = (slide("a", 0.0, 0.0), slide("b", 4.0, 4.0));
+        let fade = CrossFade::new(Playback::new(&a, 0.0, LoopMode::Loop), Playback::new(&b, 0.0, LoopMode::Loop), 1.0, 2.0);
+
+        assert_eq!(fade.weight(0.0), 0.0);
+        assert_eq!(fade.weight(1.0), 0.0);
+        assert_eq!(fade.weight(1.5), 0.25);
+        assert_eq!(fade.weight(2.0), 0.5);
+        assert_eq!(fade.weight(3.0), 1.0);
+        assert_eq!(fade.weight(9.0), 1.0);
+
+        let instant = CrossFade::new(fade.from, fade.to, 1.0, 0.0);
+        assert_eq!(instant.weight(0.5), 0.0);
+        assert_eq!(instant.weight(1.0), 1.0);
+    }
+
+    #[test]
+    fn cross_fade_blends_poses() {
+        let (a, b) = (slide("a", 0.0, 0.0), slide("b", 4.0, 4.0));
+        let base = Skeleton::new().joint("root", None, Transform::default()).rest_pose();
+        let fade = CrossFade::new(Playback::new(&a, 0.0, LoopMode::Loop), Playback::new(&b, 0.0, LoopMode::Loop), 1.0, 2.0);
+
+        for (time, expected) in [(0.5, 0.0), (1.5, 1.0), (2.0, 2.0), (2.5, 3.0), (4.0, 4.0)] {
+            assert!((x(&fade.sample(time, &base)) - expected).abs() < 1e-5, "at {time}");
+        }
+    }
+
+    #[test]
+    fn blend_pads_missing_weights() {
+        let a = Pose { locals: vec![], weights: vec![vec![1.0]] };
+        let b = Pose { locals: vec![], weights: vec![vec![0.0, 1.0], vec![2.0]] };
+
+        assert_eq!(a.blend(&b, 0.5).weights, vec![vec![0.5, 0.5], vec![1.0]]);
+    }
+}
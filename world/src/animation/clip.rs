@@ -0,0 +1,328 @@
+use linalg::Vector;
+use crate::{Pose, Quaternion};
+
+/// How values are read between two keyframes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Holds each keyframe's value until the next one.
+    Step,
+    /// Straight lines between keyframes, or slerp for rotations.
+    Linear,
+    /// Hermite curves through each keyframe, using its stored in and out tangents.
+    CubicSpline
+}
+
+/// The part of a target that a `Channel` drives.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Property {
+    Translation,
+    Rotation,
+    Scale,
+    /// Morph target weights, with the number of morph targets.
+    Weights(usize)
+}
+
+impl Property {
+    /// How many floats make up one value of this property.
+    pub fn components(&self) -> usize {
+        match self {
+            Property::Translation | Property::Scale => 3,
+            Property::Rotation => 4,
+            Property::Weights(n) => *n
+        }
+    }
+}
+
+/// Keyframes for one property of one target.
+///
+/// `values` is laid out as in glTF: one value per keyframe for `Step` and `Linear`, and an
+/// in-tangent, value, out-tangent triple per keyframe for `CubicSpline`. Rotations are stored
+/// as `x, y, z, w` quaternions.
+#[derive(Clone, Debug)]
+pub struct Channel {
+    pub target: usize,
+    pub property: Property,
+    pub interpolation: Interpolation,
+    times: Vec<f32>,
+    values: Vec<f32>
+}
+
+impl Channel {
+    pub fn new(target: usize, property: Property, interpolation: Interpolation, times: Vec<f32>, values: Vec<f32>) -> Self {
+        let per_key = match interpolation {
+            Interpolation::CubicSpline => 3,
+            _ => 1
+        };
+
+        assert!(!times.is_empty(), "A channel needs at least one keyframe");
+        assert!(times.windows(2).all(|w| w[0] <= w[1]), "Keyframe times must be ascending");
+        assert_eq!(values.len(), times.len() * per_key * property.components(), "Keyframe values do not match their times");
+
+        Self {
+            target,
+            property,
+            interpolation,
+            times,
+            values
+        }
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.times[self.times.len() - 1]
+    }
+
+    /// The value at `time`, holding the first and last keyframes outside the channel's range.
+    pub fn sample(&self, time: f32) -> Vec<f32> {
+        let n = self.property.components();
+        let last = self.times.len() - 1;
+
+        let next = self.times.partition_point(|t| *t <= time);
+        if next == 0 {
+            return self.value(0).to_vec();
+        }
+        if next > last {
+            return self.value(last).to_vec();
+        }
+
+        let prev = next - 1;
+        let dt = self.times[next] - self.times[prev];
+        let t = if dt > 0.0 { (time - self.times[prev]) / dt } else { 0.0 };
+
+        let mut result = match self.interpolation {
+            Interpolation::Step => self.value(prev).to_vec(),
+            Interpolation::Linear if self.property == Property::Rotation => {
+                let a = self.quaternion(self.value(prev));
+                let b = self.quaternion(self.value(next));
+                let q = a.slerp(&b, t);
+                vec![q.x, q.y, q.z, q.w]
+            },
+            Interpolation::Linear => {
+                let (a, b) = (self.value(prev), self.value(next));
+                (0..n).map(|i| a[i] + (b[i] - a[i]) * t).collect()
+            },
+            Interpolation::CubicSpline => {
+                let (t2, t3) = (t * t, t * t * t);
+                let (v0, b0) = (self.value(prev), self.out_tangent(prev));
+                let (v1, a1) = (self.value(next), self.in_tangent(next));
+
+                (0..n).map(|i| {
+                    (2.0 * t3 - 3.0 * t2 + 1.0) * v0[i]
+                        + (t3 - 2.0 * t2 + t) * dt * b0[i]
+                        + (-2.0 * t3 + 3.0 * t2) * v1[i]
+                        + (t3 - t2) * dt * a1[i]
+                }).collect()
+            }
+        };
+
+        if self.property == Property::Rotation {
+            let q = self.quaternion(&result).normalize();
+            result = vec![q.x, q.y, q.z, q.w];
+        }
+
+        result
+    }
+
+    fn key(&self, k: usize, part: usize) -> &[f32] {
+        let n = self.property.components();
+        let start = match self.interpolation {
+            Interpolation::CubicSpline => (k * 3 + part) * n,
+            _ => k * n
+        };
+
+        &self.values[start..start + n]
+    }
+
+    fn in_tangent(&self, k: usize) -> &[f32] { self.key(k, 0) }
+    fn value(&self, k: usize) -> &[f32] { self.key(k, 1) }
+    fn out_tangent(&self, k: usize) -> &[f32] { self.key(k, 2) }
+
+    fn quaternion(&self, v: &[f32]) -> Quaternion {
+        Quaternion::new(v[0], v[1], v[2], v[3])
+    }
+
+    /// Writes the value at `time` into the matching part of `pose`. Channels aimed at a joint the
+    /// pose doesn't have, such as one from a clip made for a bigger skeleton, are skipped.
+    pub fn apply(&self, time: f32, pose: &mut Pose) {
+        if self.target >= pose.locals.len() {
+            return;
+        }
+
+        let v = self.sample(time);
+        match self.property {
+            Property::Translation => pose.locals[self.target].translation = Vector::vector([v[0], v[1], v[2]]),
+            Property::Scale => pose.locals[self.target].scale = Vector::vector([v[0], v[1], v[2]]),
+            Property::Rotation => pose.locals[self.target].rotation = self.quaternion(&v),
+            Property::Weights(_) => {
+                if pose.weights.len() <= self.target {
+                    pose.weights.resize(self.target + 1, vec![]);
+                }
+                pose.weights[self.target] = v;
+            }
+        }
+    }
+}
+
+/// What happens when playback runs past the end of a clip.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LoopMode {
+    /// Holds the final frame.
+    Once,
+    /// Starts again from the beginning.
+    Loop,
+    /// Plays backwards to the beginning, then forwards again.
+    PingPong
+}
+
+impl LoopMode {
+    /// Maps a time since playback started onto the clip's own timeline.
+    pub fn local_time(&self, time: f32, duration: f32) -> f32 {
+        if duration <= 0.0 {
+            return 0.0;
+        }
+
+        match self {
+            LoopMode::Once => time.clamp(0.0, duration),
+            LoopMode::Loop => time.rem_euclid(duration),
+            LoopMode::PingPong => {
+                let t = time.rem_euclid(duration * 2.0);
+                if t > duration { duration * 2.0 - t } else { t }
+            }
+        }
+    }
+}
+
+/// A named set of channels that play together.
+#[derive(Clone, Debug)]
+pub struct Clip {
+    pub name: String,
+    channels: Vec<Channel>,
+    duration: f32
+}
+
+impl Clip {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            channels: vec![],
+            duration: 0.0
+        }
+    }
+
+    pub fn channel(self, channel: Channel) -> Self {
+        let duration = self.duration.max(channel.duration());
+        let mut channels = self.channels;
+        channels.push(channel);
+
+        Self {
+            channels,
+            duration,
+            ..self
+        }
+    }
+
+    pub fn channels(&self) -> &[Channel] {
+        &self.channels
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// Writes every channel's value at clip-local `time` into `pose`. Parts of the pose that no
+    /// channel drives are left as they were.
+    pub fn sample(&self, time: f32, pose: &mut Pose) {
+        for channel in &self.channels {
+            channel.apply(time, pose);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Skeleton, Transform};
+
+    fn close(a: &[f32], b: &[f32]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-5)
+    }
+
+    fn ramp(interpolation: Interpolation) -> Channel {
+        Channel::new(0, Property::Translation, interpolation, vec![1.0, 3.0], vec![0.0, 0.0, 0.0, 4.0, 2.0, -2.0])
+    }
+
+    #[test]
+    fn step_holds_each_key() {
+        let channel = ramp(Interpolation::Step);
+
+        assert!(close(&channel.sample(1.0), &[0.0, 0.0, 0.0]));
+        assert!(close(&channel.sample(2.9), &[0.0, 0.0, 0.0]));
+        assert!(close(&channel.sample(3.0), &[4.0, 2.0, -2.0]));
+    }
+
+    #[test]
+    fn linear_interpolates_and_clamps() {
+        let channel = ramp(Interpolation::Linear);
+
+        assert!(close(&channel.sample(0.0), &[0.0, 0.0, 0.0]));
+        assert!(close(&channel.sample(1.5), &[1.0, 0.5, -0.5]));
+        assert!(close(&channel.sample(2.0), &[2.0, 1.0, -1.0]));
+        assert!(close(&channel.sample(10.0), &[4.0, 2.0, -2.0]));
+    }
+
+    #[test]
+    fn linear_rotation_slerps() {
+        let q = Quaternion::from_axis_angle(Vector::vector([0.0, 0.0, 1.0]), 90.0);
+        let channel = Channel::new(0, Property::Rotation, Interpolation::Linear, vec![0.0, 1.0], vec![0.0, 0.0, 0.0, 1.0, q.x, q.y, q.z, q.w]);
+
+        let half = Quaternion::from_axis_angle(Vector::vector([0.0, 0.0, 1.0]), 45.0);
+        assert!(close(&channel.sample(0.5), &[half.x, half.y, half.z, half.w]));
+    }
+
+    #[test]
+    fn cubic_follows_hermite_curve() {
+        // Flat tangents ease in and out
+        let eased = Channel::new(0, Property::Weights(1), Interpolation::CubicSpline, vec![0.0, 1.0], vec![
+            0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0
+        ]);
+        assert!(close(&eased.sample(0.25), &[0.15625]));
+        assert!(close(&eased.sample(0.5), &[0.5]));
+
+        // Tangents are per second, so they scale with the gap between keys
+        let bump = Channel::new(0, Property::Weights(1), Interpolation::CubicSpline, vec![0.0, 2.0], vec![
+            0.0, 0.0, 1.0,
+            0.0, 0.0, 0.0
+        ]);
+        assert!(close(&bump.sample(1.0), &[0.25]));
+        assert!(close(&bump.sample(2.0), &[0.0]));
+    }
+
+    #[test]
+    fn loop_modes_map_time() {
+        assert_eq!(LoopMode::Once.local_time(-1.0, 2.0), 0.0);
+        assert_eq!(LoopMode::Once.local_time(5.0, 2.0), 2.0);
+        assert_eq!(LoopMode::Loop.local_time(5.0, 2.0), 1.0);
+        assert_eq!(LoopMode::Loop.local_time(-0.5, 2.0), 1.5);
+        assert_eq!(LoopMode::PingPong.local_time(1.5, 2.0), 1.5);
+        assert_eq!(LoopMode::PingPong.local_time(2.5, 2.0), 1.5);
+        assert_eq!(LoopMode::PingPong.local_time(4.5, 2.0), 0.5);
+        assert_eq!(LoopMode::Loop.local_time(3.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn clips_skip_missing_joints() {
+        let skeleton = Skeleton::new().joint("root", None, Transform::default());
+        let clip = Clip::new("walk")
+            .channel(ramp(Interpolation::Linear))
+            .channel(Channel::new(4, Property::Scale, Interpolation::Step, vec![0.0], vec![2.0, 2.0, 2.0]))
+            .channel(Channel::new(4, Property::Weights(2), Interpolation::Step, vec![0.0], vec![0.5, 0.5]));
+
+        let mut pose = skeleton.rest_pose();
+        clip.sample(2.0, &mut pose);
+
+        assert_eq!(clip.duration(), 3.0);
+        assert_eq!(pose.locals.len(), 1);
+        assert!(close(&pose.locals[0].translation.col_major()[0], &[2.0, 1.0, -1.0]));
+        assert!(pose.weights.is_empty());
+    }
+}
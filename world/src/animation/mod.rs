@@ -0,0 +1,5 @@
+mod clip;
+pub use clip::{Channel, Clip, Interpolation, LoopMode, Property};
+
+mod blend;
+pub use blend::{CrossFade, Playback};
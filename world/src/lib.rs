@@ -1,5 +1,16 @@
 use linalg::Matrix;
 
+mod quaternion;
+pub use quaternion::Quaternion;
+
+pub mod transform;
+pub use transform::Transform;
+
+pub mod skeleton;
+pub use skeleton::{Joint, JointPalette, Pose, Skeleton};
+
+pub mod animation;
+
 #[derive(Copy, Clone, Debug)]
 pub struct ModelMatrix {
     pub scale: (f32, f32, f32),
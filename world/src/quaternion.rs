@@ -0,0 +1,182 @@
+use linalg::{Matrix, Vector};
+
+/// A rotation, stored as a unit quaternion so that it can be interpolated without gimbal lock.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32
+}
+
+impl Quaternion {
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// A rotation of `degrees` around `axis`, which need not be normalized. A zero axis gives
+    /// the identity.
+    pub fn from_axis_angle(axis: Vector<f32, 3>, degrees: f32) -> Self {
+        let len = axis.dot(&axis).sqrt();
+        if len <= f32::EPSILON {
+            return Self::identity();
+        }
+
+        let half = degrees.to_radians() * 0.5;
+        let s = half.sin() / len;
+
+        Self::new(*axis.value_at(0, 0) * s, *axis.value_at(1, 0) * s, *axis.value_at(2, 0) * s, half.cos())
+    }
+
+    /// Euler angles in degrees, applied in the same order as `ModelMatrix::rotation`.
+    pub fn from_euler(x: f32, y: f32, z: f32) -> Self {
+        let rot_x = Self::from_axis_angle(Vector::vector([1.0, 0.0, 0.0]), x);
+        let rot_y = Self::from_axis_angle(Vector::vector([0.0, 1.0, 0.0]), y);
+        let rot_z = Self::from_axis_angle(Vector::vector([0.0, 0.0, 1.0]), z);
+
+        rot_z * rot_y * rot_x
+    }
+
+    pub fn dot(&self, other: &Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    pub fn normalize(&self) -> Self {
+        let len = self.dot(self).sqrt();
+        if len > f32::EPSILON {
+            Self::new(self.x / len, self.y / len, self.z / len, self.w / len)
+        } else {
+            Self::identity()
+        }
+    }
+
+    /// Normalized linear interpolation along the shorter arc.
+    pub fn nlerp(&self, other: &Self, t: f32) -> Self {
+        let other = if self.dot(other) < 0.0 { -*other } else { *other };
+
+        Self::new(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t,
+            self.z + (other.z - self.z) * t,
+            self.w + (other.w - self.w) * t
+        ).normalize()
+    }
+
+    /// Spherical linear interpolation along the shorter arc.
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let mut cos = self.dot(other);
+        let other = if cos < 0.0 {
+            cos = -cos;
+            -*other
+        } else {
+            *other
+        };
+
+        // Nearly parallel rotations make the sine below vanish, and nlerp is just as accurate there
+        if cos > 0.9995 {
+            return self.nlerp(&other, t);
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let a = ((1.0 - t) * angle).sin() / sin;
+        let b = (t * angle).sin() / sin;
+
+        Self::new(
+            self.x * a + other.x * b,
+            self.y * a + other.y * b,
+            self.z * a + other.z * b,
+            self.w * a + other.w * b
+        )
+    }
+
+    pub fn rotate(&self, v: Vector<f32, 3>) -> Vector<f32, 3> {
+        let p = Self::new(*v.value_at(0, 0), *v.value_at(1, 0), *v.value_at(2, 0), 0.0);
+        let r = *self * p * self.conjugate();
+
+        Vector::vector([r.x, r.y, r.z])
+    }
+
+    pub fn matrix(&self) -> Matrix<f32, 4, 4> {
+        let Self { x, y, z, w } = *self;
+
+        Matrix::new([
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w), 0.0],
+            [2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w), 0.0],
+            [2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y), 0.0],
+            [0.0, 0.0, 0.0, 1.0]
+        ])
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl core::ops::Neg for Quaternion {
+    type Output = Self;
+    fn neg(self) -> Self::Output { Self::new(-self.x, -self.y, -self.z, -self.w) }
+}
+
+impl core::ops::Mul for Quaternion {
+    type Output = Self;
+    fn mul(self, o: Self) -> Self::Output {
+        Self::new(
+            self.w * o.x + self.x * o.w + self.y * o.z - self.z * o.y,
+            self.w * o.y - self.x * o.z + self.y * o.w + self.z * o.x,
+            self.w * o.z + self.x * o.y - self.y * o.x + self.z * o.w,
+            self.w * o.w - self.x * o.x - self.y * o.y - self.z * o.z
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Vector<f32, 3>, b: [f32; 3]) -> bool {
+        (0..3).all(|i| (a.value_at(i, 0) - b[i]).abs() < 1e-5)
+    }
+
+    #[test]
+    fn zero_axis_is_identity() {
+        let q = Quaternion::from_axis_angle(Vector::vector([0.0, 0.0, 0.0]), 90.0);
+        assert_eq!(q, Quaternion::identity());
+    }
+
+    #[test]
+    fn rotates_around_unnormalized_axis() {
+        let q = Quaternion::from_axis_angle(Vector::vector([0.0, 0.0, 3.0]), 90.0);
+        assert!((q.dot(&q) - 1.0).abs() < 1e-6);
+        assert!(close(q.rotate(Vector::vector([1.0, 0.0, 0.0])), [0.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn matrix_agrees_with_rotate() {
+        let q = Quaternion::from_euler(30.0, -45.0, 60.0);
+        let v = Vector::vector([0.3, -1.2, 2.0]);
+        let m = (q.matrix() * Vector::vec4(v, 1.0)).vec3();
+        let r = q.rotate(v);
+
+        assert!(close(m, [0, 1, 2].map(|i| *r.value_at(i, 0))));
+    }
+
+    #[test]
+    fn slerp_takes_the_shorter_arc() {
+        let a = Quaternion::identity();
+        let b = -Quaternion::from_axis_angle(Vector::vector([0.0, 1.0, 0.0]), 90.0);
+        let half = a.slerp(&b, 0.5);
+
+        assert!(close(half.rotate(Vector::vector([0.0, 0.0, 1.0])), [45f32.to_radians().sin(), 0.0, 45f32.to_radians().cos()]));
+    }
+}
@@ -0,0 +1,133 @@
+use linalg::Matrix;
+use crate::Transform;
+use crate::transform::{affine_inverse, identity};
+
+/// The most joints a `JointPalette` can carry to the vertex shader.
+pub const MAX_JOINTS: usize = 64;
+
+#[derive(Clone, Debug)]
+pub struct Joint {
+    pub name: String,
+    pub parent: Option<usize>,
+    pub rest: Transform,
+    pub inverse_bind: Matrix<f32, 4, 4>
+}
+
+/// A hierarchy of joints, stored so that every parent comes before its children.
+#[derive(Clone, Debug, Default)]
+pub struct Skeleton {
+    joints: Vec<Joint>
+}
+
+impl Skeleton {
+    pub fn new() -> Self {
+        Self {
+            joints: vec![]
+        }
+    }
+
+    /// Adds a joint bound in its `rest` pose, deriving its inverse bind matrix from the hierarchy.
+    pub fn joint(self, name: &str, parent: Option<usize>, rest: Transform) -> Self {
+        let global = match parent {
+            Some(p) => self.bind_global(p) * rest.matrix(),
+            None => rest.matrix()
+        };
+
+        self.with_joint(Joint {
+            name: name.to_string(),
+            parent,
+            rest,
+            inverse_bind: affine_inverse(&global)
+        })
+    }
+
+    /// Adds a joint whose inverse bind matrix was authored elsewhere, such as in an imported file.
+    pub fn with_joint(self, joint: Joint) -> Self {
+        if let Some(p) = joint.parent {
+            assert!(p < self.joints.len(), "Joint '{}' must be added after its parent", joint.name);
+        }
+
+        let mut joints = self.joints;
+        joints.push(joint);
+
+        Self {
+            joints
+        }
+    }
+
+    pub fn joints(&self) -> &[Joint] {
+        &self.joints
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.joints.iter().position(|j| j.name == name)
+    }
+
+    pub fn rest_pose(&self) -> Pose {
+        Pose {
+            locals: self.joints.iter().map(|j| j.rest).collect(),
+            weights: vec![]
+        }
+    }
+
+    fn bind_global(&self, joint: usize) -> Matrix<f32, 4, 4> {
+        affine_inverse(&self.joints[joint].inverse_bind)
+    }
+}
+
+/// Local transforms for every joint of a `Skeleton`, in the skeleton's joint order.
+///
+/// Animation clips also write morph target weights here, indexed by the same joint.
+#[derive(Clone, Debug)]
+pub struct Pose {
+    pub locals: Vec<Transform>,
+    pub weights: Vec<Vec<f32>>
+}
+
+impl Pose {
+    /// Each joint's transform relative to the skeleton's root space.
+    pub fn globals(&self, skeleton: &Skeleton) -> Vec<Matrix<f32, 4, 4>> {
+        let mut globals: Vec<Matrix<f32, 4, 4>> = Vec::with_capacity(self.locals.len());
+        for (joint, local) in skeleton.joints.iter().zip(&self.locals) {
+            let global = match joint.parent {
+                Some(p) => globals[p] * local.matrix(),
+                None => local.matrix()
+            };
+            globals.push(global);
+        }
+
+        globals
+    }
+
+    /// The matrices that carry bind-pose vertices into this pose, one per joint.
+    pub fn joint_matrices(&self, skeleton: &Skeleton) -> Vec<Matrix<f32, 4, 4>> {
+        self.globals(skeleton).iter()
+            .zip(&skeleton.joints)
+            .map(|(global, joint)| *global * joint.inverse_bind)
+            .collect()
+    }
+}
+
+/// Joint matrices laid out for a uniform buffer, column major like the renderer's `Transformation`.
+///
+/// Unused slots hold the identity so that stray joint indices leave vertices in place.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct JointPalette {
+    pub matrices: [[[f32; 4]; 4]; MAX_JOINTS]
+}
+
+impl JointPalette {
+    pub fn new(joint_matrices: &[Matrix<f32, 4, 4>]) -> Self {
+        assert!(joint_matrices.len() <= MAX_JOINTS, "A skeleton may have at most {} joints", MAX_JOINTS);
+
+        let mut matrices = [identity().col_major(); MAX_JOINTS];
+        for (slot, m) in matrices.iter_mut().zip(joint_matrices) {
+            *slot = m.col_major();
+        }
+
+        Self {
+            matrices
+        }
+    }
+}
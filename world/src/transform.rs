@@ -0,0 +1,119 @@
+use linalg::{Matrix, Vector};
+use crate::Quaternion;
+
+/// A translation, rotation and scale, applied scale first.
+///
+/// Unlike `ModelMatrix`, the rotation is a `Quaternion`, so two transforms can be blended
+/// component by component.
+#[derive(Copy, Clone, Debug)]
+pub struct Transform {
+    pub translation: Vector<f32, 3>,
+    pub rotation: Quaternion,
+    pub scale: Vector<f32, 3>
+}
+
+impl Transform {
+    pub fn new(translation: Vector<f32, 3>, rotation: Quaternion, scale: Vector<f32, 3>) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale
+        }
+    }
+
+    pub fn from_translation(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            translation: Vector::vector([x, y, z]),
+            ..Default::default()
+        }
+    }
+
+    pub fn matrix(&self) -> Matrix<f32, 4, 4> {
+        let t = self.translation;
+        let s = self.scale;
+
+        let translation = Matrix::new([
+            [1.0, 0.0, 0.0, *t.value_at(0, 0)],
+            [0.0, 1.0, 0.0, *t.value_at(1, 0)],
+            [0.0, 0.0, 1.0, *t.value_at(2, 0)],
+            [0.0, 0.0, 0.0, 1.0]
+        ]);
+
+        let scale = Matrix::new([
+            [*s.value_at(0, 0), 0.0, 0.0, 0.0],
+            [0.0, *s.value_at(1, 0), 0.0, 0.0],
+            [0.0, 0.0, *s.value_at(2, 0), 0.0],
+            [0.0, 0.0, 0.0, 1.0]
+        ]);
+
+        translation * self.rotation.matrix() * scale
+    }
+
+    /// Interpolates translation and scale linearly and rotation spherically.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            translation: self.translation * (1.0 - t) + other.translation * t,
+            rotation: self.rotation.slerp(&other.rotation, t),
+            scale: self.scale * (1.0 - t) + other.scale * t
+        }
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            translation: Vector::vector([0.0, 0.0, 0.0]),
+            rotation: Quaternion::identity(),
+            scale: Vector::vector([1.0, 1.0, 1.0])
+        }
+    }
+}
+
+impl From<crate::ModelMatrix> for Transform {
+    fn from(m: crate::ModelMatrix) -> Self {
+        let (t_x, t_y, t_z) = m.translation;
+        let (r_x, r_y, r_z) = m.rotation;
+        let (s_x, s_y, s_z) = m.scale;
+
+        Self {
+            translation: Vector::vector([t_x, t_y, t_z]),
+            rotation: Quaternion::from_euler(r_x, r_y, r_z),
+            scale: Vector::vector([s_x, s_y, s_z])
+        }
+    }
+}
+
+pub fn identity() -> Matrix<f32, 4, 4> {
+    Matrix::new([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0]
+    ])
+}
+
+/// Inverts a matrix made of rotation, scale and translation only.
+pub fn affine_inverse(m: &Matrix<f32, 4, 4>) -> Matrix<f32, 4, 4> {
+    let a = |r: usize, c: usize| *m.value_at(r, c);
+
+    let cofactor = |r: usize, c: usize| {
+        let (r0, r1) = ((r + 1) % 3, (r + 2) % 3);
+        let (c0, c1) = ((c + 1) % 3, (c + 2) % 3);
+        a(r0, c0) * a(r1, c1) - a(r0, c1) * a(r1, c0)
+    };
+
+    let det = a(0, 0) * cofactor(0, 0) + a(0, 1) * cofactor(0, 1) + a(0, 2) * cofactor(0, 2);
+    let mut inverse = identity();
+    for r in 0..3 {
+        for c in 0..3 {
+            inverse.set(r, c, cofactor(c, r) / det);
+        }
+    }
+
+    for r in 0..3 {
+        let t = -(0..3).map(|c| *inverse.value_at(r, c) * a(c, 3)).sum::<f32>();
+        inverse.set(r, 3, t);
+    }
+
+    inverse
+}